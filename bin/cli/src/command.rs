@@ -111,10 +111,17 @@ pub struct BankCommand {
 #[derive(Clone, Debug, Clap)]
 pub enum BankSubCommand {
     Open(bank::OpenCommand),
+    Deposit(bank::DepositCommand),
     ProposeSpend(bank::ProposeSpendCommand),
+    ProposeBankSpend(bank::ProposeBankSpendCommand),
+    ProposeOrgSpend(bank::ProposeOrgSpendCommand),
     TriggerVote(bank::TriggerVoteCommand),
     SudoApprove(bank::SudoApproveCommand),
     Close(bank::CloseCommand),
+    ProposeClose(bank::ProposeCloseCommand),
+    ProposeController(bank::ProposeControllerCommand),
+    ProposeThreshold(bank::ProposeThresholdCommand),
+    History(bank::HistoryCommand),
 }
 
 #[derive(Clone, Debug, Clap)]
@@ -126,12 +133,32 @@ pub struct BountyCommand {
 #[derive(Clone, Debug, Clap)]
 pub enum BountySubCommand {
     PostBounty(bounty::BountyPostCommand),
+    PostBountyForOrg(bounty::BountyPostForOrgCommand),
     ContributeToBounty(bounty::BountyContributeCommand),
+    WithdrawContribution(bounty::BountyWithdrawContributionCommand),
     SubmitForBounty(bounty::BountySubmitCommand),
+    SubmitForBountyAsTeam(bounty::BountySubmitForTeamCommand),
     ApproveApplication(bounty::BountyApproveCommand),
+    ApprovePayouts(bounty::BountyApprovePayoutsCommand),
+    CloseBounty(bounty::BountyCloseCommand),
+    OptimisticReview(bounty::BountyOptimisticReviewCommand),
+    MinReputation(bounty::BountyMinReputationCommand),
+    RejectApplication(bounty::BountyRejectCommand),
+    RejectSpam(bounty::BountyRejectSpamCommand),
+    WithdrawApplication(bounty::BountyWithdrawSubmissionCommand),
+    TriggerApplicationReview(bounty::BountyTriggerReviewCommand),
+    DisputeResolution(bounty::BountyDisputeResolutionCommand),
+    EscalateApplication(bounty::BountyEscalateCommand),
+    ClaimBounty(bounty::BountyClaimCommand),
+    ApproveClaim(bounty::BountyApproveClaimCommand),
+    ReleaseClaim(bounty::BountyReleaseClaimCommand),
+    OpenFundingRound(bounty::BountyOpenRoundCommand),
+    AddToFundingRound(bounty::BountyAddToRoundCommand),
+    Label(bounty::BountyLabelCommand),
     // storage helpers
     GetBounty(bounty::GetBountyCommand),
     GetSubmission(bounty::GetSubmissionCommand),
     GetOpenBounties(bounty::GetOpenBountiesCommand),
+    GetBounties(bounty::GetBountiesCommand),
     GetOpenSubmissions(bounty::GetOpenSubmissionsCommand),
 }
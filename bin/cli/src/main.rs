@@ -78,24 +78,88 @@ async fn main() -> Result<()> {
         SubCommand::Bank(BankCommand { cmd }) => {
             match cmd {
                 BankSubCommand::Open(cmd) => cmd.exec(&client).await?,
+                BankSubCommand::Deposit(cmd) => cmd.exec(&client).await?,
                 BankSubCommand::ProposeSpend(cmd) => cmd.exec(&client).await?,
+                BankSubCommand::ProposeBankSpend(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BankSubCommand::ProposeOrgSpend(cmd) => {
+                    cmd.exec(&client).await?
+                }
                 BankSubCommand::TriggerVote(cmd) => cmd.exec(&client).await?,
                 BankSubCommand::SudoApprove(cmd) => cmd.exec(&client).await?,
                 BankSubCommand::Close(cmd) => cmd.exec(&client).await?,
+                BankSubCommand::ProposeClose(cmd) => cmd.exec(&client).await?,
+                BankSubCommand::ProposeController(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BankSubCommand::ProposeThreshold(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BankSubCommand::History(cmd) => cmd.exec(&client).await?,
             }
         }
         SubCommand::Bounty(BountyCommand { cmd }) => {
             match cmd {
                 BountySubCommand::PostBounty(cmd) => cmd.exec(&client).await?,
+                BountySubCommand::PostBountyForOrg(cmd) => {
+                    cmd.exec(&client).await?
+                }
                 BountySubCommand::ContributeToBounty(cmd) => {
                     cmd.exec(&client).await?
                 }
+                BountySubCommand::WithdrawContribution(cmd) => {
+                    cmd.exec(&client).await?
+                }
                 BountySubCommand::SubmitForBounty(cmd) => {
                     cmd.exec(&client).await?
                 }
+                BountySubCommand::SubmitForBountyAsTeam(cmd) => {
+                    cmd.exec(&client).await?
+                }
                 BountySubCommand::ApproveApplication(cmd) => {
                     cmd.exec(&client).await?
                 }
+                BountySubCommand::ApprovePayouts(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::CloseBounty(cmd) => cmd.exec(&client).await?,
+                BountySubCommand::OptimisticReview(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::MinReputation(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::RejectApplication(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::RejectSpam(cmd) => cmd.exec(&client).await?,
+                BountySubCommand::WithdrawApplication(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::TriggerApplicationReview(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::DisputeResolution(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::EscalateApplication(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::ClaimBounty(cmd) => cmd.exec(&client).await?,
+                BountySubCommand::ApproveClaim(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::ReleaseClaim(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::OpenFundingRound(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::AddToFundingRound(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::Label(cmd) => cmd.exec(&client).await?,
                 BountySubCommand::GetBounty(cmd) => cmd.exec(&client).await?,
                 BountySubCommand::GetSubmission(cmd) => {
                     cmd.exec(&client).await?
@@ -103,6 +167,7 @@ async fn main() -> Result<()> {
                 BountySubCommand::GetOpenBounties(cmd) => {
                     cmd.exec(&client).await?
                 }
+                BountySubCommand::GetBounties(cmd) => cmd.exec(&client).await?,
                 BountySubCommand::GetOpenSubmissions(cmd) => {
                     cmd.exec(&client).await?
                 }
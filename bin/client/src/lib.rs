@@ -24,6 +24,7 @@ use sunshine_bounty_client::{
     bounty::Bounty,
     donate::Donate,
     org::Org,
+    tokens::Tokens,
     vote::Vote,
 };
 use sunshine_client_utils::{
@@ -90,6 +91,10 @@ impl Vote for Runtime {
 
 impl Donate for Runtime {}
 
+impl Tokens for Runtime {
+    type CurrencyId = u32;
+}
+
 impl Bank for Runtime {
     type BankId = u64;
     type SpendId = u64;
@@ -101,6 +106,8 @@ impl Bounty for Runtime {
     type BountyPost = GithubIssue;
     type SubmissionId = u64;
     type BountySubmission = GithubIssue;
+    type DisputeId = u64;
+    type RoundId = u64;
 }
 
 impl sunshine_identity_client::Identity for Runtime {
@@ -7,6 +7,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use orml_currencies::BasicCurrencyAdapter;
 use pallet_grandpa::{
     fg_primitives,
     AuthorityId,
@@ -39,6 +40,14 @@ use sp_std::prelude::*;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
+use util::{
+    meta::{
+        VoteCall,
+        VoteMetadata,
+    },
+    organization::OrgRep,
+    vote::Threshold,
+};
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
@@ -303,6 +312,32 @@ impl pallet_transaction_payment::Trait for Runtime {
     type WeightToFee = IdentityFee<Balance>;
     type FeeMultiplierUpdate = ();
 }
+/// Identifies the assets held by bank accounts
+pub type CurrencyId = u32;
+/// Signed balance change used by `orml_tokens`
+pub type Amount = i128;
+
+parameter_types! {
+    pub const GetNativeCurrencyId: CurrencyId = 0;
+}
+
+impl orml_tokens::Trait for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type Amount = Amount;
+    type CurrencyId = CurrencyId;
+    type OnReceived = ();
+    type WeightInfo = ();
+}
+
+impl orml_currencies::Trait for Runtime {
+    type Event = Event;
+    type MultiCurrency = Tokens;
+    type NativeCurrency =
+        BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+    type GetNativeCurrencyId = GetNativeCurrencyId;
+    type WeightInfo = ();
+}
 impl org::Trait for Runtime {
     type Event = Event;
     type Cid = sunshine_codec::Cid; // Serialize and Deserialize
@@ -336,44 +371,112 @@ parameter_types! {
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
     pub const MaxTreasuryPerOrg: u32 = 50;
     pub const MinimumDeposit: u128 = 20;
+    pub const SpendProposalExpiry: BlockNumber = 7 * DAYS;
+    pub const MaxProposalPollsPerBlock: u32 = 32;
+    pub const ProposalPollFrequency: BlockNumber = 10;
 }
 impl bank::Trait for Runtime {
     type Event = Event;
-    type Currency = Balances;
+    type Currency = Currencies;
+    type NativeCurrencyId = GetNativeCurrencyId;
     type BigBank = BigBank;
     type BankId = u64;
     type SpendId = u64;
     type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
     type MinDeposit = MinimumDeposit;
+    type SpendProposalExpiry = SpendProposalExpiry;
+    type MaxProposalPollsPerBlock = MaxProposalPollsPerBlock;
+    type ProposalPollFrequency = ProposalPollFrequency;
+}
+parameter_types! {
+    pub const MinimumDisputeAmount: u128 = 10;
+}
+impl insurance::Trait for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type DisputeId = u64;
+    type MinimumDisputeAmount = MinimumDisputeAmount;
+}
+parameter_types! {
+    pub const MinRecordedPayment: Balance = 10;
+}
+impl reputation::Trait for Runtime {
+    type Event = Event;
+    type BountyId = u64;
+    type MilestoneId = (u64, u64);
+    type Balance = Balance;
+    type MinRecordedPayment = MinRecordedPayment;
 }
 parameter_types! {
     pub const Foundation: ModuleId = ModuleId(*b"fundacon");
     pub const MinDeposit: u128 = 10;
     pub const MinContribution: u128 = 5;
+    pub const SubmissionReviewPeriod: BlockNumber = 14 * DAYS;
+    pub const ClaimBond: u128 = 2;
+    pub const ClaimPeriod: BlockNumber = 7 * DAYS;
+    pub const ClaimSlash: Permill = Permill::from_percent(50);
+    pub const SubmissionDeposit: u128 = 1;
+    pub const MaxBountyTags: u32 = 8;
+    pub const MaxBountyLabelLength: u32 = 64;
+    pub const MaxBountyDeadlinesPerBlock: u32 = 32;
+    pub const BountyReviewPollFrequency: BlockNumber = 10;
+    pub const BountyEscalationPeriod: BlockNumber = 3 * DAYS;
+    // disputes on bounties which set no resolution are settled by the first org
+    pub BountyDisputeResolution: VoteMetadata<OrgRep<u64>, u64, Permill, BlockNumber> =
+        VoteMetadata::Percentage(VoteCall::new(
+            OrgRep::Weighted(1),
+            Threshold::new(Permill::from_percent(51), None),
+            Some(7 * DAYS),
+        ));
 }
 impl bounty::Trait for Runtime {
     type Event = Event;
     type IpfsReference = sunshine_codec::Cid;
-    type Currency = Balances;
     type BountyId = u64;
     type SubmissionId = u64;
+    type RoundId = u64;
     type Foundation = Foundation;
+    type Treasury = TreasuryModuleId;
     type MinDeposit = MinDeposit;
     type MinContribution = MinContribution;
+    type SubmissionReviewPeriod = SubmissionReviewPeriod;
+    type ClaimBond = ClaimBond;
+    type ClaimPeriod = ClaimPeriod;
+    type ClaimSlash = ClaimSlash;
+    type SubmissionDeposit = SubmissionDeposit;
+    type MaxTags = MaxBountyTags;
+    type MaxLabelLength = MaxBountyLabelLength;
+    type MaxDeadlinesPerBlock = MaxBountyDeadlinesPerBlock;
+    type ReviewPollFrequency = BountyReviewPollFrequency;
+    type EscalationPeriod = BountyEscalationPeriod;
+    type DefaultDisputeResolution = BountyDisputeResolution;
+    type DisputeId = u64;
+    type Court = Insurance;
+    type Reputation = Reputation;
 }
 parameter_types! {
     pub const BigFoundation: ModuleId = ModuleId(*b"big/fund");
     pub const MinGrantDeposit: u128 = 20;
     pub const MinGrantContribution: u128 = 10;
+    pub const GrantApplicationDeposit: u128 = 1;
+    pub const MaxGrantReviewPollsPerBlock: u32 = 32;
+    pub const MaxGrantReviewCommittees: u32 = 4;
 }
 impl grant::Trait for Runtime {
     type Event = Event;
     type FoundationId = u64;
     type ApplicationId = u64;
     type MilestoneId = u64;
+    type CommitteeId = u64;
     type BigFoundation = BigFoundation;
     type MinDeposit = MinGrantDeposit;
     type MinContribution = MinGrantContribution;
+    type ApplicationDeposit = GrantApplicationDeposit;
+    type MaxReviewPollsPerBlock = MaxGrantReviewPollsPerBlock;
+    type MaxReviewCommittees = MaxGrantReviewCommittees;
+    type Reputation = Reputation;
+    type DripId = u64;
+    type Drip = Drip;
 }
 
 construct_runtime!(
@@ -389,6 +492,8 @@ construct_runtime!(
         Grandpa: pallet_grandpa::{Module, Call, Storage, Config, Event},
         Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Module, Storage},
+        Tokens: orml_tokens::{Module, Storage, Event<T>},
+        Currencies: orml_currencies::{Module, Call, Event<T>},
         // sunshine-bounty modules
         Org: org::{Module, Call, Config<T>, Storage, Event<T>},
         Vote: vote::{Module, Call, Storage, Event<T>},
@@ -396,6 +501,8 @@ construct_runtime!(
         Treasury: treasury::{Module, Call, Config<T>, Storage, Event<T>},
         Donate: donate::{Module, Call, Event<T>},
         Bank: bank::{Module, Call, Storage, Event<T>},
+        Insurance: insurance::{Module, Call, Storage, Event<T>},
+        Reputation: reputation::{Module, Call, Storage, Event<T>},
         Bounty: bounty::{Module, Call, Storage, Event<T>},
         Grant: grant::{Module, Call, Config<T>, Storage, Event<T>},
     }
@@ -542,4 +649,43 @@ impl_runtime_apis! {
             None
         }
     }
+
+    impl bank_runtime_api::BankApi<Block, u64, CurrencyId, Balance> for Runtime {
+        fn bank_balances(bank_id: u64, currency_id: CurrencyId) -> Option<util::bank::BankBalance<Balance>> {
+            Bank::bank_balances(bank_id, currency_id)
+        }
+    }
+
+    impl reputation_runtime_api::ReputationApi<Block, AccountId, Balance> for Runtime {
+        fn reputation(who: AccountId) -> util::reputation::Reputation<Balance> {
+            Reputation::reputations(who)
+        }
+        fn reputation_score(who: AccountId) -> u32 {
+            Reputation::reputation_score(&who)
+        }
+    }
+
+    impl bounty_runtime_api::BountyApi<Block, u64, sunshine_codec::Cid, AccountId, Balance> for Runtime {
+        fn bounties_by_repo(
+            repo: Vec<u8>,
+            after: Option<u64>,
+            limit: u32,
+        ) -> Vec<util::bounty::BountyInformation<u64, sunshine_codec::Cid, AccountId, Balance>> {
+            Bounty::bounties_by_repo(repo, after, limit)
+        }
+        fn bounties_by_tag(
+            tag: Vec<u8>,
+            after: Option<u64>,
+            limit: u32,
+        ) -> Vec<util::bounty::BountyInformation<u64, sunshine_codec::Cid, AccountId, Balance>> {
+            Bounty::bounties_by_tag(tag, after, limit)
+        }
+        fn bounties_by_depositer(
+            depositer: AccountId,
+            after: Option<u64>,
+            limit: u32,
+        ) -> Vec<util::bounty::BountyInformation<u64, sunshine_codec::Cid, AccountId, Balance>> {
+            Bounty::bounties_by_depositer(depositer, after, limit)
+        }
+    }
 }
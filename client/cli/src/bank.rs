@@ -20,9 +20,14 @@ use sunshine_bounty_client::{
         BankClient,
     },
     org::Org,
+    tokens::NATIVE_CURRENCY_ID,
     vote::Vote,
 };
 use sunshine_bounty_utils::{
+    bank::{
+        BankChange,
+        SpendDest,
+    },
     organization::OrgRep,
     vote::{
         Threshold,
@@ -99,6 +104,7 @@ pub struct ProposeSpendCommand {
     pub bank_id: u64,
     pub amount: u128,
     pub dest: String,
+    pub currency_id: Option<u32>,
 }
 
 impl ProposeSpendCommand {
@@ -114,10 +120,93 @@ impl ProposeSpendCommand {
     {
         let raw_dest: Ss58<N::Runtime> = self.dest.parse()?;
         let event = client
-            .propose_spend(self.bank_id.into(), self.amount.into(), raw_dest.0)
+            .propose_spend(
+                self.bank_id.into(),
+                self.currency_id.unwrap_or(NATIVE_CURRENCY_ID).into(),
+                self.amount.into(),
+                SpendDest::Account(raw_dest.0),
+            )
+            .await?;
+        println!(
+            "Account {} proposed new spend from Bank {:?} with Spend Proposal ID {:?} of Amount {:?} to Destination {:?}",
+            event.caller, event.bank_id, event.spend_id, event.amount, event.dest
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct ProposeBankSpendCommand {
+    pub bank_id: u64,
+    pub amount: u128,
+    pub dest_bank_id: u64,
+    pub currency_id: Option<u32>,
+}
+
+impl ProposeBankSpendCommand {
+    pub async fn exec<N: Node, C: BankClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bank,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Bank>::BankId: From<u64> + Display,
+        <N::Runtime as Balances>::Balance: From<u128> + Display,
+    {
+        let event = client
+            .propose_spend(
+                self.bank_id.into(),
+                self.currency_id.unwrap_or(NATIVE_CURRENCY_ID).into(),
+                self.amount.into(),
+                SpendDest::Bank(self.dest_bank_id.into()),
+            )
+            .await?;
+        println!(
+            "Account {} proposed new spend from Bank {:?} with Spend Proposal ID {:?} of Amount {:?} to Destination {:?}",
+            event.caller, event.bank_id, event.spend_id, event.amount, event.dest
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct ProposeOrgSpendCommand {
+    pub bank_id: u64,
+    pub amount: u128,
+    pub org: u64,
+    pub weighted: u8,
+    pub currency_id: Option<u32>,
+}
+
+impl ProposeOrgSpendCommand {
+    pub async fn exec<N: Node, C: BankClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bank,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Org>::OrgId: From<u64> + Display,
+        <N::Runtime as Bank>::BankId: From<u64> + Display,
+        <N::Runtime as Balances>::Balance: From<u128> + Display,
+    {
+        // 0 is false, every other integer is true
+        let org = if self.weighted != 0 {
+            OrgRep::Weighted(self.org.into())
+        } else {
+            OrgRep::Equal(self.org.into())
+        };
+        let event = client
+            .propose_spend(
+                self.bank_id.into(),
+                self.currency_id.unwrap_or(NATIVE_CURRENCY_ID).into(),
+                self.amount.into(),
+                SpendDest::Org(org),
+            )
             .await?;
         println!(
-            "Account {} proposed new spend from Bank {:?} with Spend Proposal ID {:?} of Amount {} to Destination {:?}",
+            "Account {} proposed new spend from Bank {:?} with Spend Proposal ID {:?} of Amount {:?} to Destination {:?}",
             event.caller, event.bank_id, event.spend_id, event.amount, event.dest
         );
         Ok(())
@@ -206,3 +295,173 @@ impl CloseCommand {
         Ok(())
     }
 }
+
+#[derive(Clone, Debug, Clap)]
+pub struct DepositCommand {
+    pub bank_id: u64,
+    pub amount: u128,
+    pub currency_id: Option<u32>,
+}
+
+impl DepositCommand {
+    pub async fn exec<N: Node, C: BankClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bank,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Bank>::BankId: From<u64> + Display,
+        <N::Runtime as Balances>::Balance: From<u128> + Display,
+    {
+        let event = client
+            .deposit(
+                self.bank_id.into(),
+                self.currency_id.unwrap_or(NATIVE_CURRENCY_ID).into(),
+                self.amount.into(),
+            )
+            .await?;
+        println!(
+            "Account {} deposited {:?} into Bank {:?}",
+            event.depositer, event.amount, event.bank_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct HistoryCommand {
+    pub bank_id: u64,
+    pub start: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+impl HistoryCommand {
+    pub async fn exec<N: Node, C: BankClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bank,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as System>::BlockNumber: Display,
+        <N::Runtime as Bank>::BankId: From<u64> + Display,
+        <N::Runtime as Balances>::Balance: Display,
+    {
+        let entries = client
+            .bank_history(
+                self.bank_id.into(),
+                self.start.unwrap_or(0),
+                self.limit.unwrap_or(20),
+            )
+            .await?;
+        for (index, entry) in entries {
+            println!(
+                "#{} block {}: {:?} of {} with {:?} for Spend {:?}",
+                index,
+                entry.block(),
+                entry.kind(),
+                entry.amount(),
+                entry.counterparty(),
+                entry.spend_id()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct ProposeCloseCommand {
+    pub bank_id: u64,
+}
+
+impl ProposeCloseCommand {
+    pub async fn exec<N: Node, C: BankClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bank,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Bank>::BankId: From<u64> + Display,
+        <N::Runtime as Vote>::VoteId: Display,
+    {
+        let event = client
+            .propose_change(self.bank_id.into(), BankChange::Close)
+            .await?;
+        println!(
+            "Account {} proposed Change {} to close Bank {:?} with VoteId {}",
+            event.caller, event.change_id, event.bank_id, event.vote_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct ProposeControllerCommand {
+    pub bank_id: u64,
+    pub controller: Option<String>,
+}
+
+impl ProposeControllerCommand {
+    pub async fn exec<N: Node, C: BankClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bank,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Bank>::BankId: From<u64> + Display,
+        <N::Runtime as Vote>::VoteId: Display,
+    {
+        let controller = if let Some(acc) = &self.controller {
+            let new_acc: Ss58<N::Runtime> = acc.parse()?;
+            Some(new_acc.0)
+        } else {
+            None
+        };
+        let event = client
+            .propose_change(
+                self.bank_id.into(),
+                BankChange::ReplaceController(controller),
+            )
+            .await?;
+        println!(
+            "Account {} proposed Change {:?} to Bank {:?} with VoteId {}",
+            event.caller, event.change, event.bank_id, event.vote_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct ProposeThresholdCommand {
+    pub bank_id: u64,
+    pub threshold_id: u64,
+}
+
+impl ProposeThresholdCommand {
+    pub async fn exec<N: Node, C: BankClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bank,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Bank>::BankId: From<u64> + Display,
+        <N::Runtime as Vote>::ThresholdId: From<u64>,
+        <N::Runtime as Vote>::VoteId: Display,
+    {
+        let event = client
+            .propose_change(
+                self.bank_id.into(),
+                BankChange::ChangeThreshold(self.threshold_id.into()),
+            )
+            .await?;
+        println!(
+            "Account {} proposed Change {:?} to Bank {:?} with VoteId {}",
+            event.caller, event.change, event.bank_id, event.vote_id
+        );
+        Ok(())
+    }
+}
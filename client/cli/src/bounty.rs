@@ -1,4 +1,12 @@
-use crate::utils::GithubIssueMetadata;
+use crate::{
+    error::{
+        BountyFilterInputError,
+        InvalidPayoutInput,
+        PostBountyInputError,
+    },
+    utils::GithubIssueMetadata,
+    vote::u8_to_permill,
+};
 use clap::Clap;
 use core::fmt::{
     Debug,
@@ -19,9 +27,21 @@ use sunshine_bounty_client::{
         Bounty,
         BountyClient,
     },
+    org::Org,
+    vote::Vote,
     GithubIssue,
 };
+use sunshine_bounty_utils::{
+    meta::{
+        ResolutionMetadata,
+        VoteCall,
+        VoteMetadata,
+    },
+    organization::OrgRep,
+    vote::Threshold,
+};
 use sunshine_client_utils::{
+    crypto::ss58::Ss58,
     Node,
     OffchainConfig,
     Result,
@@ -31,6 +51,10 @@ use sunshine_client_utils::{
 pub struct BountyPostCommand {
     pub issue_url: String,
     pub amount: u128,
+    pub deadline: Option<u32>,
+    /// Tags indexed along with the issue's repository
+    #[clap(long)]
+    pub tag: Vec<String>,
 }
 
 impl BountyPostCommand {
@@ -44,20 +68,95 @@ impl BountyPostCommand {
         <N::Runtime as Balances>::Balance: From<u128> + Display,
         <N::Runtime as Bounty>::BountyId: Display,
         <N::Runtime as Bounty>::BountyPost: From<GithubIssue>,
+        <N::Runtime as System>::BlockNumber: From<u32>,
     {
         let metadata: GithubIssueMetadata =
             self.issue_url.as_str().try_into()?;
+        let repo = format!("{}/{}", metadata.owner, metadata.repo);
         let bounty: <N::Runtime as Bounty>::BountyPost = GithubIssue {
             repo_owner: metadata.owner,
             repo_name: metadata.repo,
             issue_number: metadata.issue,
         }
         .into();
-        let event = client.post_bounty(bounty, self.amount.into()).await?;
+        let event = client
+            .post_bounty(
+                bounty,
+                self.amount.into(),
+                self.deadline.map(|d| d.into()),
+            )
+            .await?;
         println!(
             "Depositer with AccountId {} posted new BountyId {}, Balance {}",
             event.depositer, event.id, event.amount,
         );
+        match client
+            .label_bounty(event.id, Some(repo.clone()), self.tag.clone())
+            .await
+        {
+            Ok(_) => println!("BountyId {} indexed under {}", event.id, repo),
+            Err(e) => {
+                eprintln!("BountyId {} was posted but not indexed", event.id);
+                eprintln!("{}", e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyPostForOrgCommand {
+    pub issue_url: String,
+    pub amount: u128,
+    pub org: u64,
+    pub signal_threshold: u64,
+    pub reviewer: Option<String>,
+}
+
+impl BountyPostForOrgCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Balances>::Balance: From<u128> + Display,
+        <N::Runtime as Bounty>::BountyId: Display,
+        <N::Runtime as Bounty>::BountyPost: From<GithubIssue>,
+        <N::Runtime as Org>::OrgId: From<u64> + Display,
+        <N::Runtime as Vote>::Signal: From<u64>,
+    {
+        let metadata: GithubIssueMetadata =
+            self.issue_url.as_str().try_into()?;
+        let bounty: <N::Runtime as Bounty>::BountyPost = GithubIssue {
+            repo_owner: metadata.owner,
+            repo_name: metadata.repo,
+            issue_number: metadata.issue,
+        }
+        .into();
+        let reviewer = if let Some(acc) = &self.reviewer {
+            let new_acc: Ss58<N::Runtime> = acc.parse()?;
+            Some(new_acc.0)
+        } else {
+            None
+        };
+        let governance = ResolutionMetadata::new(
+            reviewer,
+            Some(VoteMetadata::Signal(VoteCall::new(
+                OrgRep::Equal(self.org.into()),
+                Threshold::new(self.signal_threshold.into(), None),
+                None,
+            ))),
+        )
+        .ok_or(PostBountyInputError)?;
+        let event = client
+            .post_governed_bounty(bounty, self.amount.into(), None, governance)
+            .await?;
+        println!(
+            "Depositer with AccountId {} posted new BountyId {} governed by Org {}",
+            event.depositer, event.bounty_id, event.org,
+        );
         Ok(())
     }
 }
@@ -90,6 +189,34 @@ impl BountyContributeCommand {
     }
 }
 
+#[derive(Clone, Debug, Clap)]
+pub struct BountyWithdrawContributionCommand {
+    pub bounty_id: u64,
+    pub amount: u128,
+}
+
+impl BountyWithdrawContributionCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Balances>::Balance: From<u128> + Display,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        let event = client
+            .withdraw_contribution(self.bounty_id.into(), self.amount.into())
+            .await?;
+        println!(
+            "AccountId {} withdrew ${} from BountyId {} and the Total Balance for the Bounty is now {}",
+            event.contributor, event.amount, event.bounty_id, event.total
+        );
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Clap)]
 pub struct BountySubmitCommand {
     pub issue_url: String,
@@ -133,6 +260,60 @@ impl BountySubmitCommand {
     }
 }
 
+#[derive(Clone, Debug, Clap)]
+pub struct BountySubmitForTeamCommand {
+    pub issue_url: String,
+    pub bounty_id: u64,
+    pub amount: u128,
+    pub org: u64,
+    pub weighted: u8,
+}
+
+impl BountySubmitForTeamCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Balances>::Balance: From<u128>,
+        <N::Runtime as Bounty>::BountyId: From<u64>,
+        <N::Runtime as Bounty>::SubmissionId: Display,
+        <N::Runtime as Bounty>::BountySubmission: From<GithubIssue>,
+        <N::Runtime as Org>::OrgId: From<u64> + Display,
+    {
+        let metadata: GithubIssueMetadata =
+            self.issue_url.as_str().try_into()?;
+        let submission: <N::Runtime as Bounty>::BountySubmission =
+            GithubIssue {
+                repo_owner: metadata.owner,
+                repo_name: metadata.repo,
+                issue_number: metadata.issue,
+            }
+            .into();
+        // 0 is false, every other integer is true
+        let team = if self.weighted != 0 {
+            OrgRep::Weighted(self.org.into())
+        } else {
+            OrgRep::Equal(self.org.into())
+        };
+        let event = client
+            .submit_for_bounty_as_team(
+                self.bounty_id.into(),
+                submission,
+                self.amount.into(),
+                team,
+            )
+            .await?;
+        println!(
+            "Submitter with AccountId {} submitted SubmissionId {} on behalf of Org {}",
+            event.submitter, event.id, event.org,
+        );
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Clap)]
 pub struct BountyApproveCommand {
     pub submission_id: u64,
@@ -161,6 +342,478 @@ impl BountyApproveCommand {
     }
 }
 
+#[derive(Clone, Debug, Clap)]
+pub struct BountyApprovePayoutsCommand {
+    pub bounty_id: u64,
+    /// Each payout as SubmissionId:Amount
+    pub payouts: Vec<String>,
+}
+
+impl BountyApprovePayoutsCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Balances>::Balance: From<u128> + Display,
+        <N::Runtime as Bounty>::SubmissionId: From<u64> + Display,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        let mut payouts = Vec::new();
+        for payout in &self.payouts {
+            let mut split = payout.split(':');
+            let (id, amount) = match (split.next(), split.next(), split.next())
+            {
+                (Some(id), Some(amount), None) => (id, amount),
+                _ => return Err(InvalidPayoutInput.into()),
+            };
+            let id: u64 = id.parse().map_err(|_| InvalidPayoutInput)?;
+            let amount: u128 =
+                amount.parse().map_err(|_| InvalidPayoutInput)?;
+            payouts.push((id.into(), amount.into()));
+        }
+        let event = client
+            .approve_bounty_submissions(self.bounty_id.into(), payouts)
+            .await?;
+        println!(
+            "Approved {} payouts for BountyId {}, starting with Balance {} to AccountId {} for SubmissionId {}",
+            self.payouts.len(), event.bounty_id, event.amount, event.submitter, event.submission_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyCloseCommand {
+    pub bounty_id: u64,
+}
+
+impl BountyCloseCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Balances>::Balance: Display,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        let event = client.close_bounty(self.bounty_id.into()).await?;
+        println!(
+            "Closed BountyId {} and refunded Balance {} to contributors",
+            event.bounty_id, event.refunded
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyClaimCommand {
+    pub bounty_id: u64,
+}
+
+impl BountyClaimCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as System>::BlockNumber: Display,
+        <N::Runtime as Balances>::Balance: Display,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        match client.claim_bounty(self.bounty_id.into()).await? {
+            Some(event) => {
+                println!(
+                    "AccountId {} claimed BountyId {} with bond {} until block {}",
+                    event.hunter, event.bounty_id, event.bond, event.expires
+                )
+            }
+            None => {
+                println!(
+                    "Claim on BountyId {} awaits approval by the bounty's reviewer",
+                    self.bounty_id
+                )
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyApproveClaimCommand {
+    pub bounty_id: u64,
+    pub hunter: String,
+}
+
+impl BountyApproveClaimCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as System>::BlockNumber: Display,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        let hunter: Ss58<N::Runtime> = self.hunter.parse()?;
+        let event = client
+            .approve_bounty_claim(self.bounty_id.into(), hunter.0)
+            .await?;
+        println!(
+            "Approved claim by AccountId {} on BountyId {} until block {}",
+            event.hunter, event.bounty_id, event.expires
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyReleaseClaimCommand {
+    pub bounty_id: u64,
+}
+
+impl BountyReleaseClaimCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::BountyId: From<u64>,
+    {
+        client.release_bounty_claim(self.bounty_id.into()).await?;
+        println!("Released claim on BountyId {}", self.bounty_id);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyOpenRoundCommand {
+    pub pool: u128,
+    /// Maximum matched to any one bounty
+    pub cap: u128,
+    pub end: u32,
+}
+
+impl BountyOpenRoundCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Balances>::Balance: From<u128> + Display,
+        <N::Runtime as Bounty>::RoundId: Display,
+        <N::Runtime as System>::BlockNumber: From<u32> + Display,
+    {
+        let event = client
+            .open_funding_round(
+                self.pool.into(),
+                self.cap.into(),
+                self.end.into(),
+            )
+            .await?;
+        println!(
+            "AccountId {} opened RoundId {} matching {} (at most {} per bounty) until block {}",
+            event.sponsor.to_ss58check(),
+            event.round_id,
+            event.pool,
+            event.cap,
+            event.end
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyAddToRoundCommand {
+    pub round_id: u64,
+    pub bounty_id: u64,
+}
+
+impl BountyAddToRoundCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::RoundId: From<u64> + Display,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        let event = client
+            .add_bounty_to_funding_round(
+                self.round_id.into(),
+                self.bounty_id.into(),
+            )
+            .await?;
+        println!(
+            "Contributions to BountyId {} are matched by RoundId {}",
+            event.bounty_id, event.round_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyOptimisticReviewCommand {
+    pub bounty_id: u64,
+    /// Contributor share (percent) required to reject, omit to disable
+    pub rejection_threshold: Option<u8>,
+}
+
+impl BountyOptimisticReviewCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        let threshold = if let Some(t) = self.rejection_threshold {
+            Some(u8_to_permill(t)?)
+        } else {
+            None
+        };
+        let event = client
+            .set_optimistic_review(self.bounty_id.into(), threshold)
+            .await?;
+        match event.rejection_threshold {
+            Some(t) => {
+                println!(
+                    "BountyId {} pays unrejected submissions after review, contributors with {:?} of contributions may reject",
+                    event.bounty_id, t
+                )
+            }
+            None => {
+                println!(
+                    "BountyId {} no longer pays submissions optimistically",
+                    event.bounty_id
+                )
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyMinReputationCommand {
+    pub bounty_id: u64,
+    /// Reputation score required to submit, omit to allow any account
+    pub min_score: Option<u32>,
+}
+
+impl BountyMinReputationCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        let event = client
+            .set_min_reputation(self.bounty_id.into(), self.min_score)
+            .await?;
+        match event.min_score {
+            Some(s) => {
+                println!(
+                    "BountyId {} requires a reputation score of {} to submit",
+                    event.bounty_id, s
+                )
+            }
+            None => {
+                println!(
+                    "BountyId {} no longer requires reputation to submit",
+                    event.bounty_id
+                )
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyRejectCommand {
+    pub submission_id: u64,
+}
+
+impl BountyRejectCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::SubmissionId: From<u64> + Display,
+        <N::Runtime as Bounty>::BountyId: Display,
+    {
+        let event = client
+            .reject_bounty_submission(self.submission_id.into())
+            .await?;
+        println!(
+            "Rejected SubmissionId {} for BountyId {}",
+            event.submission_id, event.bounty_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyRejectSpamCommand {
+    pub submission_id: u64,
+}
+
+impl BountyRejectSpamCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::SubmissionId: From<u64> + Display,
+        <N::Runtime as Bounty>::BountyId: Display,
+    {
+        let event = client
+            .reject_bounty_submission_as_spam(self.submission_id.into())
+            .await?;
+        println!(
+            "Rejected SubmissionId {} for BountyId {} as spam, slashing {:?} to the bounty",
+            event.submission_id, event.bounty_id, event.slashed
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyWithdrawSubmissionCommand {
+    pub submission_id: u64,
+}
+
+impl BountyWithdrawSubmissionCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Bounty>::SubmissionId: From<u64> + Display,
+        <N::Runtime as Bounty>::BountyId: Display,
+    {
+        let event = client
+            .withdraw_bounty_submission(self.submission_id.into())
+            .await?;
+        println!(
+            "AccountId {} withdrew SubmissionId {} for BountyId {}",
+            event.submitter, event.submission_id, event.bounty_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyTriggerReviewCommand {
+    pub submission_id: u64,
+}
+
+impl BountyTriggerReviewCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::SubmissionId: From<u64> + Display,
+        <N::Runtime as Bounty>::BountyId: Display,
+        <N::Runtime as Vote>::VoteId: Display,
+    {
+        let event = client
+            .trigger_submission_review(self.submission_id.into())
+            .await?;
+        println!(
+            "Review of SubmissionId {} for BountyId {} opened as VoteId {}",
+            event.submission_id, event.bounty_id, event.vote_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyDisputeResolutionCommand {
+    pub bounty_id: u64,
+    pub org: u64,
+    pub signal_threshold: u64,
+}
+
+impl BountyDisputeResolutionCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+        <N::Runtime as Org>::OrgId: From<u64>,
+        <N::Runtime as Vote>::Signal: From<u64>,
+    {
+        let resolution = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(self.org.into()),
+            Threshold::new(self.signal_threshold.into(), None),
+            None,
+        ));
+        let event = client
+            .set_dispute_resolution(self.bounty_id.into(), resolution)
+            .await?;
+        println!(
+            "Disputes over submissions for BountyId {} are resolved by Org {}",
+            event.bounty_id, self.org
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyEscalateCommand {
+    pub submission_id: u64,
+}
+
+impl BountyEscalateCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Balances>::Balance: Display,
+        <N::Runtime as Bounty>::SubmissionId: From<u64> + Display,
+        <N::Runtime as Bounty>::BountyId: Display,
+        <N::Runtime as Bounty>::DisputeId: Display,
+        <N::Runtime as Vote>::VoteId: Display,
+    {
+        let event = client
+            .escalate_bounty_submission(self.submission_id.into())
+            .await?;
+        println!(
+            "SubmissionId {} for BountyId {} escalated to DisputeId {} with {} locked, resolved by VoteId {}",
+            event.submission_id,
+            event.bounty_id,
+            event.dispute_id,
+            event.locked,
+            event.vote_id
+        );
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Clap)]
 pub struct GetBountyCommand {
     pub bounty_id: u64,
@@ -268,6 +921,120 @@ impl GetOpenBountiesCommand {
     }
 }
 
+#[derive(Clone, Debug, Clap)]
+pub struct BountyLabelCommand {
+    pub bounty_id: u64,
+    /// Repository as owner/name
+    #[clap(long)]
+    pub repo: Option<String>,
+    #[clap(long)]
+    pub tag: Vec<String>,
+}
+
+impl BountyLabelCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty,
+        <N::Runtime as Bounty>::BountyId: From<u64> + Display,
+    {
+        let event = client
+            .label_bounty(
+                self.bounty_id.into(),
+                self.repo.clone(),
+                self.tag.clone(),
+            )
+            .await?;
+        println!(
+            "BountyId {} labeled with {} tag(s), repository set: {}",
+            event.bounty_id,
+            event.tags.len(),
+            event.repo.is_some()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct GetBountiesCommand {
+    /// Repository as owner/name
+    #[clap(long)]
+    pub repo: Option<String>,
+    #[clap(long)]
+    pub tag: Option<String>,
+    #[clap(long)]
+    pub depositer: Option<String>,
+    /// List bounties after this one in index order
+    #[clap(long)]
+    pub after: Option<u64>,
+    #[clap(long, default_value = "20")]
+    pub limit: u32,
+}
+
+impl GetBountiesCommand {
+    pub async fn exec<N: Node, C: BountyClient<N>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        N::Runtime: Bounty<IpfsReference = sunshine_codec::Cid>,
+        C::OffchainClient: Cache<OffchainConfig<N>, DagCborCodec, GithubIssue>,
+        <N::Runtime as System>::AccountId: Ss58Codec,
+        <N::Runtime as Balances>::Balance: Display,
+        <N::Runtime as Bounty>::BountyId: Display + From<u64>,
+    {
+        let after = self.after.map(|a| a.into());
+        let bounties = match (&self.repo, &self.tag, &self.depositer) {
+            (Some(repo), None, None) => {
+                client
+                    .bounties_by_repo(repo.clone(), after, self.limit)
+                    .await?
+            }
+            (None, Some(tag), None) => {
+                client
+                    .bounties_by_tag(tag.clone(), after, self.limit)
+                    .await?
+            }
+            (None, None, Some(acc)) => {
+                let depositer: Ss58<N::Runtime> = acc.parse()?;
+                client
+                    .bounties_by_depositer(depositer.0, after, self.limit)
+                    .await?
+            }
+            _ => return Err(BountyFilterInputError.into()),
+        };
+        if bounties.is_empty() {
+            println!("No bounties match the filter");
+        }
+        for (id, bounty) in bounties.into_iter() {
+            let event_cid = bounty.info();
+            match client.offchain_client().get(&event_cid).await {
+                Ok(bounty_body) => {
+                    println!(
+                        "BountyID {} has total available balance {} at {} added by {}",
+                        id,
+                        bounty.total(),
+                        format!(
+                            "https://github.com/{}/{}/issues/{}",
+                            bounty_body.repo_owner,
+                            bounty_body.repo_name,
+                            bounty_body.issue_number
+                        ),
+                        bounty.depositer().to_ss58check()
+                    );
+                }
+                Err(e) => {
+                    eprintln!("Error while getting bounty {}. skipping..", id);
+                    eprintln!("{}", e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Clap)]
 pub struct GetOpenSubmissionsCommand {
     pub bounty_id: u64,
@@ -11,3 +11,11 @@ pub struct PostBountyInputError;
 #[derive(Debug, Error)]
 #[error("Invalid Github Issue Url.")]
 pub struct InvalidGithubIssueUrl;
+
+#[derive(Debug, Error)]
+#[error("Payouts must be given as SubmissionId:Amount.")]
+pub struct InvalidPayoutInput;
+
+#[derive(Debug, Error)]
+#[error("Exactly one of --repo, --tag or --depositer must be given.")]
+pub struct BountyFilterInputError;
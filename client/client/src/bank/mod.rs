@@ -5,13 +5,24 @@ pub use subxt::*;
 use crate::{
     error::Error,
     org::Org,
+    tokens::{
+        AccountsStoreExt,
+        Tokens,
+        NATIVE_CURRENCY_ID,
+    },
 };
 use substrate_subxt::{
-    system::System,
+    balances::AccountData,
+    sp_runtime::traits::Saturating,
+    system::{
+        AccountStoreExt,
+        System,
+    },
     Runtime,
     SignedExtension,
     SignedExtra,
 };
+use sunshine_bounty_utils::bank::BankBalance;
 use sunshine_client_utils::{
     async_trait,
     Client,
@@ -31,11 +42,18 @@ where
         bank_operator: Option<<N::Runtime as System>::AccountId>,
         threshold: Threshold<N::Runtime>,
     ) -> Result<AccountOpenedEvent<N::Runtime>>;
+    async fn deposit(
+        &self,
+        bank_id: <N::Runtime as Bank>::BankId,
+        currency_id: <N::Runtime as Tokens>::CurrencyId,
+        amount: BalanceOf<N::Runtime>,
+    ) -> Result<DepositedEvent<N::Runtime>>;
     async fn propose_spend(
         &self,
         bank_id: <N::Runtime as Bank>::BankId,
+        currency_id: <N::Runtime as Tokens>::CurrencyId,
         amount: BalanceOf<N::Runtime>,
-        dest: <N::Runtime as System>::AccountId,
+        dest: SpendDst<N::Runtime>,
     ) -> Result<SpendProposedEvent<N::Runtime>>;
     async fn trigger_vote(
         &self,
@@ -51,6 +69,11 @@ where
         &self,
         bank_id: <N::Runtime as Bank>::BankId,
     ) -> Result<AccountClosedEvent<N::Runtime>>;
+    async fn propose_change(
+        &self,
+        bank_id: <N::Runtime as Bank>::BankId,
+        change: BankChg<N::Runtime>,
+    ) -> Result<BankChangeProposedEvent<N::Runtime>>;
     async fn bank(
         &self,
         bank_id: <N::Runtime as Bank>::BankId,
@@ -64,6 +87,17 @@ where
         &self,
         org: <N::Runtime as Org>::OrgId,
     ) -> Result<Option<Vec<(<N::Runtime as Bank>::BankId, BankSt<N::Runtime>)>>>;
+    async fn bank_balances(
+        &self,
+        bank_id: <N::Runtime as Bank>::BankId,
+        currency_id: <N::Runtime as Tokens>::CurrencyId,
+    ) -> Result<BankBalance<BalanceOf<N::Runtime>>>;
+    async fn bank_history(
+        &self,
+        bank_id: <N::Runtime as Bank>::BankId,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<(u32, LedgerEnt<N::Runtime>)>>;
 }
 
 #[async_trait]
@@ -73,6 +107,7 @@ where
     N::Runtime: Bank,
     <<<N::Runtime as Runtime>::Extra as SignedExtra<N::Runtime>>::Extra as SignedExtension>::AdditionalSigned:
         Send + Sync,
+    <N::Runtime as System>::AccountData: Into<AccountData<BalanceOf<N::Runtime>>>,
     C: Client<N>,
 {
     async fn open(
@@ -95,15 +130,35 @@ where
             .account_opened()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn deposit(
+        &self,
+        bank_id: <N::Runtime as Bank>::BankId,
+        currency_id: <N::Runtime as Tokens>::CurrencyId,
+        amount: BalanceOf<N::Runtime>,
+    ) -> Result<DepositedEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .deposit_and_watch(&signer, bank_id, currency_id, amount)
+            .await?
+            .deposited()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn propose_spend(
         &self,
         bank_id: <N::Runtime as Bank>::BankId,
+        currency_id: <N::Runtime as Tokens>::CurrencyId,
         amount: BalanceOf<N::Runtime>,
-        dest: <N::Runtime as System>::AccountId,
+        dest: SpendDst<N::Runtime>,
     ) -> Result<SpendProposedEvent<N::Runtime>> {
         let signer = self.chain_signer()?;
         self.chain_client()
-            .propose_spend_and_watch(&signer, bank_id, amount, dest)
+            .propose_spend_and_watch(
+                &signer,
+                bank_id,
+                currency_id,
+                amount,
+                dest,
+            )
             .await?
             .spend_proposed()?
             .ok_or_else(|| Error::EventNotFound.into())
@@ -143,6 +198,18 @@ where
             .account_closed()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn propose_change(
+        &self,
+        bank_id: <N::Runtime as Bank>::BankId,
+        change: BankChg<N::Runtime>,
+    ) -> Result<BankChangeProposedEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .propose_change_and_watch(&signer, bank_id, change)
+            .await?
+            .bank_change_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn bank(&self, bank_id: <N::Runtime as Bank>::BankId) -> Result<BankSt<N::Runtime>> {
         Ok(self.chain_client().banks(bank_id, None).await?)
     }
@@ -173,4 +240,52 @@ where
             Ok(Some(banks_for_org))
         }
     }
+    async fn bank_balances(
+        &self,
+        bank_id: <N::Runtime as Bank>::BankId,
+        currency_id: <N::Runtime as Tokens>::CurrencyId,
+    ) -> Result<BankBalance<BalanceOf<N::Runtime>>> {
+        let bank_account = bank_account_id::<N::Runtime>(bank_id);
+        // the native asset is held by the balances module
+        let total = if currency_id == NATIVE_CURRENCY_ID.into() {
+            let account: AccountData<BalanceOf<N::Runtime>> = self
+                .chain_client()
+                .account(&bank_account, None)
+                .await?
+                .data
+                .into();
+            account.free.saturating_add(account.reserved)
+        } else {
+            let account = self
+                .chain_client()
+                .accounts(&bank_account, currency_id, None)
+                .await?;
+            account.free.saturating_add(account.reserved)
+        };
+        let committed = self
+            .chain_client()
+            .committed(bank_id, currency_id, None)
+            .await?;
+        Ok(BankBalance::new(
+            total.saturating_sub(committed),
+            committed,
+            total,
+        ))
+    }
+    async fn bank_history(
+        &self,
+        bank_id: <N::Runtime as Bank>::BankId,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<(u32, LedgerEnt<N::Runtime>)>> {
+        let len = self.chain_client().ledger_len(bank_id, None).await?;
+        let end = start.saturating_add(limit).min(len);
+        let mut entries = Vec::new();
+        for index in start..end {
+            let entry =
+                self.chain_client().ledger(bank_id, index, None).await?;
+            entries.push((index, entry));
+        }
+        Ok(entries)
+    }
 }
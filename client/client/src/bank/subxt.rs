@@ -7,6 +7,10 @@ use crate::{
         Org,
         OrgEventsDecoder,
     },
+    tokens::{
+        Tokens,
+        TokensEventsDecoder,
+    },
     vote::{
         Vote,
         VoteEventsDecoder,
@@ -18,11 +22,15 @@ use parity_scale_codec::{
     Decode,
     Encode,
 };
-use sp_runtime::traits::{
-    AtLeast32Bit,
-    MaybeSerializeDeserialize,
-    Member,
-    Zero,
+use sp_runtime::{
+    traits::{
+        AccountIdConversion,
+        AtLeast32Bit,
+        MaybeSerializeDeserialize,
+        Member,
+        Zero,
+    },
+    ModuleId,
 };
 use std::fmt::Debug;
 use substrate_subxt::{
@@ -42,7 +50,12 @@ use substrate_subxt::{
 };
 use sunshine_bounty_utils::{
     bank::{
+        Asset,
+        BankChange,
         BankState,
+        ChangeProposal,
+        LedgerEntry,
+        SpendDest,
         SpendProposal,
         SpendState,
     },
@@ -54,6 +67,7 @@ use sunshine_bounty_utils::{
 };
 
 pub type BalanceOf<T> = <T as Balances>::Balance;
+pub type AssetOf<T> = Asset<<T as Tokens>::CurrencyId, BalanceOf<T>>;
 pub type BankSt<T> = BankState<
     <T as Bank>::BankId,
     <T as System>::AccountId,
@@ -64,16 +78,35 @@ pub type Threshold<T> = ThresholdInput<
     OrgRep<<T as Org>::OrgId>,
     XorThreshold<<T as Vote>::Signal, <T as Vote>::Percent>,
 >;
+/// Must match `BigBank` in the runtime's `bank::Trait` config
+pub const BIG_BANK: ModuleId = ModuleId(*b"big/bank");
+
+pub fn bank_account_id<T: Bank>(id: T::BankId) -> <T as System>::AccountId {
+    BIG_BANK.into_sub_account(id)
+}
+
+pub type SpendDst<T> =
+    SpendDest<<T as System>::AccountId, <T as Bank>::BankId, <T as Org>::OrgId>;
+pub type BankChg<T> =
+    BankChange<<T as System>::AccountId, <T as Vote>::ThresholdId>;
+pub type ChangeProp<T> =
+    ChangeProposal<<T as System>::AccountId, BankChg<T>, <T as Vote>::VoteId>;
+pub type LedgerEnt<T> = LedgerEntry<
+    SpendDst<T>,
+    <T as Bank>::SpendId,
+    AssetOf<T>,
+    <T as System>::BlockNumber,
+>;
 pub type SpendProp<T> = SpendProposal<
     <T as Bank>::BankId,
     <T as Bank>::SpendId,
-    BalanceOf<T>,
-    <T as System>::AccountId,
+    AssetOf<T>,
+    SpendDst<T>,
     SpendState<<T as Vote>::VoteId>,
 >;
 
 #[module]
-pub trait Bank: System + Balances + Org + Vote + Donate {
+pub trait Bank: System + Balances + Org + Vote + Donate + Tokens {
     type BankId: Parameter
         + Member
         + AtLeast32Bit
@@ -113,6 +146,33 @@ pub struct SpendProposalsStore<T: Bank> {
     pub spend_id: T::SpendId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct CommittedStore<T: Bank> {
+    #[store(returns = BalanceOf<T>)]
+    pub bank_id: T::BankId,
+    pub currency_id: <T as Tokens>::CurrencyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BankChangesStore<T: Bank> {
+    #[store(returns = ChangeProp<T>)]
+    pub bank_id: T::BankId,
+    pub change_id: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct LedgerStore<T: Bank> {
+    #[store(returns = LedgerEnt<T>)]
+    pub bank_id: T::BankId,
+    pub index: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct LedgerLenStore<T: Bank> {
+    #[store(returns = u32)]
+    pub bank_id: T::BankId,
+}
+
 // ~~ (Calls, Events) ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
@@ -132,11 +192,26 @@ pub struct AccountOpenedEvent<T: Bank> {
     pub bank_operator: Option<<T as System>::AccountId>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct DepositCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub currency_id: <T as Tokens>::CurrencyId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DepositedEvent<T: Bank> {
+    pub depositer: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub amount: AssetOf<T>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct ProposeSpendCall<T: Bank> {
     pub bank_id: T::BankId,
+    pub currency_id: <T as Tokens>::CurrencyId,
     pub amount: BalanceOf<T>,
-    pub dest: <T as System>::AccountId,
+    pub dest: SpendDst<T>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -144,8 +219,8 @@ pub struct SpendProposedEvent<T: Bank> {
     pub caller: <T as System>::AccountId,
     pub bank_id: T::BankId,
     pub spend_id: T::SpendId,
-    pub amount: BalanceOf<T>,
-    pub dest: <T as System>::AccountId,
+    pub amount: AssetOf<T>,
+    pub dest: SpendDst<T>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
@@ -182,6 +257,28 @@ pub struct ProposalPolledEvent<T: Bank> {
     pub state: SpendState<<T as Vote>::VoteId>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendExecutionFailedEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub amount: AssetOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendArchivedEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub amount: AssetOf<T>,
+    pub dest: SpendDst<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendExpiredEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub state: SpendState<<T as Vote>::VoteId>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct CloseCall<T: Bank> {
     pub bank_id: T::BankId,
@@ -193,3 +290,42 @@ pub struct AccountClosedEvent<T: Bank> {
     pub bank_id: T::BankId,
     pub org: <T as Org>::OrgId,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeChangeCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub change: BankChg<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BankChangeProposedEvent<T: Bank> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub change_id: u32,
+    pub change: BankChg<T>,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BankChangeEnactedEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub change_id: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BankChangeRejectedEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub change_id: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BankChangeFailedEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub change_id: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BankChangeExpiredEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub change_id: u32,
+}
@@ -1,18 +1,31 @@
 mod subxt;
 
-use crate::error::Error;
+use crate::{
+    error::Error,
+    org::Org,
+};
+use jsonrpsee::common::{
+    to_value as to_json_value,
+    Params,
+};
 use libipld::{
     cache::Cache,
     cbor::DagCborCodec,
 };
-use parity_scale_codec::Encode;
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
 use substrate_subxt::{
+    sp_core::Bytes,
+    sp_runtime::Permill,
     system::System,
     Runtime,
     SignedExtension,
     SignedExtra,
 };
 pub use subxt::*;
+use sunshine_bounty_utils::organization::OrgRep;
 use sunshine_client_utils::{
     async_trait,
     Client,
@@ -30,26 +43,128 @@ where
         &self,
         bounty: <N::Runtime as Bounty>::BountyPost,
         amount: BalanceOf<N::Runtime>,
+        deadline: Option<<N::Runtime as System>::BlockNumber>,
     ) -> Result<BountyPostedEvent<N::Runtime>>;
+    async fn post_governed_bounty(
+        &self,
+        bounty: <N::Runtime as Bounty>::BountyPost,
+        amount: BalanceOf<N::Runtime>,
+        deadline: Option<<N::Runtime as System>::BlockNumber>,
+        governance: GovernanceOf<N::Runtime>,
+    ) -> Result<BountyGovernedByOrgEvent<N::Runtime>>;
     async fn contribute_to_bounty(
         &self,
         bounty_id: <N::Runtime as Bounty>::BountyId,
         amount: BalanceOf<N::Runtime>,
     ) -> Result<BountyRaiseContributionEvent<N::Runtime>>;
+    async fn withdraw_contribution(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        amount: BalanceOf<N::Runtime>,
+    ) -> Result<BountyContributionWithdrawnEvent<N::Runtime>>;
     async fn submit_for_bounty(
         &self,
         bounty_id: <N::Runtime as Bounty>::BountyId,
         submission: <N::Runtime as Bounty>::BountySubmission,
         amount: BalanceOf<N::Runtime>,
     ) -> Result<BountySubmissionPostedEvent<N::Runtime>>;
+    async fn submit_for_bounty_as_team(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        submission: <N::Runtime as Bounty>::BountySubmission,
+        amount: BalanceOf<N::Runtime>,
+        team: OrgRep<<N::Runtime as Org>::OrgId>,
+    ) -> Result<BountySubmissionByTeamEvent<N::Runtime>>;
     async fn approve_bounty_submission(
         &self,
         submission_id: <N::Runtime as Bounty>::SubmissionId,
     ) -> Result<BountyPaymentExecutedEvent<N::Runtime>>;
+    async fn approve_bounty_submissions(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        payouts: Vec<(
+            <N::Runtime as Bounty>::SubmissionId,
+            BalanceOf<N::Runtime>,
+        )>,
+    ) -> Result<BountyPaymentExecutedEvent<N::Runtime>>;
+    async fn close_bounty(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+    ) -> Result<BountyClosedEvent<N::Runtime>>;
+    async fn reject_bounty_submission(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionRejectedEvent<N::Runtime>>;
+    async fn reject_bounty_submission_as_spam(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionRejectedAsSpamEvent<N::Runtime>>;
+    async fn withdraw_bounty_submission(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionWithdrawnEvent<N::Runtime>>;
+    async fn trigger_submission_review(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionReviewTriggeredEvent<N::Runtime>>;
+    async fn set_optimistic_review(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        rejection_threshold: Option<Permill>,
+    ) -> Result<BountyOptimisticReviewSetEvent<N::Runtime>>;
+    async fn set_min_reputation(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        min_score: Option<u32>,
+    ) -> Result<BountyMinReputationSetEvent<N::Runtime>>;
+    async fn label_bounty(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        repo: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<BountyLabeledEvent<N::Runtime>>;
+    async fn set_dispute_resolution(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        resolution: VoteMetadataOf<N::Runtime>,
+    ) -> Result<BountyDisputeResolutionSetEvent<N::Runtime>>;
+    async fn escalate_bounty_submission(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionEscalatedEvent<N::Runtime>>;
+    async fn claim_bounty(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+    ) -> Result<Option<BountyClaimedEvent<N::Runtime>>>;
+    async fn approve_bounty_claim(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        hunter: <N::Runtime as System>::AccountId,
+    ) -> Result<BountyClaimedEvent<N::Runtime>>;
+    async fn release_bounty_claim(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+    ) -> Result<()>;
+    async fn open_funding_round(
+        &self,
+        pool: BalanceOf<N::Runtime>,
+        cap: BalanceOf<N::Runtime>,
+        end: <N::Runtime as System>::BlockNumber,
+    ) -> Result<FundingRoundOpenedEvent<N::Runtime>>;
+    async fn add_bounty_to_funding_round(
+        &self,
+        round_id: <N::Runtime as Bounty>::RoundId,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+    ) -> Result<BountyAddedToFundingRoundEvent<N::Runtime>>;
     async fn bounty(
         &self,
         bounty_id: <N::Runtime as Bounty>::BountyId,
     ) -> Result<BountyState<N::Runtime>>;
+    async fn claim(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        hunter: <N::Runtime as System>::AccountId,
+    ) -> Result<Claim<N::Runtime>>;
     async fn submission(
         &self,
         submission_id: <N::Runtime as Bounty>::SubmissionId,
@@ -83,6 +198,29 @@ where
         &self,
         account_id: <N::Runtime as System>::AccountId,
     ) -> Result<Option<Vec<Contrib<N::Runtime>>>>;
+    async fn bounties_by_repo(
+        &self,
+        repo: String,
+        after: Option<<N::Runtime as Bounty>::BountyId>,
+        limit: u32,
+    ) -> Result<Vec<(<N::Runtime as Bounty>::BountyId, BountyState<N::Runtime>)>>;
+    async fn bounties_by_tag(
+        &self,
+        tag: String,
+        after: Option<<N::Runtime as Bounty>::BountyId>,
+        limit: u32,
+    ) -> Result<Vec<(<N::Runtime as Bounty>::BountyId, BountyState<N::Runtime>)>>;
+    async fn bounties_by_depositer(
+        &self,
+        depositer: <N::Runtime as System>::AccountId,
+        after: Option<<N::Runtime as Bounty>::BountyId>,
+        limit: u32,
+    ) -> Result<Vec<(<N::Runtime as Bounty>::BountyId, BountyState<N::Runtime>)>>;
+    async fn bounty_api_page(
+        &self,
+        method: &str,
+        args: Vec<u8>,
+    ) -> Result<Vec<(<N::Runtime as Bounty>::BountyId, BountyState<N::Runtime>)>>;
 }
 
 #[async_trait]
@@ -108,16 +246,46 @@ where
         &self,
         bounty: <N::Runtime as Bounty>::BountyPost,
         amount: BalanceOf<N::Runtime>,
+        deadline: Option<<N::Runtime as System>::BlockNumber>,
     ) -> Result<BountyPostedEvent<N::Runtime>> {
         let signer = self.chain_signer()?;
         let issue = Encode::encode(&bounty);
         let info = self.offchain_client().insert(bounty).await?;
         self.chain_client()
-            .post_bounty_and_watch(&signer, issue, info.into(), amount)
+            .post_bounty_and_watch(
+                &signer,
+                issue,
+                info.into(),
+                amount,
+                deadline,
+            )
             .await?
             .bounty_posted()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn post_governed_bounty(
+        &self,
+        bounty: <N::Runtime as Bounty>::BountyPost,
+        amount: BalanceOf<N::Runtime>,
+        deadline: Option<<N::Runtime as System>::BlockNumber>,
+        governance: GovernanceOf<N::Runtime>,
+    ) -> Result<BountyGovernedByOrgEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        let issue = Encode::encode(&bounty);
+        let info = self.offchain_client().insert(bounty).await?;
+        self.chain_client()
+            .post_governed_bounty_and_watch(
+                &signer,
+                issue,
+                info.into(),
+                amount,
+                deadline,
+                governance,
+            )
+            .await?
+            .bounty_governed_by_org()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn contribute_to_bounty(
         &self,
         bounty_id: <N::Runtime as Bounty>::BountyId,
@@ -130,6 +298,18 @@ where
             .bounty_raise_contribution()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn withdraw_contribution(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        amount: BalanceOf<N::Runtime>,
+    ) -> Result<BountyContributionWithdrawnEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .withdraw_contribution_and_watch(&signer, bounty_id, amount)
+            .await?
+            .bounty_contribution_withdrawn()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn submit_for_bounty(
         &self,
         bounty_id: <N::Runtime as Bounty>::BountyId,
@@ -151,6 +331,29 @@ where
             .bounty_submission_posted()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn submit_for_bounty_as_team(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        submission: <N::Runtime as Bounty>::BountySubmission,
+        amount: BalanceOf<N::Runtime>,
+        team: OrgRep<<N::Runtime as Org>::OrgId>,
+    ) -> Result<BountySubmissionByTeamEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        let issue = Encode::encode(&submission);
+        let submission_ref = self.offchain_client().insert(submission).await?;
+        self.chain_client()
+            .submit_for_bounty_as_team_and_watch(
+                &signer,
+                bounty_id,
+                issue,
+                submission_ref.into(),
+                amount,
+                team,
+            )
+            .await?
+            .bounty_submission_by_team()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn approve_bounty_submission(
         &self,
         submission_id: <N::Runtime as Bounty>::SubmissionId,
@@ -162,9 +365,211 @@ where
             .bounty_payment_executed()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn approve_bounty_submissions(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        payouts: Vec<(
+            <N::Runtime as Bounty>::SubmissionId,
+            BalanceOf<N::Runtime>,
+        )>,
+    ) -> Result<BountyPaymentExecutedEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .approve_bounty_submissions_and_watch(&signer, bounty_id, payouts)
+            .await?
+            .bounty_payment_executed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn close_bounty(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+    ) -> Result<BountyClosedEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .close_bounty_and_watch(&signer, bounty_id)
+            .await?
+            .bounty_closed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn reject_bounty_submission(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionRejectedEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .reject_bounty_submission_and_watch(&signer, submission_id)
+            .await?
+            .bounty_submission_rejected()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn reject_bounty_submission_as_spam(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionRejectedAsSpamEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .reject_bounty_submission_as_spam_and_watch(&signer, submission_id)
+            .await?
+            .bounty_submission_rejected_as_spam()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn withdraw_bounty_submission(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionWithdrawnEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .withdraw_bounty_submission_and_watch(&signer, submission_id)
+            .await?
+            .bounty_submission_withdrawn()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn trigger_submission_review(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionReviewTriggeredEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .trigger_submission_review_and_watch(&signer, submission_id)
+            .await?
+            .bounty_submission_review_triggered()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn set_optimistic_review(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        rejection_threshold: Option<Permill>,
+    ) -> Result<BountyOptimisticReviewSetEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .set_optimistic_review_and_watch(
+                &signer,
+                bounty_id,
+                rejection_threshold,
+            )
+            .await?
+            .bounty_optimistic_review_set()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn set_min_reputation(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        min_score: Option<u32>,
+    ) -> Result<BountyMinReputationSetEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .set_min_reputation_and_watch(&signer, bounty_id, min_score)
+            .await?
+            .bounty_min_reputation_set()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn label_bounty(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        repo: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<BountyLabeledEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        let repo = repo.map(String::into_bytes);
+        let tags = tags.into_iter().map(String::into_bytes).collect();
+        self.chain_client()
+            .label_bounty_and_watch(&signer, bounty_id, repo, tags)
+            .await?
+            .bounty_labeled()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn set_dispute_resolution(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        resolution: VoteMetadataOf<N::Runtime>,
+    ) -> Result<BountyDisputeResolutionSetEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .set_dispute_resolution_and_watch(&signer, bounty_id, resolution)
+            .await?
+            .bounty_dispute_resolution_set()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn escalate_bounty_submission(
+        &self,
+        submission_id: <N::Runtime as Bounty>::SubmissionId,
+    ) -> Result<BountySubmissionEscalatedEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .escalate_bounty_submission_and_watch(&signer, submission_id)
+            .await?
+            .bounty_submission_escalated()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    /// Returns `None` if the claim awaits approval by the bounty's reviewer
+    async fn claim_bounty(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+    ) -> Result<Option<BountyClaimedEvent<N::Runtime>>> {
+        let signer = self.chain_signer()?;
+        Ok(self
+            .chain_client()
+            .claim_bounty_and_watch(&signer, bounty_id)
+            .await?
+            .bounty_claimed()?)
+    }
+    async fn approve_bounty_claim(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        hunter: <N::Runtime as System>::AccountId,
+    ) -> Result<BountyClaimedEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .approve_bounty_claim_and_watch(&signer, bounty_id, hunter)
+            .await?
+            .bounty_claimed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn release_bounty_claim(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+    ) -> Result<()> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .release_bounty_claim_and_watch(&signer, bounty_id)
+            .await?;
+        Ok(())
+    }
+    async fn open_funding_round(
+        &self,
+        pool: BalanceOf<N::Runtime>,
+        cap: BalanceOf<N::Runtime>,
+        end: <N::Runtime as System>::BlockNumber,
+    ) -> Result<FundingRoundOpenedEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .open_funding_round_and_watch(&signer, pool, cap, end)
+            .await?
+            .funding_round_opened()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn add_bounty_to_funding_round(
+        &self,
+        round_id: <N::Runtime as Bounty>::RoundId,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+    ) -> Result<BountyAddedToFundingRoundEvent<N::Runtime>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .add_bounty_to_funding_round_and_watch(&signer, round_id, bounty_id)
+            .await?
+            .bounty_added_to_funding_round()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn bounty(&self, bounty_id: <N::Runtime as Bounty>::BountyId) -> Result<BountyState<N::Runtime>> {
         Ok(self.chain_client().bounties(bounty_id, None).await?)
     }
+    async fn claim(
+        &self,
+        bounty_id: <N::Runtime as Bounty>::BountyId,
+        hunter: <N::Runtime as System>::AccountId,
+    ) -> Result<Claim<N::Runtime>> {
+        Ok(self.chain_client().claims(bounty_id, hunter, None).await?)
+    }
     async fn submission(
         &self,
         submission_id: <N::Runtime as Bounty>::SubmissionId,
@@ -255,6 +660,60 @@ where
             Ok(Some(contributions_by_account))
         }
     }
+    async fn bounties_by_repo(
+        &self,
+        repo: String,
+        after: Option<<N::Runtime as Bounty>::BountyId>,
+        limit: u32,
+    ) -> Result<Vec<(<N::Runtime as Bounty>::BountyId, BountyState<N::Runtime>)>> {
+        self.bounty_api_page(
+            "bounties_by_repo",
+            (repo.into_bytes(), after, limit).encode(),
+        )
+        .await
+    }
+    async fn bounties_by_tag(
+        &self,
+        tag: String,
+        after: Option<<N::Runtime as Bounty>::BountyId>,
+        limit: u32,
+    ) -> Result<Vec<(<N::Runtime as Bounty>::BountyId, BountyState<N::Runtime>)>> {
+        self.bounty_api_page(
+            "bounties_by_tag",
+            (tag.into_bytes(), after, limit).encode(),
+        )
+        .await
+    }
+    async fn bounties_by_depositer(
+        &self,
+        depositer: <N::Runtime as System>::AccountId,
+        after: Option<<N::Runtime as Bounty>::BountyId>,
+        limit: u32,
+    ) -> Result<Vec<(<N::Runtime as Bounty>::BountyId, BountyState<N::Runtime>)>> {
+        self.bounty_api_page(
+            "bounties_by_depositer",
+            (depositer, after, limit).encode(),
+        )
+        .await
+    }
+    async fn bounty_api_page(
+        &self,
+        method: &str,
+        args: Vec<u8>,
+    ) -> Result<Vec<(<N::Runtime as Bounty>::BountyId, BountyState<N::Runtime>)>> {
+        // the runtime api pages through the index in a single call
+        let params = Params::Array(vec![
+            to_json_value(format!("BountyApi_{}", method))?,
+            to_json_value(Bytes(args))?,
+        ]);
+        let page: Bytes = self
+            .chain_client()
+            .rpc_client()
+            .request("state_call", params)
+            .await?;
+        let page = Vec::<BountyState<N::Runtime>>::decode(&mut &page[..])?;
+        Ok(page.into_iter().map(|b| (b.id(), b)).collect())
+    }
 }
 
 #[cfg(test)]
@@ -314,7 +773,7 @@ mod tests {
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 124,
         };
-        let event = client.post_bounty(bounty, 10u128).await.unwrap();
+        let event = client.post_bounty(bounty, 10u128, None).await.unwrap();
         let expected_event = BountyPostedEvent {
             depositer: alice_account_id,
             amount: 10,
@@ -334,13 +793,13 @@ mod tests {
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 125,
         };
-        let event1 = client.post_bounty(bounty1, 10u128).await.unwrap();
+        let event1 = client.post_bounty(bounty1, 10u128, None).await.unwrap();
         let bounty2 = GithubIssue {
             repo_owner: "sunshine-protocol".to_string(),
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 126,
         };
-        let event2 = client.post_bounty(bounty2, 10u128).await.unwrap();
+        let event2 = client.post_bounty(bounty2, 10u128, None).await.unwrap();
         let bounties = client.open_bounties(9u128).await.unwrap().unwrap();
         assert_eq!(bounties.len(), 2);
         let expected_bounty1 = BountyInformation::new(
@@ -383,7 +842,7 @@ mod tests {
             .free;
         println!("{}", b);
 
-        let event1 = client.post_bounty(bounty, 1000).await.unwrap();
+        let event1 = client.post_bounty(bounty, 1000, None).await.unwrap();
         let expected_event1 = BountyPostedEvent {
             depositer: alice_account_id.clone(),
             amount: 1000,
@@ -1,3 +1,13 @@
+use crate::{
+    org::{
+        Org,
+        OrgEventsDecoder,
+    },
+    vote::{
+        Vote,
+        VoteEventsDecoder,
+    },
+};
 use frame_support::Parameter;
 use libipld::{
     cbor::DagCborCodec,
@@ -11,11 +21,14 @@ use parity_scale_codec::{
     Decode,
     Encode,
 };
-use sp_runtime::traits::{
-    AtLeast32Bit,
-    MaybeSerializeDeserialize,
-    Member,
-    Zero,
+use sp_runtime::{
+    traits::{
+        AtLeast32Bit,
+        MaybeSerializeDeserialize,
+        Member,
+        Zero,
+    },
+    Permill,
 };
 use std::fmt::Debug;
 use substrate_subxt::{
@@ -33,11 +46,19 @@ use substrate_subxt::{
     Event,
     Store,
 };
-use sunshine_bounty_utils::bounty::{
-    BountyInformation,
-    BountySubmission,
-    Contribution,
-    SubmissionState,
+use sunshine_bounty_utils::{
+    bounty::{
+        BountyClaim,
+        BountyInformation,
+        BountySubmission,
+        Contribution,
+        SubmissionState,
+    },
+    meta::{
+        ResolutionMetadata,
+        VoteMetadata,
+    },
+    organization::OrgRep,
 };
 use sunshine_faucet_client::{
     Faucet,
@@ -49,9 +70,17 @@ use sunshine_identity_client::{
 };
 
 pub type BalanceOf<T> = <T as Balances>::Balance;
+pub type VoteMetadataOf<T> = VoteMetadata<
+    OrgRep<<T as Org>::OrgId>,
+    <T as Vote>::Signal,
+    <T as Vote>::Percent,
+    <T as System>::BlockNumber,
+>;
+pub type GovernanceOf<T> =
+    ResolutionMetadata<<T as System>::AccountId, VoteMetadataOf<T>>;
 
 #[module]
-pub trait Bounty: System + Balances + Identity + Faucet {
+pub trait Bounty: System + Balances + Identity + Faucet + Org + Vote {
     /// Cid type
     type IpfsReference: Parameter + Member + Default;
 
@@ -98,6 +127,30 @@ pub trait Bounty: System + Balances + Identity + Faucet {
         + DagDecode<DagCborCodec>
         + Send
         + Sync;
+
+    type DisputeId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+
+    type RoundId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
 }
 
 // ~~ Storage ~~
@@ -114,7 +167,13 @@ pub type SubState<T> = BountySubmission<
     <T as Bounty>::IpfsReference,
     <T as System>::AccountId,
     BalanceOf<T>,
-    SubmissionState,
+    SubmissionState<<T as Vote>::VoteId>,
+>;
+pub type Claim<T> = BountyClaim<
+    <T as Bounty>::BountyId,
+    <T as System>::AccountId,
+    BalanceOf<T>,
+    <T as System>::BlockNumber,
 >;
 pub type Contrib<T> = Contribution<
     <T as Bounty>::BountyId,
@@ -128,6 +187,12 @@ pub struct BountiesStore<T: Bounty> {
     pub id: T::BountyId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyGovernanceStore<T: Bounty> {
+    #[store(returns = GovernanceOf<T>)]
+    pub id: T::BountyId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct SubmissionsStore<T: Bounty> {
     #[store(returns = SubState<T>)]
@@ -141,6 +206,31 @@ pub struct ContributionsStore<T: Bounty> {
     pub account: T::AccountId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct OptimisticReviewStore<T: Bounty> {
+    #[store(returns = Permill)]
+    pub id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct MinReputationStore<T: Bounty> {
+    #[store(returns = u32)]
+    pub id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct DisputeResolutionStore<T: Bounty> {
+    #[store(returns = VoteMetadataOf<T>)]
+    pub id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ClaimsStore<T: Bounty> {
+    #[store(returns = Claim<T>)]
+    pub id: T::BountyId,
+    pub hunter: T::AccountId,
+}
+
 // ~~ (Calls, Events) ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
@@ -148,6 +238,7 @@ pub struct PostBountyCall<T: Bounty> {
     pub issue: Vec<u8>,
     pub info: T::IpfsReference,
     pub amount: BalanceOf<T>,
+    pub deadline: Option<<T as System>::BlockNumber>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -158,6 +249,22 @@ pub struct BountyPostedEvent<T: Bounty> {
     pub description: T::IpfsReference,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PostGovernedBountyCall<T: Bounty> {
+    pub issue: Vec<u8>,
+    pub info: T::IpfsReference,
+    pub amount: BalanceOf<T>,
+    pub deadline: Option<<T as System>::BlockNumber>,
+    pub governance: GovernanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyGovernedByOrgEvent<T: Bounty> {
+    pub depositer: <T as System>::AccountId,
+    pub org: <T as Org>::OrgId,
+    pub bounty_id: T::BountyId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct ContributeToBountyCall<T: Bounty> {
     pub bounty_id: T::BountyId,
@@ -173,6 +280,21 @@ pub struct BountyRaiseContributionEvent<T: Bounty> {
     pub bounty_ref: T::IpfsReference,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct WithdrawContributionCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyContributionWithdrawnEvent<T: Bounty> {
+    pub contributor: <T as System>::AccountId,
+    pub amount: BalanceOf<T>,
+    pub bounty_id: T::BountyId,
+    pub total: BalanceOf<T>,
+    pub bounty_ref: T::IpfsReference,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct SubmitForBountyCall<T: Bounty> {
     pub bounty_id: T::BountyId,
@@ -191,6 +313,22 @@ pub struct BountySubmissionPostedEvent<T: Bounty> {
     pub submission_ref: T::IpfsReference,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SubmitForBountyAsTeamCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub issue: Vec<u8>,
+    pub submission_ref: T::IpfsReference,
+    pub amount: BalanceOf<T>,
+    pub team: OrgRep<<T as Org>::OrgId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionByTeamEvent<T: Bounty> {
+    pub submitter: <T as System>::AccountId,
+    pub id: T::SubmissionId,
+    pub org: <T as Org>::OrgId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct ApproveBountySubmissionCall<T: Bounty> {
     pub submission_id: T::SubmissionId,
@@ -206,3 +344,255 @@ pub struct BountyPaymentExecutedEvent<T: Bounty> {
     pub bounty_ref: T::IpfsReference,
     pub submission_ref: T::IpfsReference,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyExpiredEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub refunded: BalanceOf<T>,
+    pub bounty_ref: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ApproveBountySubmissionsCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub payouts: Vec<(T::SubmissionId, BalanceOf<T>)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CloseBountyCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyClosedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub refunded: BalanceOf<T>,
+    pub bounty_ref: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RejectBountySubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionRejectedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub submission_ref: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RejectBountySubmissionAsSpamCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionRejectedAsSpamEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub slashed: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct WithdrawBountySubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionWithdrawnEvent<T: Bounty> {
+    pub submitter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub submission_ref: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionExpiredEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub submission_ref: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct TriggerSubmissionReviewCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionReviewTriggeredEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ClaimBountyCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyClaimedEvent<T: Bounty> {
+    pub hunter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub bond: BalanceOf<T>,
+    pub expires: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyClaimRequestedEvent<T: Bounty> {
+    pub hunter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub bond: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ApproveBountyClaimCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub hunter: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ReleaseBountyClaimCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyClaimLapsedEvent<T: Bounty> {
+    pub hunter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub slashed: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyClaimReleasedEvent<T: Bounty> {
+    pub hunter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SetOptimisticReviewCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub rejection_threshold: Option<Permill>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyOptimisticReviewSetEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub rejection_threshold: Option<Permill>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SetMinReputationCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub min_score: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyMinReputationSetEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub min_score: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct LabelBountyCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub repo: Option<Vec<u8>>,
+    pub tags: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyLabeledEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub repo: Option<Vec<u8>>,
+    pub tags: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SetDisputeResolutionCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub resolution: VoteMetadataOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyDisputeResolutionSetEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct EscalateBountySubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionEscalatedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub locked: BalanceOf<T>,
+    pub dispute_id: T::DisputeId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionDisputeResolvedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub dispute_id: T::DisputeId,
+    pub accepted: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct OpenFundingRoundCall<T: Bounty> {
+    pub pool: BalanceOf<T>,
+    pub cap: BalanceOf<T>,
+    pub end: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct FundingRoundOpenedEvent<T: Bounty> {
+    pub sponsor: <T as System>::AccountId,
+    pub round_id: T::RoundId,
+    pub pool: BalanceOf<T>,
+    pub cap: BalanceOf<T>,
+    pub end: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct AddBountyToFundingRoundCall<T: Bounty> {
+    pub round_id: T::RoundId,
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyAddedToFundingRoundEvent<T: Bounty> {
+    pub round_id: T::RoundId,
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyMatchedEvent<T: Bounty> {
+    pub round_id: T::RoundId,
+    pub bounty_id: T::BountyId,
+    pub matched: BalanceOf<T>,
+    pub total: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct FundingRoundClosedEvent<T: Bounty> {
+    pub round_id: T::RoundId,
+    pub matched: BalanceOf<T>,
+    pub returned: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionDisputeDroppedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub dispute_id: T::DisputeId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyClaimRequestExpiredEvent<T: Bounty> {
+    pub hunter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+}
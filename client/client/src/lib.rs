@@ -6,6 +6,7 @@ pub mod bank;
 pub mod bounty;
 pub mod donate;
 pub mod org;
+pub mod tokens;
 pub mod vote;
 pub use sunshine_bounty_utils as utils;
 
@@ -0,0 +1,3 @@
+mod subxt;
+
+pub use subxt::*;
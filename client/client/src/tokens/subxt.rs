@@ -0,0 +1,58 @@
+use frame_support::Parameter;
+use parity_scale_codec::{
+    Codec,
+    Decode,
+    Encode,
+};
+use sp_runtime::traits::{
+    MaybeSerializeDeserialize,
+    Member,
+};
+use std::fmt::Debug;
+use substrate_subxt::{
+    balances::{
+        Balances,
+        BalancesEventsDecoder,
+    },
+    module,
+    sp_runtime,
+    system::{
+        System,
+        SystemEventsDecoder,
+    },
+    Store,
+};
+
+pub type BalanceOf<T> = <T as Balances>::Balance;
+/// Must match `GetNativeCurrencyId` in the runtime's `orml_currencies::Trait` config
+pub const NATIVE_CURRENCY_ID: u32 = 0;
+
+#[module]
+pub trait Tokens: System + Balances {
+    type CurrencyId: Parameter
+        + Member
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialEq
+        + From<u32>;
+}
+
+/// Balance of one non-native asset held by an account
+#[derive(Clone, Debug, Default, Eq, PartialEq, Encode, Decode)]
+pub struct AccountData<Balance> {
+    pub free: Balance,
+    pub reserved: Balance,
+    pub frozen: Balance,
+}
+
+// ~~ Maps ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct AccountsStore<'a, T: Tokens> {
+    #[store(returns = AccountData<BalanceOf<T>>)]
+    pub account: &'a <T as System>::AccountId,
+    pub currency_id: T::CurrencyId,
+}
@@ -247,12 +247,109 @@ where
             .client
             .read()
             .await
-            .post_bounty(bounty, amount.parse::<u64>()?.into())
+            .post_bounty(bounty, amount.parse::<u64>()?.into(), None)
             .await?;
         info!("Bounty Created: {:?}", event);
+        let repo = format!("{}/{}", repo_owner, repo_name);
+        if let Err(e) = self
+            .client
+            .read()
+            .await
+            .label_bounty(event.id, Some(repo), vec![])
+            .await
+        {
+            warn!("Bounty #{} posted but not indexed by repo", event.id);
+            error!("{:?}", e);
+        }
         Ok(event.id.into())
     }
 
+    pub async fn label(
+        &self,
+        bounty_id: &str,
+        repo: Option<&str>,
+        tags: Option<&str>,
+    ) -> Result<bool> {
+        info!("Labeling BountyId: {}", bounty_id);
+        let tags = tags
+            .map(|t| {
+                t.split(',')
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let event = self
+            .client
+            .read()
+            .await
+            .label_bounty(
+                bounty_id.parse::<u64>()?.into(),
+                repo.map(String::from),
+                tags,
+            )
+            .await?;
+        info!("Bounty Labeled: {:?}", event);
+        Ok(true)
+    }
+
+    pub async fn bounties_by_repo(
+        &self,
+        repo: &str,
+        after: Option<&str>,
+        limit: u32,
+    ) -> Result<String> {
+        info!("Getting Bounties for repo: {}", repo);
+        let list = self
+            .client
+            .read()
+            .await
+            .bounties_by_repo(
+                repo.to_string(),
+                Self::parse_after(after)?,
+                limit,
+            )
+            .await?;
+        self.bounty_info_list(list).await
+    }
+
+    pub async fn bounties_by_tag(
+        &self,
+        tag: &str,
+        after: Option<&str>,
+        limit: u32,
+    ) -> Result<String> {
+        info!("Getting Bounties with tag: {}", tag);
+        let list = self
+            .client
+            .read()
+            .await
+            .bounties_by_tag(tag.to_string(), Self::parse_after(after)?, limit)
+            .await?;
+        self.bounty_info_list(list).await
+    }
+
+    pub async fn bounties_by_depositer(
+        &self,
+        depositer: &str,
+        after: Option<&str>,
+        limit: u32,
+    ) -> Result<String> {
+        info!("Getting Bounties posted by: {}", depositer);
+        let list = self
+            .client
+            .read()
+            .await
+            .bounties_by_depositer(
+                depositer.parse::<Ss58<N::Runtime>>()?.0,
+                Self::parse_after(after)?,
+                limit,
+            )
+            .await?;
+        self.bounty_info_list(list).await
+    }
+
     pub async fn contribute(
         &self,
         bounty_id: &str,
@@ -497,6 +594,38 @@ where
         }
     }
 
+    fn parse_after(
+        after: Option<&str>,
+    ) -> Result<Option<<N::Runtime as BountyTrait>::BountyId>> {
+        Ok(match after {
+            Some(a) => Some(a.parse::<u64>()?.into()),
+            None => None,
+        })
+    }
+
+    async fn bounty_info_list(
+        &self,
+        list: Vec<(
+            <N::Runtime as BountyTrait>::BountyId,
+            BountyState<N::Runtime>,
+        )>,
+    ) -> Result<String> {
+        let mut v = Vec::with_capacity(list.len());
+        for (id, state) in list {
+            match self.get_bounty_info(id, state).await {
+                Ok(info) => v.push(info),
+                Err(e) => {
+                    warn!(
+                        "I can't get the info of Bounty #{}. Skipping...",
+                        id
+                    );
+                    error!("{:?}", e);
+                }
+            }
+        }
+        Ok(serde_json::to_string(&v)?)
+    }
+
     async fn get_bounty_info(
         &self,
         id: <N::Runtime as BountyTrait>::BountyId,
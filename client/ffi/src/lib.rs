@@ -126,6 +126,34 @@ macro_rules! impl_bounty_ffi {
             Bounty::open_bounty_submissions => fn client_bounty_open_bounty_submissions(
                 bounty_id: *const raw::c_char = cstr!(bounty_id)
             ) -> JSON<Vec<BountySubmissionInformation>>;
+            /// Label a bounty with its repository (as `owner/name`) and comma separated tags.
+            /// Replaces any previous labels, returns `true` once labeled
+            Bounty::label => fn client_bounty_label(
+                bounty_id: *const raw::c_char = cstr!(bounty_id),
+                repo: *const raw::c_char = cstr!(repo, allow_null),
+                tags: *const raw::c_char = cstr!(tags, allow_null)
+            ) -> bool;
+            /// Get a page of bounties for a repository (as `owner/name`), after the given `BountyId` (if any).
+            /// Returns a JSON encoded list of `BountyInformation` as string.
+            Bounty::bounties_by_repo => fn client_bounty_bounties_by_repo(
+                repo: *const raw::c_char = cstr!(repo),
+                after: *const raw::c_char = cstr!(after, allow_null),
+                limit: u32 = limit
+            ) -> JSON<Vec<BountyInformation>>;
+            /// Get a page of bounties with a tag, after the given `BountyId` (if any).
+            /// Returns a JSON encoded list of `BountyInformation` as string.
+            Bounty::bounties_by_tag => fn client_bounty_bounties_by_tag(
+                tag: *const raw::c_char = cstr!(tag),
+                after: *const raw::c_char = cstr!(after, allow_null),
+                limit: u32 = limit
+            ) -> JSON<Vec<BountyInformation>>;
+            /// Get a page of bounties posted by an account, after the given `BountyId` (if any).
+            /// Returns a JSON encoded list of `BountyInformation` as string.
+            Bounty::bounties_by_depositer => fn client_bounty_bounties_by_depositer(
+                depositer: *const raw::c_char = cstr!(depositer),
+                after: *const raw::c_char = cstr!(after, allow_null),
+                limit: u32 = limit
+            ) -> JSON<Vec<BountyInformation>>;
         }
     };
 }
@@ -113,6 +113,43 @@ impl GBot {
             .await?;
         Ok(())
     }
+    /// Updates the bounty comment with the hunter's claim, or removes the
+    /// claim if `claimed_until` is `None`
+    pub async fn update_bounty_claim(
+        &self,
+        balance: u128,
+        bounty_id: u64,
+        hunter: String,
+        claimed_until: Option<u64>,
+        repo_owner: String,
+        repo_name: String,
+        issue_number: u64,
+    ) -> Result<()> {
+        let bounty_comment = self
+            .get_last_comment(
+                repo_owner.clone(),
+                repo_name.clone(),
+                issue_number,
+            )
+            .await?
+            .ok_or(Error::MustRefValidBountyIssue)?;
+        let claim = if let Some(block) = claimed_until {
+            format!(" 🌻 Claimed By: {} until block {}", hunter, block)
+        } else {
+            String::new()
+        };
+        let new_issues_handler = self.crab.issues(repo_owner, repo_name);
+        let _ = new_issues_handler
+            .update_comment(
+                bounty_comment.id,
+                format!(
+                    "## ☀️ Sunshine Bounty Posted ☀️ <br> BountyID: {} 🌻 Total Amount: {}{}",
+                    bounty_id, balance, claim,
+                ),
+            )
+            .await?;
+        Ok(())
+    }
     pub async fn new_submission_issue(
         &self,
         amount: u128,
@@ -187,4 +224,47 @@ impl GBot {
             .await?;
         Ok(())
     }
+    /// Updates the submission comment once the submission is rejected,
+    /// withdrawn or expires (`outcome` names which)
+    pub async fn close_submission_issue(
+        &self,
+        outcome: &str,
+        bounty_id: u64,
+        submission_id: u64,
+        bounty_repo_owner: String,
+        bounty_repo_name: String,
+        bounty_issue_number: u64,
+        submission_repo_owner: String,
+        submission_repo_name: String,
+        submission_issue_number: u64,
+    ) -> Result<()> {
+        let bounty_issue_ref = format!(
+            "{}/{}/{}/issues/{}",
+            GITHUB_BASE_URL,
+            bounty_repo_owner,
+            bounty_repo_name,
+            bounty_issue_number
+        );
+        let submission_comment = self
+            .get_last_comment(
+                submission_repo_owner.clone(),
+                submission_repo_name.clone(),
+                submission_issue_number,
+            )
+            .await?
+            .ok_or(Error::MustRefValidSubmissionIssue)?;
+        let new_issues_handler = self
+            .crab
+            .issues(submission_repo_owner, submission_repo_name);
+        let _ = new_issues_handler
+            .update_comment(
+                submission_comment.id,
+                format!(
+                    "## ☀️ Sunshine Submission {} ☀️ <br> BountyID: {} 🌻 SubmissionID: {} 🌻 [Bounty Issue]({})",
+                    outcome, bounty_id, submission_id, bounty_issue_ref,
+                ),
+            )
+            .await?;
+        Ok(())
+    }
 }
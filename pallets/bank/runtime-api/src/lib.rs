@@ -0,0 +1,17 @@
+//! Runtime API definition for the bank module
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use parity_scale_codec::Codec;
+use util::bank::BankBalance;
+
+sp_api::decl_runtime_apis! {
+    pub trait BankApi<BankId, CurrencyId, Balance> where
+        BankId: Codec,
+        CurrencyId: Codec,
+        Balance: Codec,
+    {
+        /// Free, committed and total balance of one asset held by the bank (None if the bank DNE)
+        fn bank_balances(bank_id: BankId, currency_id: CurrencyId) -> Option<BankBalance<Balance>>;
+    }
+}
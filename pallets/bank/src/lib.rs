@@ -24,18 +24,16 @@ use frame_support::{
     decl_storage,
     ensure,
     storage::{
+        with_transaction,
         IterableStorageDoubleMap,
         IterableStorageMap,
     },
-    traits::{
-        Currency,
-        ExistenceRequirement,
-        Get,
-        ReservableCurrency,
-    },
+    traits::Get,
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
+use orml_traits::MultiCurrency;
 use parity_scale_codec::Codec;
 use sp_runtime::{
     traits::{
@@ -43,12 +41,14 @@ use sp_runtime::{
         AtLeast32Bit,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
     DispatchResult,
     ModuleId,
     Permill,
+    TransactionOutcome,
 };
 use sp_std::{
     fmt::Debug,
@@ -56,7 +56,15 @@ use sp_std::{
 };
 use util::{
     bank::{
+        Asset,
+        BankBalance,
+        BankChange,
         BankState,
+        ChangeProposal,
+        LedgerEntry,
+        LedgerEntryKind,
+        QueuedProposal,
+        SpendDest,
         SpendProposal,
         SpendState,
     },
@@ -76,9 +84,13 @@ use util::{
 };
 
 // type aliases
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+type BalanceOf<T> = <<T as Trait>::Currency as MultiCurrency<
     <T as frame_system::Trait>::AccountId,
 >>::Balance;
+type CurrencyIdOf<T> = <<T as Trait>::Currency as MultiCurrency<
+    <T as frame_system::Trait>::AccountId,
+>>::CurrencyId;
+type AssetOf<T> = Asset<CurrencyIdOf<T>, BalanceOf<T>>;
 type BankSt<T> = BankState<
     <T as Trait>::BankId,
     <T as frame_system::Trait>::AccountId,
@@ -89,13 +101,34 @@ type Threshold<T> = ThresholdInput<
     OrgRep<<T as org::Trait>::OrgId>,
     XorThreshold<<T as vote::Trait>::Signal, Permill>,
 >;
+type SpendDst<T> = SpendDest<
+    <T as frame_system::Trait>::AccountId,
+    <T as Trait>::BankId,
+    <T as org::Trait>::OrgId,
+>;
 type SpendProp<T> = SpendProposal<
     <T as Trait>::BankId,
     <T as Trait>::SpendId,
-    BalanceOf<T>,
-    <T as frame_system::Trait>::AccountId,
+    AssetOf<T>,
+    SpendDst<T>,
     SpendState<<T as vote::Trait>::VoteId>,
 >;
+type BankChg<T> = BankChange<
+    <T as frame_system::Trait>::AccountId,
+    <T as vote::Trait>::ThresholdId,
+>;
+type ChangeProp<T> = ChangeProposal<
+    <T as frame_system::Trait>::AccountId,
+    BankChg<T>,
+    <T as vote::Trait>::VoteId,
+>;
+type Queued<T> = QueuedProposal<<T as Trait>::SpendId, u32>;
+type LedgerEnt<T> = LedgerEntry<
+    SpendDst<T>,
+    <T as Trait>::SpendId,
+    AssetOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
 
 pub trait Trait:
     frame_system::Trait + org::Trait + donate::Trait + vote::Trait
@@ -103,9 +136,11 @@ pub trait Trait:
     /// The overarching event types
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
-    /// The currency type for on-chain transactions
-    type Currency: Currency<Self::AccountId>
-        + ReservableCurrency<Self::AccountId>;
+    /// The assets which banks may hold
+    type Currency: MultiCurrency<Self::AccountId>;
+
+    /// The asset in which banks are opened
+    type NativeCurrencyId: Get<CurrencyIdOf<Self>>;
 
     /// The base bank account for this module
     type BigBank: Get<ModuleId>;
@@ -138,8 +173,14 @@ pub trait Trait:
 
     /// Max number of bank accounts for one org
     type MaxTreasuryPerOrg: Get<u32>;
-    /// Min to open bank account
+    /// Min to open bank account, in the native asset
     type MinDeposit: Get<BalanceOf<Self>>;
+    /// Blocks after which unsponsored spend proposals and failed votes expire
+    type SpendProposalExpiry: Get<Self::BlockNumber>;
+    /// Max number of spend and change proposal polls run in one block
+    type MaxProposalPollsPerBlock: Get<u32>;
+    /// Blocks between polls of a proposal under vote, unless its vote ends sooner
+    type ProposalPollFrequency: Get<Self::BlockNumber>;
 }
 
 decl_event!(
@@ -151,13 +192,30 @@ decl_event!(
         <T as Trait>::BankId,
         <T as Trait>::SpendId,
         Balance = BalanceOf<T>,
+        Funds = AssetOf<T>,
+        Dest = SpendDst<T>,
+        Change = BankChg<T>,
     {
         AccountOpened(AccountId, BankId, Balance, OrgId, Option<AccountId>),
-        SpendProposed(AccountId, BankId, SpendId, Balance, AccountId),
+        Deposited(AccountId, BankId, Funds),
+        SpendProposed(AccountId, BankId, SpendId, Funds, Dest),
         VoteTriggered(AccountId, BankId, SpendId, VoteId),
         SudoApproved(AccountId, BankId, SpendId),
         ProposalPolled(BankId, SpendId, SpendState<VoteId>),
+        /// Executed spend removed from storage, only this event remains
+        SpendArchived(BankId, SpendId, Funds, Dest),
+        /// Unsponsored or failed spend proposal removed from storage
+        SpendExpired(BankId, SpendId, SpendState<VoteId>),
+        /// Approved spend could not be executed so its commitment was released
+        SpendExecutionFailed(BankId, SpendId, Funds),
         AccountClosed(AccountId, BankId, OrgId),
+        BankChangeProposed(AccountId, BankId, u32, Change, VoteId),
+        BankChangeEnacted(BankId, u32),
+        BankChangeRejected(BankId, u32),
+        /// Approved change could not be enacted
+        BankChangeFailed(BankId, u32),
+        /// Change still under vote at its expiry removed from storage
+        BankChangeExpired(BankId, u32),
     }
 );
 
@@ -168,6 +226,7 @@ decl_error! {
         CommitteeCountExceedsLimitPerOrg,
         CannotCloseBankThatDNE,
         NotPermittedToOpenBankAccountForOrg,
+        CannotDepositToBankThatDNE,
         NotPermittedToProposeSpendForBankAccount,
         NotPermittedToTriggerVoteForBankAccount,
         NotPermittedToPollSpendProposalForBankAccount,
@@ -177,6 +236,13 @@ decl_error! {
         // spend proposal stuff
         CannotProposeSpendIfBankDNE,
         BankMustExistToProposeSpendFrom,
+        CannotProposeSpendToBankThatDNE,
+        CannotProposeSpendToSameBank,
+        CannotProposeSpendToOrgThatDNE,
+        CannotSpendToBankThatDNE,
+        CannotProposeSpendIfAmountExceedsFreeBankBalance,
+        CannotTriggerVoteIfAmountExceedsFreeBankBalance,
+        CannotSudoApproveIfAmountExceedsFreeBankBalance,
         CannotTriggerVoteForSpendIfBaseBankDNE,
         CannotTriggerVoteForSpendIfSpendProposalDNE,
         CannotTriggerVoteFromCurrentSpendProposalState,
@@ -185,6 +251,11 @@ decl_error! {
         CannotSudoApproveFromCurrentState,
         CannotPollSpendProposalIfBaseBankDNE,
         CannotPollSpendProposalIfSpendProposalDNE,
+        // bank change proposal stuff
+        CannotProposeChangeIfBankDNE,
+        NotPermittedToProposeChangeForBankAccount,
+        CannotChangeToThresholdThatDNE,
+        CannotEnactChangeIfBankDNE,
         // for getting banks for org
         NoBanksForOrg,
         ThresholdCannotBeSetForOrg,
@@ -212,12 +283,49 @@ decl_storage! {
         pub Banks get(fn banks): map
             hasher(blake2_128_concat) T::BankId => Option<BankSt<T>>;
 
+        /// Funds reserved for spends that are approved or under vote, per asset
+        pub Committed get(fn committed): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) CurrencyIdOf<T> => BalanceOf<T>;
+
+        /// Assets deposited into each bank, distributed when the bank closes
+        pub BankAssets get(fn bank_assets): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) CurrencyIdOf<T> => bool;
+
         /// Proposals to make spends from the bank account
         pub SpendProposals get(fn spend_proposals): double_map
             hasher(blake2_128_concat) T::BankId,
             hasher(blake2_128_concat) T::SpendId => Option<SpendProp<T>>;
-        /// Frequency for which all spend proposals are polled and pushed along
-        SpendPollFrequency get(fn spend_poll_frequency) config(): T::BlockNumber;
+
+        /// Live spend and change proposals keyed by the block of their next poll
+        /// -> value is the block at which a spend proposal expires unless it is under vote
+        ProposalSchedule get(fn proposal_schedule): double_map
+            hasher(twox_64_concat) T::BlockNumber,
+            hasher(blake2_128_concat) (T::BankId, Queued<T>) => Option<T::BlockNumber>;
+        /// Block of the next poll of each live proposal, one per proposal
+        ScheduledPolls get(fn scheduled_polls): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) Queued<T> => Option<T::BlockNumber>;
+        /// Earliest block whose scheduled polls may not have all run
+        ProposalCursor get(fn proposal_cursor): T::BlockNumber;
+
+        /// Counter for generating unique change proposal identifiers
+        ChangeNonce get(fn change_nonce): map
+            hasher(blake2_128_concat) T::BankId => u32;
+
+        /// Proposals to change a bank, enacted if the bank's threshold approves
+        pub BankChanges get(fn bank_changes): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(twox_64_concat) u32 => Option<ChangeProp<T>>;
+
+        /// Inflows and outflows of each bank, kept after the bank is closed
+        pub Ledger get(fn ledger): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(twox_64_concat) u32 => Option<LedgerEnt<T>>;
+        /// Number of entries in the ledger of each bank
+        pub LedgerLen get(fn ledger_len): map
+            hasher(blake2_128_concat) T::BankId => u32;
     }
 }
 
@@ -244,13 +352,35 @@ decl_module! {
             Ok(())
         }
         #[weight = 0]
+        fn deposit(
+            origin,
+            bank_id: T::BankId,
+            currency_id: CurrencyIdOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let depositer = ensure_signed(origin)?;
+            ensure!(Self::is_bank(bank_id), Error::<T>::CannotDepositToBankThatDNE);
+            <T as Trait>::Currency::transfer(
+                currency_id,
+                &depositer,
+                &Self::bank_account_id(bank_id),
+                amount,
+            )?;
+            let asset = Asset::new(currency_id, amount);
+            Self::record(bank_id, LedgerEntryKind::Deposit, SpendDest::Account(depositer.clone()), asset, None);
+            Self::deposit_event(RawEvent::Deposited(depositer, bank_id, asset));
+            Ok(())
+        }
+        #[weight = 0]
         fn propose_spend(
             origin,
             bank_id: T::BankId,
+            currency_id: CurrencyIdOf<T>,
             amount: BalanceOf<T>,
-            dest: T::AccountId,
+            dest: SpendDst<T>,
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
+            let amount = Asset::new(currency_id, amount);
             let new_spend_id = Self::_propose_spend(&caller, bank_id, amount, dest.clone())?;
             Self::deposit_event(RawEvent::SpendProposed(caller, bank_id, new_spend_id, amount, dest));
             Ok(())
@@ -289,30 +419,27 @@ decl_module! {
                 bank.is_controller(&closer),
                 Error::<T>::OnlyControllerCanCloseBank
             );
-            let bank_account_id = Self::bank_account_id(bank_id);
-            let remaining_funds = <T as donate::Trait>::Currency::total_balance(&bank_account_id);
-            // distributes remaining funds equally among members in proportion to ownership (PropDonation)
-            let _ = <donate::Module<T>>::donate(
-                &bank_account_id,
-                OrgRep::Weighted(bank.org()),
-                &closer,
-                remaining_funds,
-            )?;
-            <Banks<T>>::remove(bank_id);
-            <OrgTreasuryCount<T>>::mutate(bank.org(), |count| *count -= 1);
-            <TotalBankCount>::mutate(|count| *count -= 1);
-            Self::deposit_event(RawEvent::AccountClosed(closer, bank_id, bank.org()));
+            Self::close_bank(&closer, bank_id, bank)
+        }
+        #[weight = 0]
+        fn propose_change(
+            origin,
+            bank_id: T::BankId,
+            change: BankChg<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let (change_id, vote_id) = Self::propose_bank_change(&caller, bank_id, change.clone())?;
+            Self::deposit_event(RawEvent::BankChangeProposed(caller, bank_id, change_id, change, vote_id));
             Ok(())
         }
-        fn on_finalize(_n: T::BlockNumber) {
-            if <frame_system::Module<T>>::block_number() % Self::spend_poll_frequency() == Zero::zero() {
-                <SpendProposals<T>>::iter().for_each(|(_, _, prop)| {
-                    let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
-                    if let Ok(state) = Self::poll_spend_proposal(prop) {
-                        Self::deposit_event(RawEvent::ProposalPolled(bank_id, spend_id, state));
-                    }
-                });
-            }
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let (polls, steps) = Self::poll_scheduled_proposals(n);
+            // each poll reads the schedule entry, proposal, bank and vote and
+            // writes the schedule and its index twice, the proposal and at
+            // most two accounts
+            T::DbWeight::get().reads_writes(1, 1)
+                .saturating_add(T::DbWeight::get().reads(steps.into()))
+                .saturating_add(T::DbWeight::get().reads_writes(6, 9).saturating_mul(polls.into()))
         }
     }
 }
@@ -322,8 +449,41 @@ impl<T: Trait> Module<T> {
     pub fn bank_account_id(id: T::BankId) -> T::AccountId {
         T::BigBank::get().into_sub_account(id)
     }
-    pub fn bank_balance(bank: T::BankId) -> BalanceOf<T> {
-        <T as Trait>::Currency::total_balance(&Self::bank_account_id(bank))
+    pub fn bank_balance(
+        bank: T::BankId,
+        currency_id: CurrencyIdOf<T>,
+    ) -> BalanceOf<T> {
+        <T as Trait>::Currency::total_balance(
+            currency_id,
+            &Self::bank_account_id(bank),
+        )
+    }
+    /// Bank balance which is not committed to any spend
+    pub fn free_bank_balance(
+        bank: T::BankId,
+        currency_id: CurrencyIdOf<T>,
+    ) -> BalanceOf<T> {
+        Self::bank_balance(bank, currency_id)
+            .saturating_sub(<Committed<T>>::get(bank, currency_id))
+    }
+    fn is_covered_by_free_balance(bank: T::BankId, asset: AssetOf<T>) -> bool {
+        asset.balance() <= Self::free_bank_balance(bank, asset.currency_id())
+    }
+    pub fn bank_balances(
+        bank: T::BankId,
+        currency_id: CurrencyIdOf<T>,
+    ) -> Option<BankBalance<BalanceOf<T>>> {
+        if Self::is_bank(bank) {
+            let total = Self::bank_balance(bank, currency_id);
+            let committed = <Committed<T>>::get(bank, currency_id);
+            Some(BankBalance::new(
+                total.saturating_sub(committed),
+                committed,
+                total,
+            ))
+        } else {
+            None
+        }
     }
     pub fn is_bank(id: T::BankId) -> bool {
         <Banks<T>>::get(id).is_some()
@@ -362,6 +522,467 @@ impl<T: Trait> Module<T> {
     }
 }
 
+// Commitment helpers
+impl<T: Trait> Module<T> {
+    fn commit_funds(bank: T::BankId, asset: AssetOf<T>) {
+        <Committed<T>>::mutate(bank, asset.currency_id(), |c| {
+            *c = c.saturating_add(asset.balance())
+        });
+    }
+    fn release_funds(bank: T::BankId, asset: AssetOf<T>) {
+        <Committed<T>>::mutate(bank, asset.currency_id(), |c| {
+            *c = c.saturating_sub(asset.balance())
+        });
+    }
+    /// Stores the spend proposal unless it was executed, in which case it is
+    /// removed and archived in an event
+    fn put_spend(prop: SpendProp<T>) {
+        let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
+        if prop.state() == SpendState::ApprovedAndExecuted {
+            <SpendProposals<T>>::remove(bank_id, spend_id);
+            Self::deposit_event(RawEvent::SpendArchived(
+                bank_id,
+                spend_id,
+                prop.amount(),
+                prop.dest(),
+            ));
+        } else {
+            <SpendProposals<T>>::insert(bank_id, spend_id, prop);
+        }
+    }
+    /// Pays the spend to its destination and returns the amount which left
+    /// the bank
+    fn transfer_spend(
+        bank_id: T::BankId,
+        amount: AssetOf<T>,
+        dest: SpendDst<T>,
+    ) -> Result<AssetOf<T>, DispatchError> {
+        let bank_account = Self::bank_account_id(bank_id);
+        let currency_id = amount.currency_id();
+        match dest {
+            SpendDest::Account(account) => {
+                <T as Trait>::Currency::transfer(
+                    currency_id,
+                    &bank_account,
+                    &account,
+                    amount.balance(),
+                )?;
+                Ok(amount)
+            }
+            SpendDest::Bank(dest_bank) => {
+                ensure!(
+                    Self::is_bank(dest_bank),
+                    Error::<T>::CannotSpendToBankThatDNE
+                );
+                <T as Trait>::Currency::transfer(
+                    currency_id,
+                    &bank_account,
+                    &Self::bank_account_id(dest_bank),
+                    amount.balance(),
+                )?;
+                Ok(amount)
+            }
+            SpendDest::Org(org) => {
+                // rounding remainder stays in the bank
+                let (to_org, _) = <donate::Module<T>>::donate_asset::<
+                    <T as Trait>::Currency,
+                >(
+                    currency_id,
+                    &bank_account,
+                    org,
+                    &bank_account,
+                    amount.balance(),
+                )?;
+                Ok(Asset::new(currency_id, to_org))
+            }
+        }
+    }
+    /// Tries to execute the committed spend and releases the commitment
+    fn execute_spend(prop: SpendProp<T>) -> SpendProp<T> {
+        let (bank_id, spend_id, amount) =
+            (prop.bank_id(), prop.spend_id(), prop.amount());
+        Self::release_funds(bank_id, amount);
+        // donations make many transfers so all are reverted if one fails
+        let executed = with_transaction(|| {
+            match Self::transfer_spend(bank_id, amount, prop.dest()) {
+                Ok(sent) => TransactionOutcome::Commit(Some(sent)),
+                Err(_) => TransactionOutcome::Rollback(None),
+            }
+        });
+        if let Some(sent) = executed {
+            Self::record(
+                bank_id,
+                LedgerEntryKind::Spend,
+                prop.dest(),
+                sent,
+                Some(spend_id),
+            );
+            if let SpendDest::Bank(dest_bank) = prop.dest() {
+                Self::record(
+                    dest_bank,
+                    LedgerEntryKind::Deposit,
+                    SpendDest::Bank(bank_id),
+                    sent,
+                    Some(spend_id),
+                );
+            }
+            prop.set_state(SpendState::ApprovedAndExecuted)
+        } else {
+            Self::deposit_event(RawEvent::SpendExecutionFailed(
+                bank_id, spend_id, amount,
+            ));
+            prop.set_state(SpendState::ApprovedButNotExecuted)
+        }
+    }
+}
+
+// Ledger helpers
+impl<T: Trait> Module<T> {
+    fn record(
+        bank_id: T::BankId,
+        kind: LedgerEntryKind,
+        counterparty: SpendDst<T>,
+        amount: AssetOf<T>,
+        spend_id: Option<T::SpendId>,
+    ) {
+        if kind == LedgerEntryKind::Deposit {
+            <BankAssets<T>>::insert(bank_id, amount.currency_id(), true);
+        }
+        let index = <LedgerLen<T>>::get(bank_id);
+        let entry = LedgerEntry::new(
+            kind,
+            counterparty,
+            amount,
+            spend_id,
+            <frame_system::Module<T>>::block_number(),
+        );
+        <Ledger<T>>::insert(bank_id, index, entry);
+        <LedgerLen<T>>::insert(bank_id, index + 1);
+    }
+}
+
+// Proposal queue helpers
+impl<T: Trait> Module<T> {
+    fn expiry_from_now() -> T::BlockNumber {
+        <frame_system::Module<T>>::block_number()
+            .saturating_add(T::SpendProposalExpiry::get())
+    }
+    /// Schedules the only poll of a proposal, never earlier than the next
+    /// block, replacing the one scheduled before
+    fn schedule_proposal(
+        bank_id: T::BankId,
+        proposal: Queued<T>,
+        at: T::BlockNumber,
+        expiry: T::BlockNumber,
+    ) {
+        let next = <frame_system::Module<T>>::block_number() + 1u32.into();
+        let at = at.max(next);
+        if let Some(prev) = <ScheduledPolls<T>>::get(bank_id, proposal) {
+            <ProposalSchedule<T>>::remove(prev, (bank_id, proposal));
+        }
+        <ProposalSchedule<T>>::insert(at, (bank_id, proposal), expiry);
+        <ScheduledPolls<T>>::insert(bank_id, proposal, at);
+    }
+    /// Block of the next poll of a vote, once it ends or after the poll
+    /// frequency, whichever is sooner
+    fn next_vote_poll(vote_id: T::VoteId) -> T::BlockNumber {
+        let now = <frame_system::Module<T>>::block_number();
+        let at = now + T::ProposalPollFrequency::get().max(1u32.into());
+        // votes are accepted through their end block
+        match <vote::Module<T>>::vote_states(vote_id).and_then(|v| v.ends()) {
+            Some(ends) if ends >= now => at.min(ends + 1u32.into()),
+            _ => at,
+        }
+    }
+    /// Runs the polls scheduled up to `now`, at most MaxProposalPollsPerBlock
+    /// of them, and leaves the rest to the next block
+    /// -> returns the number of polls and of blocks stepped over
+    fn poll_scheduled_proposals(now: T::BlockNumber) -> (u32, u32) {
+        let mut budget = T::MaxProposalPollsPerBlock::get();
+        let mut cursor = <ProposalCursor<T>>::get();
+        let (mut polls, mut steps) = (0u32, 0u32);
+        while cursor <= now && budget > 0 {
+            let due = <ProposalSchedule<T>>::drain_prefix(cursor)
+                .take(budget as usize)
+                .collect::<Vec<_>>();
+            let polled = due.len() as u32;
+            for ((bank_id, proposal), expiry) in due {
+                <ScheduledPolls<T>>::remove(bank_id, proposal);
+                match proposal {
+                    QueuedProposal::Spend(spend_id) => {
+                        Self::poll_queued_spend(now, bank_id, spend_id, expiry)
+                    }
+                    QueuedProposal::Change(change_id) => {
+                        Self::poll_queued_change(
+                            now, bank_id, change_id, expiry,
+                        )
+                    }
+                }
+            }
+            polls += polled;
+            // the block is done unless the budget ran out first
+            if polled < budget {
+                cursor += 1u32.into();
+                steps += 1;
+            }
+            budget = budget.saturating_sub(polled.max(1));
+        }
+        <ProposalCursor<T>>::put(cursor);
+        (polls, steps)
+    }
+    /// Polls proposals under vote and expires the others once their expiry
+    /// passes, scheduling the next poll of live proposals
+    fn poll_queued_spend(
+        now: T::BlockNumber,
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+        expiry: T::BlockNumber,
+    ) {
+        // drop entries for proposals removed since they were queued
+        let prop = match <SpendProposals<T>>::get(bank_id, spend_id) {
+            Some(p) => p,
+            None => return,
+        };
+        let queued = QueuedProposal::Spend(spend_id);
+        match prop.state() {
+            SpendState::Voting(vote_id) => {
+                let amount = prop.amount();
+                match Self::poll_spend_proposal(prop) {
+                    Ok(SpendState::Voting(vote_id)) => {
+                        Self::schedule_proposal(
+                            bank_id,
+                            queued,
+                            Self::next_vote_poll(vote_id),
+                            expiry,
+                        )
+                    }
+                    Ok(state) => {
+                        if state != SpendState::ApprovedAndExecuted {
+                            // failed votes expire after a full period
+                            let expiry = Self::expiry_from_now();
+                            Self::schedule_proposal(
+                                bank_id, queued, expiry, expiry,
+                            );
+                        }
+                        Self::deposit_event(RawEvent::ProposalPolled(
+                            bank_id, spend_id, state,
+                        ));
+                    }
+                    // the vote is gone so the proposal can no longer pass
+                    Err(_) => {
+                        Self::release_funds(bank_id, amount);
+                        <SpendProposals<T>>::remove(bank_id, spend_id);
+                        Self::deposit_event(RawEvent::SpendExpired(
+                            bank_id,
+                            spend_id,
+                            SpendState::Voting(vote_id),
+                        ));
+                    }
+                }
+            }
+            state => {
+                if now >= expiry {
+                    <SpendProposals<T>>::remove(bank_id, spend_id);
+                    Self::deposit_event(RawEvent::SpendExpired(
+                        bank_id, spend_id, state,
+                    ));
+                } else {
+                    Self::schedule_proposal(bank_id, queued, expiry, expiry);
+                }
+            }
+        }
+    }
+    /// Enacts approved changes, drops rejected ones and expires the ones
+    /// still under vote once their expiry passes
+    fn poll_queued_change(
+        now: T::BlockNumber,
+        bank_id: T::BankId,
+        change_id: u32,
+        expiry: T::BlockNumber,
+    ) {
+        let prop = match <BankChanges<T>>::get(bank_id, change_id) {
+            Some(p) => p,
+            None => return,
+        };
+        match <vote::Module<T>>::get_vote_outcome(prop.vote_id()) {
+            Ok(VoteOutcome::Approved) => {
+                <BankChanges<T>>::remove(bank_id, change_id);
+                // closing the bank makes many transfers so revert all if one fails
+                let enacted = with_transaction(|| {
+                    match Self::enact_change(bank_id, prop) {
+                        Ok(()) => TransactionOutcome::Commit(true),
+                        Err(_) => TransactionOutcome::Rollback(false),
+                    }
+                });
+                if enacted {
+                    Self::deposit_event(RawEvent::BankChangeEnacted(
+                        bank_id, change_id,
+                    ));
+                } else {
+                    Self::deposit_event(RawEvent::BankChangeFailed(
+                        bank_id, change_id,
+                    ));
+                }
+            }
+            Ok(VoteOutcome::Rejected) => {
+                <BankChanges<T>>::remove(bank_id, change_id);
+                Self::deposit_event(RawEvent::BankChangeRejected(
+                    bank_id, change_id,
+                ));
+            }
+            Ok(_) => {
+                if now >= expiry {
+                    <BankChanges<T>>::remove(bank_id, change_id);
+                    Self::deposit_event(RawEvent::BankChangeExpired(
+                        bank_id, change_id,
+                    ));
+                } else {
+                    Self::schedule_proposal(
+                        bank_id,
+                        QueuedProposal::Change(change_id),
+                        Self::next_vote_poll(prop.vote_id()).min(expiry),
+                        expiry,
+                    )
+                }
+            }
+            // the vote is gone so the change can no longer pass
+            Err(_) => {
+                <BankChanges<T>>::remove(bank_id, change_id);
+                Self::deposit_event(RawEvent::BankChangeExpired(
+                    bank_id, change_id,
+                ));
+            }
+        }
+    }
+}
+
+// Bank governance helpers
+impl<T: Trait> Module<T> {
+    fn check_threshold_for_bank(
+        bank: &BankSt<T>,
+        threshold_id: T::ThresholdId,
+    ) -> DispatchResult {
+        let threshold = <vote::Module<T>>::vote_thresholds(threshold_id)
+            .ok_or(Error::<T>::CannotChangeToThresholdThatDNE)?;
+        ensure!(
+            threshold.org().org() == bank.org(),
+            Error::<T>::ThresholdCannotBeSetForOrg
+        );
+        Ok(())
+    }
+    fn propose_bank_change(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        change: BankChg<T>,
+    ) -> Result<(u32, T::VoteId), DispatchError> {
+        let bank = <Banks<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotProposeChangeIfBankDNE)?;
+        ensure!(
+            <org::Module<T>>::is_member_of_group(bank.org(), caller),
+            Error::<T>::NotPermittedToProposeChangeForBankAccount
+        );
+        if let BankChange::ChangeThreshold(threshold_id) = &change {
+            Self::check_threshold_for_bank(&bank, *threshold_id)?;
+        }
+        // dispatch vote with bank's current threshold
+        let vote_id = <vote::Module<T>>::invoke_threshold(
+            bank.threshold_id(),
+            None,
+            None,
+        )?;
+        let change_id = <ChangeNonce<T>>::get(bank_id) + 1;
+        <ChangeNonce<T>>::insert(bank_id, change_id);
+        <BankChanges<T>>::insert(
+            bank_id,
+            change_id,
+            ChangeProposal::new(caller.clone(), change, vote_id),
+        );
+        Self::schedule_proposal(
+            bank_id,
+            QueuedProposal::Change(change_id),
+            Self::next_vote_poll(vote_id),
+            Self::expiry_from_now(),
+        );
+        Ok((change_id, vote_id))
+    }
+    fn enact_change(bank_id: T::BankId, prop: ChangeProp<T>) -> DispatchResult {
+        let bank = <Banks<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotEnactChangeIfBankDNE)?;
+        match prop.change() {
+            BankChange::Close => {
+                Self::close_bank(&prop.proposer(), bank_id, bank)
+            }
+            BankChange::ReplaceController(controller) => {
+                <Banks<T>>::insert(bank_id, bank.set_controller(controller));
+                Ok(())
+            }
+            BankChange::ChangeThreshold(threshold_id) => {
+                Self::check_threshold_for_bank(&bank, threshold_id)?;
+                <Banks<T>>::insert(
+                    bank_id,
+                    bank.set_threshold_id(threshold_id),
+                );
+                Ok(())
+            }
+        }
+    }
+    /// Distributes the remaining funds of every asset to the org, the
+    /// rounding remainders to the closer, and removes the bank
+    fn close_bank(
+        closer: &T::AccountId,
+        bank_id: T::BankId,
+        bank: BankSt<T>,
+    ) -> DispatchResult {
+        let bank_account_id = Self::bank_account_id(bank_id);
+        let org = OrgRep::Weighted(bank.org());
+        for (currency_id, _) in <BankAssets<T>>::iter_prefix(bank_id) {
+            let remaining_funds = <T as Trait>::Currency::free_balance(
+                currency_id,
+                &bank_account_id,
+            );
+            // distributes remaining funds among members in proportion to ownership (PropDonation)
+            let (to_org, remainder) =
+                <donate::Module<T>>::donate_asset::<<T as Trait>::Currency>(
+                    currency_id,
+                    &bank_account_id,
+                    org,
+                    closer,
+                    remaining_funds,
+                )?;
+            Self::record(
+                bank_id,
+                LedgerEntryKind::CloseDistribution,
+                SpendDest::Org(org),
+                Asset::new(currency_id, to_org),
+                None,
+            );
+            if !remainder.is_zero() {
+                Self::record(
+                    bank_id,
+                    LedgerEntryKind::CloseDistribution,
+                    SpendDest::Account(closer.clone()),
+                    Asset::new(currency_id, remainder),
+                    None,
+                );
+            }
+        }
+        <Banks<T>>::remove(bank_id);
+        <Committed<T>>::remove_prefix(bank_id);
+        <BankAssets<T>>::remove_prefix(bank_id);
+        // scheduled polls of these proposals are dropped when they run
+        <SpendProposals<T>>::remove_prefix(bank_id);
+        <BankChanges<T>>::remove_prefix(bank_id);
+        <OrgTreasuryCount<T>>::mutate(bank.org(), |count| *count -= 1);
+        <TotalBankCount>::mutate(|count| *count -= 1);
+        Self::deposit_event(RawEvent::AccountClosed(
+            closer.clone(),
+            bank_id,
+            bank.org(),
+        ));
+        Ok(())
+    }
+}
+
 impl<T: Trait>
     OpenBankAccount<T::OrgId, BalanceOf<T>, T::AccountId, Threshold<T>>
     for Module<T>
@@ -378,8 +999,9 @@ impl<T: Trait>
             deposit >= T::MinDeposit::get(),
             Error::<T>::CannotOpenBankAccountIfDepositIsBelowModuleMinimum
         );
+        let native = T::NativeCurrencyId::get();
         ensure!(
-            <T as Trait>::Currency::free_balance(&opener) > deposit,
+            <T as Trait>::Currency::free_balance(native, &opener) > deposit,
             Error::<T>::InsufficientBalanceToFundBankOpen
         );
         let new_count = <OrgTreasuryCount<T>>::get(org) + 1;
@@ -400,13 +1022,20 @@ impl<T: Trait>
         let bank = BankState::new(id, org, controller, threshold_id);
         // perform fallible transfer
         <T as Trait>::Currency::transfer(
+            native,
             &opener,
             &Self::bank_account_id(id),
             deposit,
-            ExistenceRequirement::KeepAlive,
         )?;
         // insert new bank object
         <Banks<T>>::insert(id, bank);
+        Self::record(
+            id,
+            LedgerEntryKind::Deposit,
+            SpendDest::Account(opener),
+            Asset::new(native, deposit),
+            None,
+        );
         // put new org treasury count
         <OrgTreasuryCount<T>>::insert(org, new_count);
         // iterate total bank count
@@ -417,17 +1046,18 @@ impl<T: Trait>
 }
 
 impl<T: Trait>
-    SpendGovernance<T::BankId, BalanceOf<T>, T::AccountId, SpendProp<T>>
+    SpendGovernance<T::BankId, AssetOf<T>, T::AccountId, SpendProp<T>>
     for Module<T>
 {
     type SpendId = T::SpendId;
     type VoteId = T::VoteId;
     type SpendState = SpendState<T::VoteId>;
+    type SpendDest = SpendDst<T>;
     fn _propose_spend(
         caller: &T::AccountId,
         bank_id: T::BankId,
-        amount: BalanceOf<T>,
-        dest: T::AccountId,
+        amount: AssetOf<T>,
+        dest: SpendDst<T>,
     ) -> Result<Self::SpendId, DispatchError> {
         let bank = <Banks<T>>::get(bank_id)
             .ok_or(Error::<T>::BankMustExistToProposeSpendFrom)?;
@@ -435,9 +1065,39 @@ impl<T: Trait>
             <org::Module<T>>::is_member_of_group(bank.org(), caller),
             Error::<T>::NotPermittedToProposeSpendForBankAccount
         );
+        ensure!(
+            Self::is_covered_by_free_balance(bank_id, amount),
+            Error::<T>::CannotProposeSpendIfAmountExceedsFreeBankBalance
+        );
+        match &dest {
+            SpendDest::Bank(dest_bank) => {
+                ensure!(
+                    *dest_bank != bank_id,
+                    Error::<T>::CannotProposeSpendToSameBank
+                );
+                ensure!(
+                    Self::is_bank(*dest_bank),
+                    Error::<T>::CannotProposeSpendToBankThatDNE
+                );
+            }
+            SpendDest::Org(org) => {
+                ensure!(
+                    <org::Module<T>>::orgs(org.org()).is_some(),
+                    Error::<T>::CannotProposeSpendToOrgThatDNE
+                );
+            }
+            SpendDest::Account(_) => (),
+        }
         let id = Self::generate_spend_uid(bank_id);
         let proposal = SpendProposal::new(bank_id, id, amount, dest);
         <SpendProposals<T>>::insert(bank_id, id, proposal);
+        let expiry = Self::expiry_from_now();
+        Self::schedule_proposal(
+            bank_id,
+            QueuedProposal::Spend(id),
+            expiry,
+            expiry,
+        );
         Ok(id)
     }
     fn _trigger_vote_on_spend_proposal(
@@ -455,12 +1115,27 @@ impl<T: Trait>
             .ok_or(Error::<T>::CannotTriggerVoteForSpendIfSpendProposalDNE)?;
         match spend_proposal.state() {
             SpendState::WaitingForApproval => {
+                ensure!(
+                    Self::is_covered_by_free_balance(
+                        bank_id,
+                        spend_proposal.amount()
+                    ),
+                    Error::<T>::CannotTriggerVoteIfAmountExceedsFreeBankBalance
+                );
                 // dispatch vote with bank's default threshold
                 let new_vote_id = <vote::Module<T>>::invoke_threshold(
                     bank.threshold_id(),
                     None, // TODO: use vote info ref here instead of None
                     None,
                 )?;
+                // reserve funds for the duration of the vote
+                Self::commit_funds(bank_id, spend_proposal.amount());
+                Self::schedule_proposal(
+                    bank_id,
+                    QueuedProposal::Spend(spend_id),
+                    Self::next_vote_poll(new_vote_id),
+                    Self::expiry_from_now(),
+                );
                 let new_spend_proposal =
                     spend_proposal.set_state(SpendState::Voting(new_vote_id));
                 <SpendProposals<T>>::insert(
@@ -494,22 +1169,16 @@ impl<T: Trait>
         match spend_proposal.state() {
             SpendState::WaitingForApproval => {
                 // TODO: if Voting, remove the current live vote
-                let new_spend_proposal = if let Ok(()) =
-                    <T as Trait>::Currency::transfer(
-                        &Self::bank_account_id(bank_id),
-                        &spend_proposal.dest(),
-                        spend_proposal.amount(),
-                        ExistenceRequirement::KeepAlive,
-                    ) {
-                    spend_proposal.set_state(SpendState::ApprovedAndExecuted)
-                } else {
-                    spend_proposal.set_state(SpendState::ApprovedButNotExecuted)
-                };
-                <SpendProposals<T>>::insert(
-                    bank_id,
-                    spend_id,
-                    new_spend_proposal,
+                ensure!(
+                    Self::is_covered_by_free_balance(
+                        bank_id,
+                        spend_proposal.amount()
+                    ),
+                    Error::<T>::CannotSudoApproveIfAmountExceedsFreeBankBalance
                 );
+                Self::commit_funds(bank_id, spend_proposal.amount());
+                let new_spend_proposal = Self::execute_spend(spend_proposal);
+                Self::put_spend(new_spend_proposal);
                 Ok(())
             }
             _ => Err(Error::<T>::CannotSudoApproveFromCurrentState.into()),
@@ -528,29 +1197,19 @@ impl<T: Trait>
             SpendState::Voting(vote_id) => {
                 let vote_outcome =
                     <vote::Module<T>>::get_vote_outcome(vote_id)?;
-                if vote_outcome == VoteOutcome::Approved {
+                let new_spend_proposal = match vote_outcome {
                     // approved so try to execute and if not, still approve
-                    let new_spend_proposal = if let Ok(()) =
-                        <T as Trait>::Currency::transfer(
-                            &Self::bank_account_id(prop.bank_id()),
-                            &prop.dest(),
-                            prop.amount(),
-                            ExistenceRequirement::KeepAlive,
-                        ) {
-                        prop.set_state(SpendState::ApprovedAndExecuted)
-                    } else {
-                        prop.set_state(SpendState::ApprovedButNotExecuted)
-                    };
-                    let ret_state = new_spend_proposal.state();
-                    <SpendProposals<T>>::insert(
-                        prop.bank_id(),
-                        prop.spend_id(),
-                        new_spend_proposal,
-                    );
-                    Ok(ret_state)
-                } else {
-                    Ok(prop.state())
-                }
+                    VoteOutcome::Approved => Self::execute_spend(prop),
+                    // rejected so release the funds committed for the vote
+                    VoteOutcome::Rejected => {
+                        Self::release_funds(prop.bank_id(), prop.amount());
+                        prop.set_state(SpendState::Rejected)
+                    }
+                    _ => return Ok(prop.state()),
+                };
+                let ret_state = new_spend_proposal.state();
+                Self::put_spend(new_spend_proposal);
+                Ok(ret_state)
             }
             _ => Ok(prop.state()),
         }
@@ -5,10 +5,14 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
-    traits::OnFinalize,
+    traits::{
+        Currency,
+        OnInitialize,
+    },
     weights::Weight,
 };
 use frame_system::{self as system,};
+use orml_currencies::BasicCurrencyAdapter;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -34,6 +38,9 @@ use util::{
 pub type AccountId = u64;
 pub type BlockNumber = u64;
 
+const NATIVE: u32 = 0;
+const USD: u32 = 1;
+
 impl_outer_origin! {
     pub enum Origin for Test where system = frame_system {}
 }
@@ -46,6 +53,8 @@ impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
         pallet_balances<T>,
+        orml_tokens<T>,
+        orml_currencies<T>,
         org<T>,
         vote<T>,
         donate<T>,
@@ -101,6 +110,25 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const GetNativeCurrencyId: u32 = NATIVE;
+}
+impl orml_tokens::Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type Amount = i64;
+    type CurrencyId = u32;
+    type OnReceived = ();
+    type WeightInfo = ();
+}
+impl orml_currencies::Trait for Test {
+    type Event = TestEvent;
+    type MultiCurrency = Tokens;
+    type NativeCurrency =
+        BasicCurrencyAdapter<Test, Balances, i64, BlockNumber>;
+    type GetNativeCurrencyId = GetNativeCurrencyId;
+    type WeightInfo = ();
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type Cid = u32;
@@ -121,23 +149,42 @@ parameter_types! {
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
     pub const MaxTreasuryPerOrg: u32 = 50;
     pub const MinDeposit: u64 = 20;
+    pub const SpendProposalExpiry: u64 = 20;
+    pub const MaxProposalPollsPerBlock: u32 = 5;
+    pub const ProposalPollFrequency: u64 = 1;
 }
 impl Trait for Test {
     type Event = TestEvent;
-    type Currency = Balances;
+    type Currency = Currencies;
+    type NativeCurrencyId = GetNativeCurrencyId;
     type BigBank = BigBank;
     type BankId = u64;
     type SpendId = u64;
     type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
     type MinDeposit = MinDeposit;
+    type SpendProposalExpiry = SpendProposalExpiry;
+    type MaxProposalPollsPerBlock = MaxProposalPollsPerBlock;
+    type ProposalPollFrequency = ProposalPollFrequency;
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = orml_tokens::Module<Test>;
+pub type Currencies = orml_currencies::Module<Test>;
 pub type Org = org::Module<Test>;
 pub type Vote = vote::Module<Test>;
 pub type Bank = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64, u64> {
+fn get_last_event() -> RawEvent<
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    Asset<u32, u64>,
+    SpendDest<u64, u64, u64>,
+    BankChange<u64, u64>,
+> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -155,8 +202,8 @@ fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64, u64> {
 /// Auxiliary method for simulating block time passing
 fn run_to_block(n: u64) {
     while System::block_number() < n {
-        Bank::on_finalize(System::block_number());
         System::set_block_number(System::block_number() + 1);
+        Bank::on_initialize(System::block_number());
     }
 }
 
@@ -169,6 +216,11 @@ fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    orml_tokens::GenesisConfig::<Test> {
+        endowed_accounts: vec![(1, USD, 100), (2, USD, 50)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     org::GenesisConfig::<Test> {
         sudo: 1,
         doc: 1738,
@@ -176,11 +228,6 @@ fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
-    GenesisConfig::<Test> {
-        spend_poll_frequency: 10,
-    }
-    .assimilate_storage(&mut t)
-    .unwrap();
     let mut ext: sp_io::TestExternalities = t.into();
     ext.execute_with(|| System::set_block_number(1));
     ext
@@ -243,7 +290,13 @@ fn opening_bank_account_works() {
 fn spend_governance_works() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            Bank::propose_spend(Origin::signed(1), 1, 10, 3,),
+            Bank::propose_spend(
+                Origin::signed(1),
+                1,
+                NATIVE,
+                10,
+                SpendDest::Account(3)
+            ),
             Error::<Test>::BankMustExistToProposeSpendFrom
         );
         let threshold = ThresholdInput::new(
@@ -251,7 +304,13 @@ fn spend_governance_works() {
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
         assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
-        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 3,));
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Account(3)
+        ));
         System::set_block_number(9);
         assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1,));
         for i in 1u64..7u64 {
@@ -267,9 +326,673 @@ fn spend_governance_works() {
         run_to_block(21);
         // spend executed
         assert_eq!(Balances::total_balance(&3), 210);
-        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 5, 4,));
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            5,
+            SpendDest::Account(4)
+        ));
         assert_eq!(Balances::total_balance(&4), 75);
         assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 2));
         assert_eq!(Balances::total_balance(&4), 80);
     });
 }
+
+#[test]
+fn committed_funds_accounting_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(20, 0, 20))
+        );
+        assert_noop!(
+            Bank::propose_spend(
+                Origin::signed(1),
+                1,
+                NATIVE,
+                21,
+                SpendDest::Account(3)
+            ),
+            Error::<Test>::CannotProposeSpendIfAmountExceedsFreeBankBalance
+        );
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Account(3)
+        ));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1));
+        // funds under vote are committed
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(10, 10, 20))
+        );
+        assert_noop!(
+            Bank::propose_spend(
+                Origin::signed(1),
+                1,
+                NATIVE,
+                11,
+                SpendDest::Account(4)
+            ),
+            Error::<Test>::CannotProposeSpendIfAmountExceedsFreeBankBalance
+        );
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Account(4)
+        ));
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 2));
+        assert_eq!(Balances::total_balance(&4), 85);
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(0, 10, 10))
+        );
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(11);
+        // the committed funds may drain the bank
+        assert_eq!(Balances::total_balance(&3), 210);
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(0, 0, 0))
+        );
+    });
+}
+
+#[test]
+fn failed_spend_execution_releases_commitment() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(
+            Origin::signed(1),
+            1,
+            20,
+            Some(1),
+            threshold.clone()
+        ));
+        assert_ok!(Bank::open(Origin::signed(1), 1, 10, Some(1), threshold));
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Bank(2)
+        ));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1));
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(10, 10, 20))
+        );
+        // destination bank is closed before the spend executes
+        assert_ok!(Bank::close(Origin::signed(1), 2));
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(11);
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendExecutionFailed(
+                    1,
+                    1,
+                    Asset::new(NATIVE, 10),
+                ))
+        }));
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(20, 0, 20))
+        );
+    });
+}
+
+#[test]
+fn spend_proposal_expiry_and_archival_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::one(),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 50, Some(1), threshold));
+        // never sponsored
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Account(3)
+        ));
+        // rejected by vote
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Account(4)
+        ));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 2));
+        // approved by vote
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Account(5)
+        ));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 3));
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None
+            ));
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(2);
+        // executed spend is removed from storage and archived in an event
+        assert!(!Bank::is_spend(1, 3));
+        assert_eq!(Balances::total_balance(&5), 20);
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendArchived(
+                    1,
+                    3,
+                    Asset::new(NATIVE, 10),
+                    SpendDest::Account(5),
+                ))
+        }));
+        assert_eq!(
+            Bank::spend_proposals(1, 2).unwrap().state(),
+            SpendState::Rejected
+        );
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(40, 0, 40))
+        );
+        run_to_block(21);
+        assert!(!Bank::is_spend(1, 1));
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendExpired(
+                    1,
+                    1,
+                    SpendState::WaitingForApproval,
+                ))
+        }));
+        // failed vote expires a full period after it was rejected
+        assert!(Bank::is_spend(1, 2));
+        run_to_block(22);
+        assert!(!Bank::is_spend(1, 2));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendExpired(1, 2, SpendState::Rejected),
+        );
+        assert!(<ProposalSchedule<Test>>::iter().next().is_none());
+    });
+}
+
+#[test]
+fn spend_polling_is_bounded_per_block() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 50, Some(1), threshold));
+        for _ in 0..7 {
+            assert_ok!(Bank::propose_spend(
+                Origin::signed(1),
+                1,
+                NATIVE,
+                1,
+                SpendDest::Account(3)
+            ));
+        }
+        // unsponsored proposals are only polled once they expire
+        run_to_block(20);
+        assert!((1u64..8u64).all(|id| Bank::is_spend(1, id)));
+        // only MaxProposalPollsPerBlock polls run, the rest wait a block
+        run_to_block(21);
+        assert_eq!((1u64..8u64).filter(|id| Bank::is_spend(1, *id)).count(), 2);
+        run_to_block(22);
+        assert!((1u64..8u64).all(|id| !Bank::is_spend(1, id)));
+        assert_eq!(Bank::proposal_cursor(), 23);
+    });
+}
+
+#[test]
+fn bank_and_org_spends_work() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold.clone()
+        ));
+        assert_ok!(Bank::open(Origin::signed(3), 1, 20, None, threshold));
+        assert_noop!(
+            Bank::propose_spend(
+                Origin::signed(1),
+                1,
+                NATIVE,
+                10,
+                SpendDest::Bank(1)
+            ),
+            Error::<Test>::CannotProposeSpendToSameBank
+        );
+        assert_noop!(
+            Bank::propose_spend(
+                Origin::signed(1),
+                1,
+                NATIVE,
+                10,
+                SpendDest::Bank(3)
+            ),
+            Error::<Test>::CannotProposeSpendToBankThatDNE
+        );
+        assert_noop!(
+            Bank::propose_spend(
+                Origin::signed(1),
+                1,
+                NATIVE,
+                10,
+                SpendDest::Org(OrgRep::Equal(2))
+            ),
+            Error::<Test>::CannotProposeSpendToOrgThatDNE
+        );
+        // fund the other bank
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Bank(2)
+        ));
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 1));
+        assert_eq!(Bank::bank_balance(1, NATIVE), 40);
+        assert_eq!(Bank::bank_balance(2, NATIVE), 30);
+        // distribute among org members
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            12,
+            SpendDest::Org(OrgRep::Equal(1))
+        ));
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 2));
+        assert_eq!(get_last_event(), RawEvent::SudoApproved(1, 1, 2),);
+        let share =
+            Permill::from_rational_approximation(1u64, 6u64).mul_floor(12u64);
+        assert_eq!(Balances::total_balance(&2), 98 + share);
+        assert_eq!(Balances::total_balance(&5), 10 + share);
+        // rounding remainder stays in the bank
+        assert_eq!(Bank::bank_balance(1, NATIVE), 40 - 6 * share);
+    });
+}
+
+#[test]
+fn ledger_records_bank_history() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold.clone()
+        ));
+        assert_ok!(Bank::open(Origin::signed(3), 1, 20, Some(3), threshold));
+        assert_noop!(
+            Bank::deposit(Origin::signed(2), 3, NATIVE, 10),
+            Error::<Test>::CannotDepositToBankThatDNE
+        );
+        System::set_block_number(2);
+        assert_ok!(Bank::deposit(Origin::signed(2), 1, NATIVE, 10));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::Deposited(2, 1, Asset::new(NATIVE, 10))
+        );
+        assert_eq!(Bank::bank_balance(1, NATIVE), 60);
+        System::set_block_number(3);
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            10,
+            SpendDest::Bank(2)
+        ));
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 1));
+        assert_eq!(Bank::ledger_len(1), 3);
+        assert_eq!(
+            Bank::ledger(1, 0),
+            Some(LedgerEntry::new(
+                LedgerEntryKind::Deposit,
+                SpendDest::Account(1),
+                Asset::new(NATIVE, 50),
+                None,
+                1
+            ))
+        );
+        assert_eq!(
+            Bank::ledger(1, 1),
+            Some(LedgerEntry::new(
+                LedgerEntryKind::Deposit,
+                SpendDest::Account(2),
+                Asset::new(NATIVE, 10),
+                None,
+                2
+            ))
+        );
+        assert_eq!(
+            Bank::ledger(1, 2),
+            Some(LedgerEntry::new(
+                LedgerEntryKind::Spend,
+                SpendDest::Bank(2),
+                Asset::new(NATIVE, 10),
+                Some(1),
+                3
+            ))
+        );
+        // receiving bank links the spend which funded it
+        assert_eq!(
+            Bank::ledger(2, 1),
+            Some(LedgerEntry::new(
+                LedgerEntryKind::Deposit,
+                SpendDest::Bank(1),
+                Asset::new(NATIVE, 10),
+                Some(1),
+                3
+            ))
+        );
+        // ledger outlives the bank
+        assert_ok!(Bank::close(Origin::signed(3), 2));
+        assert!(!Bank::is_bank(2));
+        let distributed = (2..Bank::ledger_len(2))
+            .map(|i| {
+                let entry = Bank::ledger(2, i).unwrap();
+                assert_eq!(entry.kind(), LedgerEntryKind::CloseDistribution);
+                entry.amount().balance()
+            })
+            .sum::<u64>();
+        assert_eq!(distributed, 30);
+        assert_eq!(
+            Bank::ledger(2, 2).unwrap().counterparty(),
+            SpendDest::Org(OrgRep::Weighted(1))
+        );
+    });
+}
+
+#[test]
+fn bank_changes_are_enacted_by_vote() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        // no controller so only a vote can close this bank
+        assert_ok!(Bank::open(Origin::signed(1), 1, 50, None, threshold));
+        assert_noop!(
+            Bank::close(Origin::signed(1), 1),
+            Error::<Test>::OnlyControllerCanCloseBank
+        );
+        assert_noop!(
+            Bank::propose_change(Origin::signed(69), 1, BankChange::Close),
+            Error::<Test>::NotPermittedToProposeChangeForBankAccount
+        );
+        assert_noop!(
+            Bank::propose_change(
+                Origin::signed(1),
+                1,
+                BankChange::ChangeThreshold(2)
+            ),
+            Error::<Test>::CannotChangeToThresholdThatDNE
+        );
+        // replace the controller
+        assert_ok!(Bank::propose_change(
+            Origin::signed(2),
+            1,
+            BankChange::ReplaceController(Some(2))
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BankChangeProposed(
+                2,
+                1,
+                1,
+                BankChange::ReplaceController(Some(2)),
+                1
+            ),
+        );
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(2);
+        assert_eq!(get_last_event(), RawEvent::BankChangeEnacted(1, 1));
+        assert_eq!(Bank::banks(1).unwrap().controller(), Some(2));
+        assert!(Bank::bank_changes(1, 1).is_none());
+        // change the threshold
+        let new_threshold = Vote::register_threshold(ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        ))
+        .unwrap();
+        assert_ok!(Bank::propose_change(
+            Origin::signed(2),
+            1,
+            BankChange::ChangeThreshold(new_threshold)
+        ));
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(3);
+        assert_eq!(get_last_event(), RawEvent::BankChangeEnacted(1, 2));
+        assert_eq!(Bank::banks(1).unwrap().threshold_id(), new_threshold);
+        // close under the new threshold
+        assert_ok!(Bank::propose_change(
+            Origin::signed(3),
+            1,
+            BankChange::Close
+        ));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                3,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(4);
+        assert_eq!(get_last_event(), RawEvent::BankChangeEnacted(1, 3));
+        assert!(!Bank::is_bank(1));
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::AccountClosed(3, 1, 1))
+        }));
+    });
+}
+
+#[test]
+fn bank_changes_expire_without_outcome() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 50, None, threshold));
+        assert_ok!(Bank::propose_change(
+            Origin::signed(2),
+            1,
+            BankChange::ReplaceController(Some(2))
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(20);
+        assert!(Bank::bank_changes(1, 1).is_some());
+        run_to_block(21);
+        assert!(Bank::bank_changes(1, 1).is_none());
+        assert_eq!(get_last_event(), RawEvent::BankChangeExpired(1, 1));
+        assert_eq!(Bank::banks(1).unwrap().controller(), None);
+        assert!(<ProposalSchedule<Test>>::iter().next().is_none());
+    });
+}
+
+#[test]
+fn proposals_whose_vote_is_gone_expire() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 50, None, threshold));
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            NATIVE,
+            30,
+            SpendDest::Account(3)
+        ));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1));
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(20, 30, 50))
+        );
+        <vote::VoteStates<Test>>::remove(1);
+        run_to_block(2);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendExpired(1, 1, SpendState::Voting(1))
+        );
+        assert!(Bank::spend_proposals(1, 1).is_none());
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(50, 0, 50))
+        );
+        assert_ok!(Bank::propose_change(
+            Origin::signed(2),
+            1,
+            BankChange::ReplaceController(Some(2))
+        ));
+        <vote::VoteStates<Test>>::remove(2);
+        run_to_block(3);
+        assert_eq!(get_last_event(), RawEvent::BankChangeExpired(1, 1));
+        assert!(Bank::bank_changes(1, 1).is_none());
+    });
+}
+
+#[test]
+fn multi_asset_treasury_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 50, Some(1), threshold));
+        assert_ok!(Bank::deposit(Origin::signed(1), 1, USD, 40));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::Deposited(1, 1, Asset::new(USD, 40))
+        );
+        assert!(Bank::bank_assets(1, USD));
+        assert_eq!(
+            Bank::bank_balances(1, USD),
+            Some(BankBalance::new(40, 0, 40))
+        );
+        // each asset is accounted for separately
+        assert_noop!(
+            Bank::propose_spend(
+                Origin::signed(1),
+                1,
+                USD,
+                41,
+                SpendDest::Account(3)
+            ),
+            Error::<Test>::CannotProposeSpendIfAmountExceedsFreeBankBalance
+        );
+        assert_ok!(Bank::propose_spend(
+            Origin::signed(1),
+            1,
+            USD,
+            30,
+            SpendDest::Account(3)
+        ));
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 1));
+        assert_eq!(Tokens::free_balance(USD, &3), 30);
+        assert_eq!(
+            Bank::bank_balances(1, USD),
+            Some(BankBalance::new(10, 0, 10))
+        );
+        assert_eq!(
+            Bank::bank_balances(1, NATIVE),
+            Some(BankBalance::new(50, 0, 50))
+        );
+        // every asset is distributed when the bank closes
+        assert_ok!(Bank::deposit(Origin::signed(2), 1, USD, 10));
+        assert_ok!(Bank::close(Origin::signed(1), 1));
+        let share =
+            Permill::from_rational_approximation(1u64, 6u64).mul_floor(20u64);
+        assert_eq!(Tokens::free_balance(USD, &4), share);
+        assert_eq!(Tokens::free_balance(USD, &1), 60 + share + 20 - 6 * share);
+        assert_eq!(Tokens::free_balance(USD, &Bank::bank_account_id(1)), 0);
+        assert_eq!(Balances::total_balance(&Bank::bank_account_id(1)), 0);
+        assert!(!Bank::bank_assets(1, USD));
+    });
+}
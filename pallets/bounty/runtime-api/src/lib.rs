@@ -0,0 +1,35 @@
+//! Runtime API definition for the bounty module
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+use util::bounty::BountyInformation;
+
+sp_api::decl_runtime_apis! {
+    pub trait BountyApi<BountyId, IpfsReference, AccountId, Balance> where
+        BountyId: Codec,
+        IpfsReference: Codec,
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Up to `limit` bounties for the repository, after the bounty `after` in index order
+        fn bounties_by_repo(
+            repo: Vec<u8>,
+            after: Option<BountyId>,
+            limit: u32,
+        ) -> Vec<BountyInformation<BountyId, IpfsReference, AccountId, Balance>>;
+        /// Up to `limit` bounties with the tag, after the bounty `after` in index order
+        fn bounties_by_tag(
+            tag: Vec<u8>,
+            after: Option<BountyId>,
+            limit: u32,
+        ) -> Vec<BountyInformation<BountyId, IpfsReference, AccountId, Balance>>;
+        /// Up to `limit` bounties posted by the depositer, after the bounty `after` in index order
+        fn bounties_by_depositer(
+            depositer: AccountId,
+            after: Option<BountyId>,
+            limit: u32,
+        ) -> Vec<BountyInformation<BountyId, IpfsReference, AccountId, Balance>>;
+    }
+}
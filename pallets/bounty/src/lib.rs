@@ -26,8 +26,14 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
-    storage::IterableStorageMap,
+    storage::{
+        generator::StorageDoubleMap as _,
+        with_transaction,
+        IterableStorageDoubleMap,
+        IterableStorageMap,
+    },
     traits::{
+        BalanceStatus,
         Currency,
         ExistenceRequirement,
         Get,
@@ -35,34 +41,71 @@ use frame_support::{
         WithdrawReason,
         WithdrawReasons,
     },
+    weights::Weight,
     Parameter,
 };
-use frame_system::ensure_signed;
-use parity_scale_codec::Codec;
+use frame_system::{
+    ensure_root,
+    ensure_signed,
+};
+use org::Trait as Org;
+use parity_scale_codec::{
+    Codec,
+    Decode,
+};
 use sp_runtime::{
+    helpers_128bit::multiply_by_rational,
     traits::{
         AccountIdConversion,
         AtLeast32Bit,
+        IntegerSquareRoot,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
+        UniqueSaturatedFrom,
+        UniqueSaturatedInto,
         Zero,
     },
+    DispatchError,
     DispatchResult,
     ModuleId,
+    Perbill,
+    Permill,
+    TransactionOutcome,
 };
 use sp_std::{
     fmt::Debug,
     prelude::*,
 };
-use util::bounty::{
-    BountyInformation,
-    BountySubmission,
-    Contribution,
-    SubmissionState,
+use util::{
+    bounty::{
+        BountyClaim,
+        BountyInformation,
+        BountySubmission,
+        ClaimState,
+        Contribution,
+        FundingRound,
+        SubmissionState,
+    },
+    meta::{
+        ResolutionMetadata,
+        VoteMetadata,
+    },
+    organization::OrgRep,
+    traits::{
+        GetVoteOutcome,
+        GroupMembership,
+        OpenVote,
+        RecordReputation,
+        RegisterDisputeType,
+        ResolveDispute,
+    },
+    vote::VoteOutcome,
 };
+use vote::Trait as Vote;
 
 // type aliases
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+type BalanceOf<T> = <<T as donate::Trait>::Currency as Currency<
     <T as frame_system::Trait>::AccountId,
 >>::Balance;
 type Bounty<T> = BountyInformation<
@@ -77,26 +120,46 @@ type BountySub<T> = BountySubmission<
     <T as Trait>::IpfsReference,
     <T as frame_system::Trait>::AccountId,
     BalanceOf<T>,
-    SubmissionState,
+    SubmissionState<<T as Vote>::VoteId>,
 >;
 type Contrib<T> = Contribution<
     <T as Trait>::BountyId,
     <T as frame_system::Trait>::AccountId,
     BalanceOf<T>,
 >;
+type Claim<T> = BountyClaim<
+    <T as Trait>::BountyId,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+type Round<T> = FundingRound<
+    <T as Trait>::RoundId,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+type VoteMetadataOf<T> = VoteMetadata<
+    OrgRep<<T as Org>::OrgId>,
+    <T as Vote>::Signal,
+    Permill,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+type GovernanceOf<T> = ResolutionMetadata<
+    <T as frame_system::Trait>::AccountId,
+    VoteMetadataOf<T>,
+>;
 type EncodedIssue = Vec<u8>;
+type EncodedRepo = Vec<u8>;
+type EncodedTag = Vec<u8>;
 
-pub trait Trait: frame_system::Trait {
+pub trait Trait: frame_system::Trait + Org + Vote + donate::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
     /// Cid type
     type IpfsReference: Parameter + Member + Default;
 
-    /// The currency type
-    type Currency: Currency<Self::AccountId>
-        + ReservableCurrency<Self::AccountId>;
-
     /// The bounty post identifier
     type BountyId: Parameter
         + Member
@@ -123,14 +186,86 @@ pub trait Trait: frame_system::Trait {
         + PartialEq
         + Zero;
 
+    /// The funding round identifier
+    type RoundId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+
     /// The foundational foundation
     type Foundation: Get<ModuleId>;
 
+    /// The treasury which sponsors funding rounds opened by root
+    type Treasury: Get<ModuleId>;
+
     /// Minimum deposit to post bounty
     type MinDeposit: Get<BalanceOf<Self>>;
 
     /// Minimum contribution to posted bounty
     type MinContribution: Get<BalanceOf<Self>>;
+
+    /// Blocks after which an unreviewed submission expires
+    type SubmissionReviewPeriod: Get<Self::BlockNumber>;
+
+    /// Bond reserved to claim a bounty
+    type ClaimBond: Get<BalanceOf<Self>>;
+
+    /// Blocks for which an active claim is exclusive
+    type ClaimPeriod: Get<Self::BlockNumber>;
+
+    /// Portion of the bond slashed to the bounty when a claim lapses
+    type ClaimSlash: Get<Permill>;
+
+    /// Deposit reserved by submitters, slashed if the submission is spam
+    type SubmissionDeposit: Get<BalanceOf<Self>>;
+
+    /// Maximum number of tags per bounty
+    type MaxTags: Get<u32>;
+
+    /// Maximum length of a repository or tag label
+    type MaxLabelLength: Get<u32>;
+
+    /// Max number of deadlines and vote polls handled in one block
+    type MaxDeadlinesPerBlock: Get<u32>;
+
+    /// Blocks between polls of a review or dispute vote which has not ended
+    type ReviewPollFrequency: Get<Self::BlockNumber>;
+
+    /// Blocks after a rejection for which the submitter may escalate
+    type EscalationPeriod: Get<Self::BlockNumber>;
+
+    /// Court resolution for disputes on bounties which set none
+    type DefaultDisputeResolution: Get<VoteMetadataOf<Self>>;
+
+    /// The dispute identifier used by the court
+    type DisputeId: Parameter + Member + Copy;
+
+    /// Locks disputed funds and resolves disputes by vote
+    type Court: RegisterDisputeType<
+            Self::AccountId,
+            BalanceOf<Self>,
+            VoteMetadataOf<Self>,
+            Self::BlockNumber,
+            DisputeIdentifier = Self::DisputeId,
+        > + ResolveDispute<
+            Self::DisputeId,
+            Self::AccountId,
+            VoteId = <Self as Vote>::VoteId,
+        >;
+
+    /// Records completed work and disputes lost by submitters
+    type Reputation: RecordReputation<
+        Self::AccountId,
+        BountyId = Self::BountyId,
+        Balance = BalanceOf<Self>,
+    >;
 }
 
 decl_event!(
@@ -140,16 +275,73 @@ decl_event!(
         <T as Trait>::IpfsReference,
         <T as Trait>::BountyId,
         <T as Trait>::SubmissionId,
+        <T as Org>::OrgId,
+        <T as Vote>::VoteId,
+        <T as frame_system::Trait>::BlockNumber,
+        <T as Trait>::DisputeId,
+        <T as Trait>::RoundId,
         Balance = BalanceOf<T>,
     {
         /// Poster, Initial Amount, Identifier, Bounty Metadata (i.e. github issue reference)
         BountyPosted(AccountId, Balance, BountyId, IpfsReference),
         /// Contributor, This Contribution Amount, Identifier, Full Amount After Contribution, Bounty Metadata
         BountyRaiseContribution(AccountId, Balance, BountyId, Balance, IpfsReference),
+        /// Contributor, Amount Withdrawn, Identifier, Full Amount After Withdrawal, Bounty Metadata
+        BountyContributionWithdrawn(AccountId, Balance, BountyId, Balance, IpfsReference),
         /// Submitter, Bounty Identifier, Amount Requested, Submission Identifier, Bounty Metadata, Submission Metadata
         BountySubmissionPosted(AccountId, BountyId, Balance, SubmissionId, IpfsReference, IpfsReference),
         /// Bounty Identifier, Full Amount Left After Payment, Submission Identifier, Amount Requested, Bounty Metadata, Submission Metadata
         BountyPaymentExecuted(BountyId, Balance, SubmissionId, Balance, AccountId, IpfsReference, IpfsReference),
+        /// Bounty Identifier, Amount Refunded To Contributors, Bounty Metadata
+        BountyExpired(BountyId, Balance, IpfsReference),
+        /// Bounty Identifier, Amount Refunded To Contributors, Bounty Metadata
+        BountyClosed(BountyId, Balance, IpfsReference),
+        /// Hunter, Bounty Identifier, Bond, Claim Expiry
+        BountyClaimed(AccountId, BountyId, Balance, BlockNumber),
+        /// Hunter, Bounty Identifier, Bond
+        BountyClaimRequested(AccountId, BountyId, Balance),
+        /// Hunter, Bounty Identifier, Bond Slashed To Bounty
+        BountyClaimLapsed(AccountId, BountyId, Balance),
+        /// Hunter, Bounty Identifier
+        BountyClaimReleased(AccountId, BountyId),
+        /// Bounty Identifier, Contributor Share Required To Reject (None if optimistic review is disabled)
+        BountyOptimisticReviewSet(BountyId, Option<Permill>),
+        /// Bounty Identifier, Reputation Score Required To Submit (None if any account may submit)
+        BountyMinReputationSet(BountyId, Option<u32>),
+        /// Bounty Identifier, Repository (None if unset), Tags
+        BountyLabeled(BountyId, Option<EncodedRepo>, Vec<EncodedTag>),
+        /// Bounty Identifier
+        BountyDisputeResolutionSet(BountyId),
+        /// Bounty Identifier, Submission Identifier, Amount Locked, Dispute Identifier, Vote Identifier
+        BountySubmissionEscalated(BountyId, SubmissionId, Balance, DisputeId, VoteId),
+        /// Bounty Identifier, Submission Identifier, Dispute Identifier, Accepted
+        BountySubmissionDisputeResolved(BountyId, SubmissionId, DisputeId, bool),
+        /// Bounty Identifier, Submission Identifier, Submission Metadata
+        BountySubmissionRejected(BountyId, SubmissionId, IpfsReference),
+        /// Bounty Identifier, Submission Identifier, Deposit Slashed To Bounty
+        BountySubmissionRejectedAsSpam(BountyId, SubmissionId, Balance),
+        /// Submitter, Bounty Identifier, Submission Identifier, Submission Metadata
+        BountySubmissionWithdrawn(AccountId, BountyId, SubmissionId, IpfsReference),
+        /// Bounty Identifier, Submission Identifier, Submission Metadata
+        BountySubmissionExpired(BountyId, SubmissionId, IpfsReference),
+        /// Poster, Org, Bounty Identifier
+        BountyGovernedByOrg(AccountId, OrgId, BountyId),
+        /// Bounty Identifier, Submission Identifier, Vote Identifier
+        BountySubmissionReviewTriggered(BountyId, SubmissionId, VoteId),
+        /// Submitter, Submission Identifier, Team Org
+        BountySubmissionByTeam(AccountId, SubmissionId, OrgId),
+        /// Sponsor, Round Identifier, Matching Pool, Cap Per Bounty, Round End
+        FundingRoundOpened(AccountId, RoundId, Balance, Balance, BlockNumber),
+        /// Round Identifier, Bounty Identifier
+        BountyAddedToFundingRound(RoundId, BountyId),
+        /// Round Identifier, Bounty Identifier, Amount Matched, Full Amount After Matching
+        BountyMatched(RoundId, BountyId, Balance, Balance),
+        /// Round Identifier, Total Matched, Remainder Returned To Sponsor
+        FundingRoundClosed(RoundId, Balance, Balance),
+        /// Bounty Identifier, Submission Identifier, Dispute Identifier
+        BountySubmissionDisputeDropped(BountyId, SubmissionId, DisputeId),
+        /// Hunter, Bounty Identifier
+        BountyClaimRequestExpired(AccountId, BountyId),
     }
 );
 
@@ -166,6 +358,49 @@ decl_error! {
         CannotApproveSubmissionIfAmountExceedsTotalAvailable,
         NotAuthorizedToApproveBountySubmissions,
         IssueAlreadyClaimedForBountyOrSubmission,
+        BountyDeadlineMustBeInTheFuture,
+        SubmissionNotInValidStateToReject,
+        NotAuthorizedToRejectBountySubmissions,
+        NotAuthorizedToWithdrawBountySubmission,
+        NotAuthorizedToPostBountyForOrg,
+        BountyGovernanceRequiresVoteMetadata,
+        SubmissionNotInValidStateToTriggerReview,
+        NotAuthorizedToTriggerSubmissionReview,
+        NoPayoutsInApproval,
+        PayoutSubmissionNotForBounty,
+        PayoutMustBeNonZeroAndNotExceedSubmissionAmount,
+        DuplicateSubmissionInPayouts,
+        NotAuthorizedToCloseBounty,
+        DepositerCannotClaimBounty,
+        HunterAlreadyClaimedBounty,
+        BountyClaimDNE,
+        BountyClaimNotInValidStateToApprove,
+        NotAuthorizedToApproveBountyClaim,
+        BountyClaimedByAnotherHunter,
+        NotAuthorizedToSetOptimisticReview,
+        NotAuthorizedToSetDisputeResolution,
+        NotAuthorizedToEscalateBountySubmission,
+        SubmissionNotInValidStateToEscalate,
+        NoDisputeResolutionForBounty,
+        BountyHasOpenDisputes,
+        DisputedSubmissionCannotBeWithdrawn,
+        ContributionDNE,
+        DepositerCannotWithdrawContribution,
+        WithdrawalExceedsContribution,
+        CannotWithdrawContributionWhileSubmissionsPending,
+        NotAuthorizedToSubmitForTeam,
+        TeamSubmissionsCannotBeEscalated,
+        NotAuthorizedToSetMinReputation,
+        ReputationBelowBountyMinimum,
+        FundingRoundDNE,
+        FundingRoundPoolBelowMinDeposit,
+        FundingRoundMustEndInTheFuture,
+        FundingRoundClosed,
+        NotAuthorizedToAddBountyToFundingRound,
+        BountyAlreadyInFundingRound,
+        NotAuthorizedToLabelBounty,
+        BountyTagsExceedModuleMax,
+        BountyLabelExceedsModuleMax,
     }
 }
 
@@ -177,9 +412,15 @@ decl_storage! {
         /// Uid generation helpers for SubmissionId
         SubmissionNonce get(fn submission_nonce): T::SubmissionId;
 
+        /// Uid generation helper for RoundId
+        RoundNonce get(fn round_nonce): T::RoundId;
+
         /// Prevent overlapping usage of issues
         pub IssueHashSet get(fn issue_hash_set): map
             hasher(blake2_128_concat) EncodedIssue => Option<()>;
+        /// Issue claimed by each bounty, freed when the bounty expires
+        pub BountyIssues get(fn bounty_issues): map
+            hasher(blake2_128_concat) T::BountyId => Option<EncodedIssue>;
         /// Posted Bounties
         pub Bounties get(fn bounties): map
             hasher(blake2_128_concat) T::BountyId => Option<Bounty<T>>;
@@ -187,10 +428,130 @@ decl_storage! {
         pub Contributions get(fn contributions): double_map
             hasher(blake2_128_concat) T::BountyId,
             hasher(blake2_128_concat) T::AccountId => Option<Contrib<T>>;
+        /// Governance for bounties posted on behalf of an org
+        pub BountyGovernance get(fn bounty_governance): map
+            hasher(blake2_128_concat) T::BountyId => Option<GovernanceOf<T>>;
+        /// Repository of each labeled bounty
+        pub BountyRepos get(fn bounty_repos): map
+            hasher(blake2_128_concat) T::BountyId => Option<EncodedRepo>;
+        /// Tags of each labeled bounty
+        pub BountyTags get(fn bounty_tags): map
+            hasher(blake2_128_concat) T::BountyId => Vec<EncodedTag>;
+        /// Open bounties for each repository
+        pub RepoBounties get(fn repo_bounties): double_map
+            hasher(blake2_128_concat) EncodedRepo,
+            hasher(blake2_128_concat) T::BountyId => Option<()>;
+        /// Open bounties with each tag
+        pub TagBounties get(fn tag_bounties): double_map
+            hasher(blake2_128_concat) EncodedTag,
+            hasher(blake2_128_concat) T::BountyId => Option<()>;
+        /// Open bounties posted by each depositer
+        pub DepositerBounties get(fn depositer_bounties): double_map
+            hasher(blake2_128_concat) T::AccountId,
+            hasher(blake2_128_concat) T::BountyId => Option<()>;
+        /// Deadline of each bounty posted with one, kept apart from the
+        /// bounty so bounties posted before deadlines decode unchanged
+        pub Deadlines get(fn deadlines): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::BlockNumber>;
+        /// Bounties which expire at each block
+        pub BountyDeadlines get(fn bounty_deadlines): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::BountyId => Option<()>;
 
         /// Posted Submissions
         pub Submissions get(fn submissions): map
             hasher(blake2_128_concat) T::SubmissionId => Option<BountySub<T>>;
+        /// Pending submissions to each bounty
+        pub BountySubmissions get(fn bounty_submissions): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::SubmissionId => Option<()>;
+        /// Issue claimed by each submission, freed when the submission is
+        /// rejected, withdrawn or expires
+        pub SubmissionIssues get(fn submission_issues): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<EncodedIssue>;
+        /// Deposits reserved by submitters, returned when the submission is
+        /// resolved unless it is rejected as spam
+        pub SubmissionDeposits get(fn submission_deposits): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<BalanceOf<T>>;
+        /// Teams which split the payout of their submissions by share weight
+        /// (or equally), the remainder going to the submitter
+        pub SubmissionTeams get(fn submission_teams): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<OrgRep<T::OrgId>>;
+        /// Bounties whose unreviewed submissions are paid when their review
+        /// period ends, with the contributor share required to reject them
+        pub OptimisticReview get(fn optimistic_review): map
+            hasher(blake2_128_concat) T::BountyId => Option<Permill>;
+        /// Minimum reputation score required to submit for each bounty
+        pub MinReputation get(fn min_reputation): map
+            hasher(blake2_128_concat) T::BountyId => Option<u32>;
+        /// Vote metadata for resolving disputes over ungoverned bounties
+        pub DisputeResolution get(fn dispute_resolution): map
+            hasher(blake2_128_concat) T::BountyId => Option<VoteMetadataOf<T>>;
+        /// Open disputes over submissions
+        pub SubmissionDisputes get(fn submission_disputes): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::SubmissionId => Option<T::DisputeId>;
+        /// Claims on bounties by hunters
+        pub Claims get(fn claims): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::AccountId => Option<Claim<T>>;
+        /// Claims which lapse at each block if their claimant has not submitted
+        pub ClaimExpiries get(fn claim_expiries): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) (T::BountyId, T::AccountId) => Option<()>;
+        /// Block at which each claim awaiting approval lapses
+        pub RequestExpiries get(fn request_expiries): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
+        /// Votes on submissions to governed bounties
+        pub SubmissionReviews get(fn submission_reviews): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<T::VoteId>;
+        /// Review votes polled at each block
+        pub ReviewPolls get(fn review_polls): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::SubmissionId => Option<T::VoteId>;
+        /// Dispute votes polled at each block
+        pub DisputePolls get(fn dispute_polls): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) (T::BountyId, T::SubmissionId) => Option<T::VoteId>;
+        /// Submissions which expire at each block if still unreviewed or
+        /// rejected and unescalated
+        pub SubmissionDeadlines get(fn submission_deadlines): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::SubmissionId => Option<()>;
+        /// End of the escalation window of each rejected submission
+        pub RejectionWindows get(fn rejection_windows): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<T::BlockNumber>;
+        /// Deadline of each submission whose approval could not be paid,
+        /// superseding the deadline set when it was submitted
+        pub RetryDeadlines get(fn retry_deadlines): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<T::BlockNumber>;
+
+        /// Open funding rounds
+        pub FundingRounds get(fn funding_rounds): map
+            hasher(blake2_128_concat) T::RoundId => Option<Round<T>>;
+        /// Bounties eligible for matching in each round
+        pub RoundBounties get(fn round_bounties): double_map
+            hasher(blake2_128_concat) T::RoundId,
+            hasher(blake2_128_concat) T::BountyId => Option<()>;
+        /// The round in which each bounty is eligible for matching
+        pub BountyRounds get(fn bounty_rounds): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::RoundId>;
+        /// Contributions made to each eligible bounty during its round
+        pub RoundContributions get(fn round_contributions): double_map
+            hasher(blake2_128_concat) (T::RoundId, T::BountyId),
+            hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+        /// Funds matched to each bounty by each sponsor, refunded to the sponsor
+        /// alongside contributions
+        pub RoundMatches get(fn round_matches): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+        /// Rounds which close at each block
+        pub RoundDeadlines get(fn round_deadlines): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::RoundId => Option<()>;
+        /// Earliest block whose deadlines and polls may not have all been handled
+        DeadlineCursor get(fn deadline_cursor): T::BlockNumber;
     }
 }
 
@@ -199,29 +560,44 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::handle_deadlines(now)
+        }
+
+        /// Posts a bounty which closes at the optional `deadline`, refunding its
+        /// remaining funds to contributors in proportion to their contributions
         #[weight = 0]
         fn post_bounty(
             origin,
             issue: EncodedIssue,
             info: T::IpfsReference,
             amount: BalanceOf<T>,
+            deadline: Option<T::BlockNumber>,
         ) -> DispatchResult {
-            ensure!(<IssueHashSet>::get(issue.clone()).is_none(), Error::<T>::IssueAlreadyClaimedForBountyOrSubmission);
-            ensure!(amount >= T::MinDeposit::get(), Error::<T>::BountyPostMustExceedMinDeposit);
             let depositer = ensure_signed(origin)?;
-            let imb = T::Currency::withdraw(
-                &depositer,
-                amount,
-                WithdrawReasons::from(WithdrawReason::Transfer),
-                ExistenceRequirement::AllowDeath,
-            )?;
-            let id = Self::bounty_generate_uid();
-            let bounty = Bounty::<T>::new(id, info.clone(), depositer.clone(), amount);
-            T::Currency::resolve_creating(&Self::bounty_account_id(id), imb);
-            <IssueHashSet>::insert(issue, ());
-            <Bounties<T>>::insert(id, bounty);
-            <Contributions<T>>::insert(id, &depositer, Contrib::<T>::new(id, depositer.clone(), amount));
-            Self::deposit_event(RawEvent::BountyPosted(depositer, amount, id, info));
+            Self::post(depositer, issue, info, amount, deadline)?;
+            Ok(())
+        }
+        /// Posts a bounty on behalf of an org, governed by `governance` like grant
+        /// foundations
+        #[weight = 0]
+        fn post_governed_bounty(
+            origin,
+            issue: EncodedIssue,
+            info: T::IpfsReference,
+            amount: BalanceOf<T>,
+            deadline: Option<T::BlockNumber>,
+            governance: GovernanceOf<T>,
+        ) -> DispatchResult {
+            let depositer = ensure_signed(origin)?;
+            let org = governance.vote().ok_or(Error::<T>::BountyGovernanceRequiresVoteMetadata)?.org().org();
+            ensure!(
+                <org::Module<T>>::is_member_of_group(org, &depositer) || governance.is_sudo(&depositer),
+                Error::<T>::NotAuthorizedToPostBountyForOrg
+            );
+            let id = Self::post(depositer.clone(), issue, info, amount, deadline)?;
+            <BountyGovernance<T>>::insert(id, governance);
+            Self::deposit_event(RawEvent::BountyGovernedByOrg(depositer, org, id));
             Ok(())
         }
         #[weight = 0]
@@ -248,9 +624,73 @@ decl_module! {
             let total = new_bounty.total();
             <Contributions<T>>::insert(bounty_id, &contributor, new_contribution);
             <Bounties<T>>::insert(bounty_id, new_bounty);
+            if let Some(round_id) = <BountyRounds<T>>::get(bounty_id) {
+                let matched = <RoundContributions<T>>::get((round_id, bounty_id), &contributor)
+                    .unwrap_or_else(Zero::zero)
+                    .saturating_add(amount);
+                <RoundContributions<T>>::insert((round_id, bounty_id), &contributor, matched);
+            }
             Self::deposit_event(RawEvent::BountyRaiseContribution(contributor, amount, bounty_id, total, bounty.info()));
             Ok(())
         }
+        /// Withdraws up to the contribution while the bounty has no pending
+        /// submissions. Matched funds stay with the bounty.
+        #[weight = 0]
+        fn withdraw_contribution(
+            origin,
+            bounty_id: T::BountyId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let contributor = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            // the depositer closes the bounty instead
+            ensure!(contributor != bounty.depositer(), Error::<T>::DepositerCannotWithdrawContribution);
+            let contribution = <Contributions<T>>::get(bounty_id, &contributor).ok_or(Error::<T>::ContributionDNE)?;
+            // matched funds stay with the bounty
+            let withdrawable = bounty.total().saturating_sub(Self::matched(bounty_id));
+            ensure!(
+                amount <= contribution.total() && amount <= withdrawable,
+                Error::<T>::WithdrawalExceedsContribution
+            );
+            // submitters rely on the funding available when they submitted
+            ensure!(!Self::has_pending_submissions(bounty_id), Error::<T>::CannotWithdrawContributionWhileSubmissionsPending);
+            let existence = if bounty.total() == amount {
+                ExistenceRequirement::AllowDeath
+            } else {
+                ExistenceRequirement::KeepAlive
+            };
+            T::Currency::transfer(
+                &Self::bounty_account_id(bounty_id),
+                &contributor,
+                amount,
+                existence,
+            )?;
+            let new_contribution = contribution.subtract_total(amount);
+            if new_contribution.total().is_zero() {
+                <Contributions<T>>::remove(bounty_id, &contributor);
+            } else {
+                <Contributions<T>>::insert(bounty_id, &contributor, new_contribution);
+            }
+            if let Some(round_id) = <BountyRounds<T>>::get(bounty_id) {
+                // withdrawn funds are no longer matched
+                if let Some(c) = <RoundContributions<T>>::get((round_id, bounty_id), &contributor) {
+                    if c > amount {
+                        <RoundContributions<T>>::insert((round_id, bounty_id), &contributor, c - amount);
+                    } else {
+                        <RoundContributions<T>>::remove((round_id, bounty_id), &contributor);
+                    }
+                }
+            }
+            let new_bounty = bounty.subtract_total(amount);
+            let total = new_bounty.total();
+            <Bounties<T>>::insert(bounty_id, new_bounty.clone());
+            Self::deposit_event(RawEvent::BountyContributionWithdrawn(contributor, amount, bounty_id, total, bounty.info()));
+            Self::close_if_drained(new_bounty);
+            Ok(())
+        }
+        /// Submits for the issue, reserving `SubmissionDeposit` until the
+        /// submission is resolved. The submission expires if it is not
+        /// reviewed within `SubmissionReviewPeriod`.
         #[weight = 0]
         fn submit_for_bounty(
             origin,
@@ -259,16 +699,85 @@ decl_module! {
             submission_ref: T::IpfsReference,
             amount: BalanceOf<T>,
         ) -> DispatchResult {
-            ensure!(<IssueHashSet>::get(issue.clone()).is_none(), Error::<T>::IssueAlreadyClaimedForBountyOrSubmission);
-            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
             let submitter = ensure_signed(origin)?;
-            ensure!(submitter != bounty.depositer(), Error::<T>::DepositerCannotSubmitForBounty);
-            ensure!(amount <= bounty.total(), Error::<T>::BountySubmissionExceedsTotalAvailableFunding);
-            let id = Self::submission_generate_uid();
-            let submission = BountySub::<T>::new(bounty_id, id, submission_ref.clone(), submitter.clone(), amount);
-            <Submissions<T>>::insert(id, submission);
-            <IssueHashSet>::insert(issue, ());
-            Self::deposit_event(RawEvent::BountySubmissionPosted(submitter, bounty_id, amount, id, bounty.info(), submission_ref));
+            Self::submit(submitter, bounty_id, issue, submission_ref, amount)?;
+            Ok(())
+        }
+        /// Submits on behalf of `team`, whose members split the payout by shares
+        /// (or equally), the remainder going to the submitter
+        #[weight = 0]
+        fn submit_for_bounty_as_team(
+            origin,
+            bounty_id: T::BountyId,
+            issue: EncodedIssue,
+            submission_ref: T::IpfsReference,
+            amount: BalanceOf<T>,
+            team: OrgRep<T::OrgId>,
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+            ensure!(<org::Module<T>>::is_member_of_group(team.org(), &submitter), Error::<T>::NotAuthorizedToSubmitForTeam);
+            let id = Self::submit(submitter.clone(), bounty_id, issue, submission_ref, amount)?;
+            <SubmissionTeams<T>>::insert(id, team);
+            Self::deposit_event(RawEvent::BountySubmissionByTeam(submitter, id, team.org()));
+            Ok(())
+        }
+        /// Reserves `ClaimBond` to claim the bounty, only claimants may submit
+        /// while it has active claims. The first claim is active immediately and
+        /// later claims wait for a reviewer, returning the bond if not approved
+        /// within `ClaimPeriod`. Active claims lapse if the claimant does not
+        /// submit before they expire, slashing `ClaimSlash` of the bond.
+        #[weight = 0]
+        fn claim_bounty(
+            origin,
+            bounty_id: T::BountyId,
+        ) -> DispatchResult {
+            let hunter = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(hunter != bounty.depositer(), Error::<T>::DepositerCannotClaimBounty);
+            ensure!(<Claims<T>>::get(bounty_id, &hunter).is_none(), Error::<T>::HunterAlreadyClaimedBounty);
+            let bond = T::ClaimBond::get();
+            T::Currency::reserve(&hunter, bond)?;
+            let claim = Claim::<T>::new(bounty_id, hunter.clone(), bond, ClaimState::Requested);
+            if Self::has_active_claims(bounty_id) {
+                // concurrent claims must be approved by the reviewer before they lapse
+                let expires = <frame_system::Module<T>>::block_number() + T::ClaimPeriod::get();
+                <Claims<T>>::insert(bounty_id, &hunter, claim);
+                <RequestExpiries<T>>::insert(bounty_id, &hunter, expires);
+                <ClaimExpiries<T>>::insert(expires, (bounty_id, hunter.clone()), ());
+                Self::deposit_event(RawEvent::BountyClaimRequested(hunter, bounty_id, bond));
+            } else {
+                Self::activate_claim(claim);
+            }
+            Ok(())
+        }
+        #[weight = 0]
+        fn approve_bounty_claim(
+            origin,
+            bounty_id: T::BountyId,
+            hunter: T::AccountId,
+        ) -> DispatchResult {
+            let approver = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &approver), Error::<T>::NotAuthorizedToApproveBountyClaim);
+            let claim = <Claims<T>>::get(bounty_id, &hunter).ok_or(Error::<T>::BountyClaimDNE)?;
+            ensure!(claim.expires().is_none(), Error::<T>::BountyClaimNotInValidStateToApprove);
+            Self::activate_claim(claim);
+            Ok(())
+        }
+        #[weight = 0]
+        fn release_bounty_claim(
+            origin,
+            bounty_id: T::BountyId,
+        ) -> DispatchResult {
+            let hunter = ensure_signed(origin)?;
+            let claim = <Claims<T>>::get(bounty_id, &hunter).ok_or(Error::<T>::BountyClaimDNE)?;
+            if claim.expires().is_some() {
+                // releasing an active claim forfeits the same portion as a lapse
+                Self::lapse_claim(claim);
+            } else {
+                Self::remove_claim(bounty_id, &hunter);
+                Self::deposit_event(RawEvent::BountyClaimReleased(hunter, bounty_id));
+            }
             Ok(())
         }
         #[weight = 0]
@@ -281,21 +790,338 @@ decl_module! {
             ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToApprove);
             let bounty_id = submission.bounty_id();
             let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
-            ensure!(bounty.total() >= submission.amount(), Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable);
-            ensure!(bounty.depositer() == approver, Error::<T>::NotAuthorizedToApproveBountySubmissions);
-            // execute payment
+            ensure!(Self::is_reviewer(&bounty, &approver), Error::<T>::NotAuthorizedToApproveBountySubmissions);
+            let amount = submission.amount();
+            let new_bounty = Self::pay_submission(submission_id, submission, bounty, amount)?;
+            Self::close_if_drained(new_bounty);
+            Ok(())
+        }
+        /// Pays a list of payouts at once, either all or none. The bounty stays
+        /// open until it is drained or closed.
+        #[weight = 0]
+        fn approve_bounty_submissions(
+            origin,
+            bounty_id: T::BountyId,
+            payouts: Vec<(T::SubmissionId, BalanceOf<T>)>,
+        ) -> DispatchResult {
+            let approver = ensure_signed(origin)?;
+            ensure!(!payouts.is_empty(), Error::<T>::NoPayoutsInApproval);
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &approver), Error::<T>::NotAuthorizedToApproveBountySubmissions);
+            let mut approved = Vec::<(T::SubmissionId, BountySub<T>, BalanceOf<T>)>::new();
+            let mut sum = BalanceOf::<T>::zero();
+            for (submission_id, amount) in payouts {
+                ensure!(
+                    approved.iter().all(|(id, _, _)| id != &submission_id),
+                    Error::<T>::DuplicateSubmissionInPayouts
+                );
+                let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+                ensure!(submission.bounty_id() == bounty_id, Error::<T>::PayoutSubmissionNotForBounty);
+                ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToApprove);
+                ensure!(
+                    !amount.is_zero() && amount <= submission.amount(),
+                    Error::<T>::PayoutMustBeNonZeroAndNotExceedSubmissionAmount
+                );
+                sum = sum.saturating_add(amount);
+                approved.push((submission_id, submission, amount));
+            }
+            ensure!(bounty.total() >= sum, Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable);
+            // all payouts execute or none do
+            let new_bounty = with_transaction(|| {
+                let paid = approved.into_iter().try_fold(bounty, |b, (submission_id, submission, amount)| {
+                    Self::pay_submission(submission_id, submission, b, amount)
+                });
+                match paid {
+                    Ok(b) => TransactionOutcome::Commit(Ok(b)),
+                    Err(e) => TransactionOutcome::Rollback(Err(e)),
+                }
+            })?;
+            Self::close_if_drained(new_bounty);
+            Ok(())
+        }
+        /// Closes the bounty, refunding its remaining funds to contributors
+        #[weight = 0]
+        fn close_bounty(
+            origin,
+            bounty_id: T::BountyId,
+        ) -> DispatchResult {
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            // root may close any bounty
+            if ensure_root(origin.clone()).is_err() {
+                let closer = ensure_signed(origin)?;
+                ensure!(Self::is_reviewer(&bounty, &closer), Error::<T>::NotAuthorizedToCloseBounty);
+            }
+            ensure!(!Self::has_open_disputes(bounty_id), Error::<T>::BountyHasOpenDisputes);
+            let info = bounty.info();
+            let (refunded, _) = Self::refund_and_remove_bounty(bounty);
+            Self::deposit_event(RawEvent::BountyClosed(bounty_id, refunded, info));
+            Ok(())
+        }
+        /// Opens the governance vote on the submission, which is approved or
+        /// rejected by its outcome
+        #[weight = 0]
+        fn trigger_submission_review(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let trigger_er = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToTriggerReview);
+            let bounty_id = submission.bounty_id();
+            let gov = <BountyGovernance<T>>::get(bounty_id).ok_or(Error::<T>::NotAuthorizedToTriggerSubmissionReview)?;
+            let review = gov.vote().ok_or(Error::<T>::NotAuthorizedToTriggerSubmissionReview)?;
+            ensure!(
+                <org::Module<T>>::is_member_of_group(review.org().org(), &trigger_er) || gov.is_sudo(&trigger_er),
+                Error::<T>::NotAuthorizedToTriggerSubmissionReview
+            );
+            let new_vote_id = match review {
+                VoteMetadata::Signal(v) => <vote::Module<T>>::open_vote(None, v.org, v.threshold, v.duration)?,
+                VoteMetadata::Percentage(v) => <vote::Module<T>>::open_percent_vote(None, v.org, v.threshold, v.duration)?,
+            };
+            <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState::UnderReview(new_vote_id)));
+            <SubmissionReviews<T>>::insert(submission_id, new_vote_id);
+            <ReviewPolls<T>>::insert(Self::next_vote_poll(new_vote_id), submission_id, new_vote_id);
+            Self::deposit_event(RawEvent::BountySubmissionReviewTriggered(bounty_id, submission_id, new_vote_id));
+            Ok(())
+        }
+        /// Rejects the submission by a reviewer or root. The submitter may still
+        /// escalate it until `EscalationPeriod` ends, after which it is removed.
+        #[weight = 0]
+        fn reject_bounty_submission(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToReject);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            // root may reject submissions for any bounty
+            if ensure_root(origin.clone()).is_err() {
+                let rejecter = ensure_signed(origin)?;
+                ensure!(
+                    Self::is_reviewer(&bounty, &rejecter) || Self::can_reject_optimistically(bounty_id, &rejecter),
+                    Error::<T>::NotAuthorizedToRejectBountySubmissions
+                );
+            }
+            Self::reject_submission(submission_id, submission);
+            Ok(())
+        }
+        /// Removes the submission and slashes its deposit to the bounty
+        #[weight = 0]
+        fn reject_bounty_submission_as_spam(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let rejecter = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToReject);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &rejecter), Error::<T>::NotAuthorizedToRejectBountySubmissions);
+            let slashed = Self::slash_submission_deposit(submission_id, &submission.submitter(), bounty_id);
+            <Bounties<T>>::insert(bounty_id, bounty.add_total(slashed));
+            Self::remove_submission(submission_id);
+            Self::deposit_event(RawEvent::BountySubmissionRejectedAsSpam(bounty_id, submission_id, slashed));
+            Ok(())
+        }
+        /// Pays submissions automatically when their review period ends unless
+        /// they were rejected by a reviewer or by contributors whose share of the
+        /// contributions meets `rejection_threshold`
+        #[weight = 0]
+        fn set_optimistic_review(
+            origin,
+            bounty_id: T::BountyId,
+            rejection_threshold: Option<Permill>,
+        ) -> DispatchResult {
+            let setter = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &setter), Error::<T>::NotAuthorizedToSetOptimisticReview);
+            if let Some(threshold) = rejection_threshold {
+                <OptimisticReview<T>>::insert(bounty_id, threshold);
+            } else {
+                <OptimisticReview<T>>::remove(bounty_id);
+            }
+            Self::deposit_event(RawEvent::BountyOptimisticReviewSet(bounty_id, rejection_threshold));
+            Ok(())
+        }
+        /// Requires submitters to have at least `min_score` in `Reputation`, which
+        /// records approved submissions and disputes lost by submitters
+        #[weight = 0]
+        fn set_min_reputation(
+            origin,
+            bounty_id: T::BountyId,
+            min_score: Option<u32>,
+        ) -> DispatchResult {
+            let setter = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &setter), Error::<T>::NotAuthorizedToSetMinReputation);
+            if let Some(score) = min_score {
+                <MinReputation<T>>::insert(bounty_id, score);
+            } else {
+                <MinReputation<T>>::remove(bounty_id);
+            }
+            Self::deposit_event(RawEvent::BountyMinReputationSet(bounty_id, min_score));
+            Ok(())
+        }
+        /// Labels the bounty with its repository and up to `MaxTags` tags of at
+        /// most `MaxLabelLength`, replacing its labels in the indexes clients page
+        /// through
+        #[weight = 0]
+        fn label_bounty(
+            origin,
+            bounty_id: T::BountyId,
+            repo: Option<EncodedRepo>,
+            tags: Vec<EncodedTag>,
+        ) -> DispatchResult {
+            let labeler = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &labeler), Error::<T>::NotAuthorizedToLabelBounty);
+            let mut tags = tags;
+            tags.sort();
+            tags.dedup();
+            ensure!(tags.len() as u32 <= T::MaxTags::get(), Error::<T>::BountyTagsExceedModuleMax);
+            let max_len = T::MaxLabelLength::get() as usize;
+            ensure!(
+                repo.iter().chain(tags.iter()).all(|l| l.len() <= max_len),
+                Error::<T>::BountyLabelExceedsModuleMax
+            );
+            Self::unlabel(bounty_id);
+            if let Some(r) = repo.clone() {
+                <RepoBounties<T>>::insert(&r, bounty_id, ());
+                <BountyRepos<T>>::insert(bounty_id, r);
+            }
+            for tag in tags.iter() {
+                <TagBounties<T>>::insert(tag, bounty_id, ());
+            }
+            if !tags.is_empty() {
+                <BountyTags<T>>::insert(bounty_id, tags.clone());
+            }
+            Self::deposit_event(RawEvent::BountyLabeled(bounty_id, repo, tags));
+            Ok(())
+        }
+        #[weight = 0]
+        fn set_dispute_resolution(
+            origin,
+            bounty_id: T::BountyId,
+            resolution: VoteMetadataOf<T>,
+        ) -> DispatchResult {
+            let setter = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &setter), Error::<T>::NotAuthorizedToSetDisputeResolution);
+            <DisputeResolution<T>>::insert(bounty_id, resolution);
+            Self::deposit_event(RawEvent::BountyDisputeResolutionSet(bounty_id));
+            Ok(())
+        }
+        /// Escalates a submission awaiting review, or rejected within
+        /// `EscalationPeriod`, to a dispute. The `Court` locks the requested amount
+        /// in the bounty's account and dispatches a vote along the bounty's dispute
+        /// resolution path, else `DefaultDisputeResolution`. The submitter is paid
+        /// if the dispute is accepted, otherwise the funds return to the bounty.
+        #[weight = 0]
+        fn escalate_bounty_submission(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.is_submitter(&submitter), Error::<T>::NotAuthorizedToEscalateBountySubmission);
+            ensure!(
+                submission.state().awaiting_review() || submission.state().rejected(),
+                Error::<T>::SubmissionNotInValidStateToEscalate
+            );
+            // the court pays disputed amounts to the submitter alone
+            ensure!(<SubmissionTeams<T>>::get(submission_id).is_none(), Error::<T>::TeamSubmissionsCannotBeEscalated);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            let amount = submission.amount();
+            ensure!(bounty.total() >= amount, Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable);
+            // governed bounties resolve disputes with their own vote and the
+            // rest with the module's default court
+            let resolution = <DisputeResolution<T>>::get(bounty_id)
+                .or_else(|| <BountyGovernance<T>>::get(bounty_id).and_then(|g| g.vote()))
+                .unwrap_or_else(T::DefaultDisputeResolution::get);
+            let dispute_id = T::Court::register_dispute_type(
+                Self::bounty_account_id(bounty_id),
+                amount,
+                submitter.clone(),
+                resolution,
+                None,
+            )?;
+            let vote_id = T::Court::raise_dispute(dispute_id, &submitter)?;
+            <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState::Disputed));
+            <RejectionWindows<T>>::remove(submission_id);
+            <RetryDeadlines<T>>::remove(submission_id);
+            <SubmissionDisputes<T>>::insert(bounty_id, submission_id, dispute_id);
+            <DisputePolls<T>>::insert(Self::next_vote_poll(vote_id), (bounty_id, submission_id), vote_id);
+            <Bounties<T>>::insert(bounty_id, bounty.subtract_total(amount));
+            Self::deposit_event(RawEvent::BountySubmissionEscalated(bounty_id, submission_id, amount, dispute_id, vote_id));
+            Ok(())
+        }
+        /// Withdraws an undisputed submission, freeing its issue to be submitted
+        /// again
+        #[weight = 0]
+        fn withdraw_bounty_submission(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.is_submitter(&submitter), Error::<T>::NotAuthorizedToWithdrawBountySubmission);
+            ensure!(!submission.state().disputed(), Error::<T>::DisputedSubmissionCannotBeWithdrawn);
+            Self::remove_submission(submission_id);
+            Self::deposit_event(RawEvent::BountySubmissionWithdrawn(submitter, submission.bounty_id(), submission_id, submission.submission()));
+            Ok(())
+        }
+        /// Opens a funding round with a matching pool, root on behalf of the
+        /// treasury. Contributions to the round's bounties before `end` are matched
+        /// quadratically up to `cap` and the unmatched remainder is returned to the
+        /// sponsor. Matched funds cannot be withdrawn by contributors and are
+        /// refunded to the sponsor if the bounty is closed.
+        #[weight = 0]
+        fn open_funding_round(
+            origin,
+            pool: BalanceOf<T>,
+            cap: BalanceOf<T>,
+            end: T::BlockNumber,
+        ) -> DispatchResult {
+            // root opens rounds on behalf of the treasury
+            let sponsor = if ensure_root(origin.clone()).is_ok() {
+                T::Treasury::get().into_account()
+            } else {
+                ensure_signed(origin)?
+            };
+            ensure!(pool >= T::MinDeposit::get(), Error::<T>::FundingRoundPoolBelowMinDeposit);
+            ensure!(end > <frame_system::Module<T>>::block_number(), Error::<T>::FundingRoundMustEndInTheFuture);
+            let id = Self::round_generate_uid();
             T::Currency::transfer(
-                &Self::bounty_account_id(bounty_id),
-                &submission.submitter(),
-                submission.amount(),
+                &sponsor,
+                &Self::round_account_id(id),
+                pool,
                 ExistenceRequirement::KeepAlive,
             )?;
-            let new_bounty = bounty.subtract_total(submission.amount());
-            let (bounty_info, new_total) = (new_bounty.info(), new_bounty.total());
-            // submission approved and executed => can be removed
-            <Submissions<T>>::remove(submission_id);
-            <Bounties<T>>::insert(bounty_id, new_bounty);
-            Self::deposit_event(RawEvent::BountyPaymentExecuted(bounty_id, new_total, submission_id, submission.amount(), submission.submitter(), bounty_info, submission.submission()));
+            <FundingRounds<T>>::insert(id, Round::<T>::new(id, sponsor.clone(), pool, cap, end));
+            <RoundDeadlines<T>>::insert(end, id, ());
+            Self::deposit_event(RawEvent::FundingRoundOpened(sponsor, id, pool, cap, end));
+            Ok(())
+        }
+        #[weight = 0]
+        fn add_bounty_to_funding_round(
+            origin,
+            round_id: T::RoundId,
+            bounty_id: T::BountyId,
+        ) -> DispatchResult {
+            let round = <FundingRounds<T>>::get(round_id).ok_or(Error::<T>::FundingRoundDNE)?;
+            // root may add bounties to any round
+            if ensure_root(origin.clone()).is_err() {
+                let adder = ensure_signed(origin)?;
+                ensure!(adder == round.sponsor(), Error::<T>::NotAuthorizedToAddBountyToFundingRound);
+            }
+            ensure!(round.end() > <frame_system::Module<T>>::block_number(), Error::<T>::FundingRoundClosed);
+            ensure!(<Bounties<T>>::get(bounty_id).is_some(), Error::<T>::BountyDNE);
+            ensure!(<BountyRounds<T>>::get(bounty_id).is_none(), Error::<T>::BountyAlreadyInFundingRound);
+            <RoundBounties<T>>::insert(round_id, bounty_id, ());
+            <BountyRounds<T>>::insert(bounty_id, round_id);
+            Self::deposit_event(RawEvent::BountyAddedToFundingRound(round_id, bounty_id));
             Ok(())
         }
     }
@@ -328,10 +1154,1061 @@ impl<T: Trait> Module<T> {
         <SubmissionNonce<T>>::put(id_counter);
         id_counter
     }
-    fn _recursive_remove_bounty(id: T::BountyId) {
-        <Bounties<T>>::remove(id);
-        <Submissions<T>>::iter()
-            .filter(|(_, app)| app.bounty_id() == id)
-            .for_each(|(app_id, _)| <Submissions<T>>::remove(app_id));
+    /// Removes the bounty and everything attached to it, returning the
+    /// weight of the removal
+    fn recursive_remove_bounty(id: T::BountyId) -> Weight {
+        let db = T::DbWeight::get();
+        let mut weight = db.reads_writes(6, 14);
+        if let Some(bounty) = <Bounties<T>>::take(id) {
+            <DepositerBounties<T>>::remove(bounty.depositer(), id);
+        }
+        let tags = Self::unlabel(id);
+        weight = weight.saturating_add(db.writes(tags.into()));
+        if let Some(round_id) = <BountyRounds<T>>::take(id) {
+            <RoundBounties<T>>::remove(round_id, id);
+            let contributions =
+                <RoundContributions<T>>::drain_prefix((round_id, id)).count();
+            weight = weight.saturating_add(
+                db.reads_writes(1, 1)
+                    .saturating_mul(contributions as Weight),
+            );
+        }
+        <Deadlines<T>>::remove(id);
+        <BountyGovernance<T>>::remove(id);
+        <OptimisticReview<T>>::remove(id);
+        <MinReputation<T>>::remove(id);
+        <DisputeResolution<T>>::remove(id);
+        <Claims<T>>::drain_prefix(id).for_each(|(hunter, claim)| {
+            T::Currency::unreserve(&hunter, claim.bond());
+            <RequestExpiries<T>>::remove(id, &hunter);
+            weight = weight.saturating_add(db.reads_writes(2, 3));
+        });
+        <BountySubmissions<T>>::drain_prefix(id).for_each(
+            |(submission_id, _)| {
+                Self::remove_submission(submission_id);
+                weight = weight.saturating_add(db.reads_writes(5, 9));
+            },
+        );
+        weight
+    }
+    /// Removes the bounty from the repository and tag indexes, returning
+    /// the number of tags removed
+    fn unlabel(id: T::BountyId) -> u32 {
+        if let Some(repo) = <BountyRepos<T>>::take(id) {
+            <RepoBounties<T>>::remove(repo, id);
+        }
+        let tags = <BountyTags<T>>::take(id);
+        let removed = tags.len() as u32;
+        for tag in tags {
+            <TagBounties<T>>::remove(tag, id);
+        }
+        removed
+    }
+    fn has_pending_submissions(bounty_id: T::BountyId) -> bool {
+        <BountySubmissions<T>>::iter_prefix(bounty_id)
+            .next()
+            .is_some()
+    }
+    /// Keeps the rejected submission until its escalation window ends
+    fn reject_submission(id: T::SubmissionId, submission: BountySub<T>) {
+        let ends = <frame_system::Module<T>>::block_number()
+            + T::EscalationPeriod::get();
+        <SubmissionReviews<T>>::remove(id);
+        <Submissions<T>>::insert(
+            id,
+            submission.set_state(SubmissionState::Rejected),
+        );
+        <RejectionWindows<T>>::insert(id, ends);
+        <SubmissionDeadlines<T>>::insert(ends, id, ());
+        Self::deposit_event(RawEvent::BountySubmissionRejected(
+            submission.bounty_id(),
+            id,
+            submission.submission(),
+        ));
+    }
+    /// Removes the submission, returns its deposit and frees its issue
+    fn remove_submission(id: T::SubmissionId) {
+        if let Some(submission) = <Submissions<T>>::take(id) {
+            Self::return_submission_deposit(id, &submission.submitter());
+            <BountySubmissions<T>>::remove(submission.bounty_id(), id);
+        }
+        <SubmissionReviews<T>>::remove(id);
+        <SubmissionTeams<T>>::remove(id);
+        <RejectionWindows<T>>::remove(id);
+        <RetryDeadlines<T>>::remove(id);
+        if let Some(issue) = <SubmissionIssues<T>>::take(id) {
+            <IssueHashSet>::remove(issue);
+        }
+    }
+}
+
+// Posting and review helpers
+impl<T: Trait> Module<T> {
+    fn submit(
+        submitter: T::AccountId,
+        bounty_id: T::BountyId,
+        issue: EncodedIssue,
+        submission_ref: T::IpfsReference,
+        amount: BalanceOf<T>,
+    ) -> Result<T::SubmissionId, DispatchError> {
+        ensure!(
+            <IssueHashSet>::get(issue.clone()).is_none(),
+            Error::<T>::IssueAlreadyClaimedForBountyOrSubmission
+        );
+        let bounty =
+            <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+        ensure!(
+            submitter != bounty.depositer(),
+            Error::<T>::DepositerCannotSubmitForBounty
+        );
+        if let Some(min_score) = <MinReputation<T>>::get(bounty_id) {
+            ensure!(
+                T::Reputation::reputation_score(&submitter) >= min_score,
+                Error::<T>::ReputationBelowBountyMinimum
+            );
+        }
+        ensure!(
+            amount <= bounty.total(),
+            Error::<T>::BountySubmissionExceedsTotalAvailableFunding
+        );
+        // claimed bounties only accept submissions from active claimants
+        let claim = <Claims<T>>::get(bounty_id, &submitter)
+            .filter(|c| c.expires().is_some());
+        ensure!(
+            claim.is_some() || !Self::has_active_claims(bounty_id),
+            Error::<T>::BountyClaimedByAnotherHunter
+        );
+        let deposit = T::SubmissionDeposit::get();
+        T::Currency::reserve(&submitter, deposit)?;
+        if claim.is_some() {
+            // the claim is delivered so its bond is returned
+            Self::remove_claim(bounty_id, &submitter);
+        }
+        let id = Self::submission_generate_uid();
+        let submission = BountySub::<T>::new(
+            bounty_id,
+            id,
+            submission_ref.clone(),
+            submitter.clone(),
+            amount,
+        );
+        let review_deadline = <frame_system::Module<T>>::block_number()
+            + T::SubmissionReviewPeriod::get();
+        <Submissions<T>>::insert(id, submission);
+        <BountySubmissions<T>>::insert(bounty_id, id, ());
+        if !deposit.is_zero() {
+            <SubmissionDeposits<T>>::insert(id, deposit);
+        }
+        <SubmissionDeadlines<T>>::insert(review_deadline, id, ());
+        <IssueHashSet>::insert(issue.clone(), ());
+        <SubmissionIssues<T>>::insert(id, issue);
+        Self::deposit_event(RawEvent::BountySubmissionPosted(
+            submitter,
+            bounty_id,
+            amount,
+            id,
+            bounty.info(),
+            submission_ref,
+        ));
+        Ok(id)
+    }
+    fn post(
+        depositer: T::AccountId,
+        issue: EncodedIssue,
+        info: T::IpfsReference,
+        amount: BalanceOf<T>,
+        deadline: Option<T::BlockNumber>,
+    ) -> Result<T::BountyId, DispatchError> {
+        ensure!(
+            <IssueHashSet>::get(issue.clone()).is_none(),
+            Error::<T>::IssueAlreadyClaimedForBountyOrSubmission
+        );
+        ensure!(
+            amount >= T::MinDeposit::get(),
+            Error::<T>::BountyPostMustExceedMinDeposit
+        );
+        if let Some(end) = deadline {
+            ensure!(
+                end > <frame_system::Module<T>>::block_number(),
+                Error::<T>::BountyDeadlineMustBeInTheFuture
+            );
+        }
+        let imb = T::Currency::withdraw(
+            &depositer,
+            amount,
+            WithdrawReasons::from(WithdrawReason::Transfer),
+            ExistenceRequirement::AllowDeath,
+        )?;
+        let id = Self::bounty_generate_uid();
+        let bounty =
+            Bounty::<T>::new(id, info.clone(), depositer.clone(), amount);
+        T::Currency::resolve_creating(&Self::bounty_account_id(id), imb);
+        if let Some(end) = deadline {
+            <Deadlines<T>>::insert(id, end);
+            <BountyDeadlines<T>>::insert(end, id, ());
+        }
+        <IssueHashSet>::insert(issue.clone(), ());
+        <BountyIssues<T>>::insert(id, issue);
+        <Bounties<T>>::insert(id, bounty);
+        <DepositerBounties<T>>::insert(&depositer, id, ());
+        <Contributions<T>>::insert(
+            id,
+            &depositer,
+            Contrib::<T>::new(id, depositer.clone(), amount),
+        );
+        Self::deposit_event(RawEvent::BountyPosted(
+            depositer, amount, id, info,
+        ));
+        Ok(id)
+    }
+    /// Governed bounties are reviewed by their sudo, others by the depositer
+    fn is_reviewer(bounty: &Bounty<T>, who: &T::AccountId) -> bool {
+        if let Some(gov) = <BountyGovernance<T>>::get(bounty.id()) {
+            gov.is_sudo(who)
+        } else {
+            &bounty.depositer() == who
+        }
+    }
+    /// Transfers the payout to the submitter or, for team submissions, splits
+    /// it among the team with the remainder to the submitter
+    fn transfer_payout(
+        bounty_id: T::BountyId,
+        submission_id: T::SubmissionId,
+        submitter: T::AccountId,
+        amount: BalanceOf<T>,
+        existence: ExistenceRequirement,
+    ) -> DispatchResult {
+        let from = Self::bounty_account_id(bounty_id);
+        let team = if let Some(t) = <SubmissionTeams<T>>::get(submission_id) {
+            t
+        } else {
+            return T::Currency::transfer(&from, &submitter, amount, existence)
+        };
+        let (payments, remainder) =
+            <donate::Module<T>>::payments(team, amount)?;
+        let transfers = Some((submitter, remainder))
+            .into_iter()
+            .chain(payments.into_iter())
+            .filter(|(_, due)| !due.is_zero())
+            .collect::<Vec<_>>();
+        // only the last transfer may reap the bounty account
+        let last = transfers.len().saturating_sub(1);
+        with_transaction(|| {
+            let paid = transfers.into_iter().enumerate().try_for_each(
+                |(i, (acc, due))| {
+                    let rule = if i == last {
+                        existence
+                    } else {
+                        ExistenceRequirement::KeepAlive
+                    };
+                    T::Currency::transfer(&from, &acc, due, rule)
+                },
+            );
+            match paid {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(e) => TransactionOutcome::Rollback(Err(e)),
+            }
+        })
+    }
+    /// Pays `amount` to the submitter and resolves the submission, returning
+    /// the bounty with the payment subtracted
+    fn pay_submission(
+        submission_id: T::SubmissionId,
+        submission: BountySub<T>,
+        bounty: Bounty<T>,
+        amount: BalanceOf<T>,
+    ) -> Result<Bounty<T>, DispatchError> {
+        let bounty_id = bounty.id();
+        ensure!(
+            bounty.total() >= amount,
+            Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable
+        );
+        // only a payment which drains the bounty may reap its account
+        let existence = if bounty.total() == amount {
+            ExistenceRequirement::AllowDeath
+        } else {
+            ExistenceRequirement::KeepAlive
+        };
+        Self::transfer_payout(
+            bounty_id,
+            submission_id,
+            submission.submitter(),
+            amount,
+            existence,
+        )?;
+        T::Reputation::record_bounty(
+            &submission.submitter(),
+            bounty_id,
+            amount,
+        );
+        let new_bounty = bounty.subtract_total(amount);
+        let (bounty_info, new_total) = (new_bounty.info(), new_bounty.total());
+        // submission approved and executed => can be removed but its issue stays claimed
+        Self::return_submission_deposit(submission_id, &submission.submitter());
+        <Submissions<T>>::remove(submission_id);
+        <BountySubmissions<T>>::remove(bounty_id, submission_id);
+        <SubmissionIssues<T>>::remove(submission_id);
+        <SubmissionReviews<T>>::remove(submission_id);
+        <SubmissionTeams<T>>::remove(submission_id);
+        <RetryDeadlines<T>>::remove(submission_id);
+        <Bounties<T>>::insert(bounty_id, new_bounty.clone());
+        Self::deposit_event(RawEvent::BountyPaymentExecuted(
+            bounty_id,
+            new_total,
+            submission_id,
+            amount,
+            submission.submitter(),
+            bounty_info,
+            submission.submission(),
+        ));
+        Ok(new_bounty)
+    }
+    /// Closes the bounty once its funds are fully paid out
+    fn close_if_drained(bounty: Bounty<T>) {
+        if bounty.total().is_zero() && !Self::has_open_disputes(bounty.id()) {
+            let (bounty_id, info) = (bounty.id(), bounty.info());
+            let (refunded, _) = Self::refund_and_remove_bounty(bounty);
+            Self::deposit_event(RawEvent::BountyClosed(
+                bounty_id, refunded, info,
+            ));
+        }
+    }
+    /// Block of the next poll of a vote, once it ends or after the poll
+    /// frequency, whichever is sooner
+    fn next_vote_poll(vote_id: T::VoteId) -> T::BlockNumber {
+        let now = <frame_system::Module<T>>::block_number();
+        let at = now + T::ReviewPollFrequency::get().max(1u32.into());
+        // votes are accepted through their end block
+        match <vote::Module<T>>::vote_states(vote_id).and_then(|v| v.ends()) {
+            Some(ends) if ends >= now => at.min(ends + 1u32.into()),
+            _ => at,
+        }
+    }
+    fn poll_submission_review(
+        submission_id: T::SubmissionId,
+        vote_id: T::VoteId,
+    ) -> Weight {
+        let db = T::DbWeight::get();
+        // reviews of resolved submissions are already removed
+        if <SubmissionReviews<T>>::get(submission_id) != Some(vote_id) {
+            return db.reads(1)
+        }
+        let submission = if let Some(s) = <Submissions<T>>::get(submission_id) {
+            s
+        } else {
+            <SubmissionReviews<T>>::remove(submission_id);
+            return db.reads_writes(2, 1)
+        };
+        match <vote::Module<T>>::get_vote_outcome(vote_id) {
+            Ok(VoteOutcome::Approved) => {
+                <SubmissionReviews<T>>::remove(submission_id);
+                let paid = <Bounties<T>>::get(submission.bounty_id())
+                    .ok_or_else(|| Error::<T>::BountyDNE.into())
+                    .and_then(|bounty| {
+                        Self::pay_submission(
+                            submission_id,
+                            submission.clone(),
+                            bounty,
+                            submission.amount(),
+                        )
+                    });
+                match paid {
+                    Ok(new_bounty) => Self::close_if_drained(new_bounty),
+                    Err(_) => {
+                        // approved but unpaid submissions await review again
+                        // and expire if they are not paid in time
+                        let retry = <frame_system::Module<T>>::block_number()
+                            + T::SubmissionReviewPeriod::get();
+                        <Submissions<T>>::insert(
+                            submission_id,
+                            submission.set_state(SubmissionState::default()),
+                        );
+                        <RetryDeadlines<T>>::insert(submission_id, retry);
+                        <SubmissionDeadlines<T>>::insert(
+                            retry,
+                            submission_id,
+                            (),
+                        );
+                    }
+                }
+            }
+            Ok(VoteOutcome::Rejected) => {
+                Self::reject_submission(submission_id, submission);
+            }
+            Ok(_) => {
+                <ReviewPolls<T>>::insert(
+                    Self::next_vote_poll(vote_id),
+                    submission_id,
+                    vote_id,
+                );
+            }
+            Err(_) => {
+                <SubmissionReviews<T>>::remove(submission_id);
+            }
+        }
+        // reads the review, submission, vote, bounty and deposit and writes
+        // the submission, its indexes and the accounts paid
+        db.reads_writes(6, 9)
+    }
+}
+
+// Dispute helpers
+impl<T: Trait> Module<T> {
+    fn has_open_disputes(bounty_id: T::BountyId) -> bool {
+        <SubmissionDisputes<T>>::iter_prefix(bounty_id)
+            .next()
+            .is_some()
+    }
+    fn poll_submission_dispute(
+        bounty_id: T::BountyId,
+        submission_id: T::SubmissionId,
+        vote_id: T::VoteId,
+    ) -> Weight {
+        let db = T::DbWeight::get();
+        // resolved disputes are already removed
+        let dispute_id = if let Some(d) =
+            <SubmissionDisputes<T>>::get(bounty_id, submission_id)
+        {
+            d
+        } else {
+            return db.reads(1)
+        };
+        // the court pays the submitter or releases the lock when the vote
+        // concludes and disputes it can no longer poll are dropped
+        let accepted = match T::Court::poll_dispute(dispute_id) {
+            Ok(Some(accepted)) => accepted,
+            Ok(None) => {
+                <DisputePolls<T>>::insert(
+                    Self::next_vote_poll(vote_id),
+                    (bounty_id, submission_id),
+                    vote_id,
+                );
+                return db.reads_writes(3, 1)
+            }
+            Err(_) => {
+                return Self::drop_dispute(bounty_id, submission_id, dispute_id)
+            }
+        };
+        <SubmissionDisputes<T>>::remove(bounty_id, submission_id);
+        let submission = <Submissions<T>>::get(submission_id);
+        let amount = submission
+            .as_ref()
+            .map(|s| s.amount())
+            .unwrap_or_else(Zero::zero);
+        if accepted {
+            // paid submissions keep their issue claimed
+            if let Some(s) = submission.as_ref() {
+                Self::return_submission_deposit(submission_id, &s.submitter());
+            }
+            <Submissions<T>>::remove(submission_id);
+            <BountySubmissions<T>>::remove(bounty_id, submission_id);
+            <SubmissionIssues<T>>::remove(submission_id);
+        } else {
+            Self::remove_submission(submission_id);
+        }
+        if let Some(s) = submission {
+            if accepted {
+                T::Reputation::record_bounty(&s.submitter(), bounty_id, amount);
+            } else {
+                T::Reputation::record_dispute_lost(&s.submitter());
+            }
+        }
+        Self::deposit_event(RawEvent::BountySubmissionDisputeResolved(
+            bounty_id,
+            submission_id,
+            dispute_id,
+            accepted,
+        ));
+        if let Some(bounty) = <Bounties<T>>::get(bounty_id) {
+            let new_bounty = if accepted {
+                bounty
+            } else {
+                bounty.add_total(amount)
+            };
+            <Bounties<T>>::insert(bounty_id, new_bounty.clone());
+            Self::close_if_drained(new_bounty);
+        }
+        // reads the dispute, its vote and lock, the submission and bounty and
+        // writes them with the accounts paid and the submitter's reputation
+        db.reads_writes(7, 10)
+    }
+    /// Releases the lock of a dispute which the court can no longer resolve
+    /// and removes the disputed submission
+    fn drop_dispute(
+        bounty_id: T::BountyId,
+        submission_id: T::SubmissionId,
+        dispute_id: T::DisputeId,
+    ) -> Weight {
+        <SubmissionDisputes<T>>::remove(bounty_id, submission_id);
+        let amount = <Submissions<T>>::get(submission_id)
+            .map(|s| s.amount())
+            .unwrap_or_else(Zero::zero);
+        Self::remove_submission(submission_id);
+        T::Currency::unreserve(&Self::bounty_account_id(bounty_id), amount);
+        if let Some(bounty) = <Bounties<T>>::get(bounty_id) {
+            <Bounties<T>>::insert(bounty_id, bounty.add_total(amount));
+        }
+        Self::deposit_event(RawEvent::BountySubmissionDisputeDropped(
+            bounty_id,
+            submission_id,
+            dispute_id,
+        ));
+        // reads the dispute, its vote, the submission, deposit and bounty and
+        // writes them with the lock and the submission's indexes
+        T::DbWeight::get().reads_writes(6, 9)
+    }
+}
+
+// Optimistic review helpers
+impl<T: Trait> Module<T> {
+    /// Pays an unreviewed submission to an optimistic bounty, returning false
+    /// if the bounty is not optimistic or cannot cover the submission
+    fn optimistically_approve(
+        submission_id: T::SubmissionId,
+        submission: BountySub<T>,
+    ) -> bool {
+        let bounty_id = submission.bounty_id();
+        if <OptimisticReview<T>>::get(bounty_id).is_none() {
+            return false
+        }
+        let amount = submission.amount();
+        let paid = <Bounties<T>>::get(bounty_id)
+            .ok_or_else(|| Error::<T>::BountyDNE.into())
+            .and_then(|bounty| {
+                Self::pay_submission(submission_id, submission, bounty, amount)
+            });
+        match paid {
+            Ok(new_bounty) => {
+                Self::close_if_drained(new_bounty);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+    /// Contributors to an optimistic bounty may reject submissions if their
+    /// share of the contributions meets the rejection threshold
+    fn can_reject_optimistically(
+        bounty_id: T::BountyId,
+        who: &T::AccountId,
+    ) -> bool {
+        let threshold = if let Some(t) = <OptimisticReview<T>>::get(bounty_id) {
+            t
+        } else {
+            return false
+        };
+        let stake = if let Some(c) = <Contributions<T>>::get(bounty_id, who) {
+            c.total()
+        } else {
+            return false
+        };
+        let contributed = <Contributions<T>>::iter_prefix(bounty_id)
+            .fold(BalanceOf::<T>::zero(), |acc, (_, c)| {
+                acc.saturating_add(c.total())
+            });
+        Permill::from_rational_approximation(stake, contributed) >= threshold
+    }
+}
+
+// Claim helpers
+impl<T: Trait> Module<T> {
+    fn has_active_claims(bounty_id: T::BountyId) -> bool {
+        <Claims<T>>::iter_prefix(bounty_id).any(|(_, c)| c.expires().is_some())
+    }
+    fn activate_claim(claim: Claim<T>) {
+        let (bounty_id, hunter) = (claim.bounty_id(), claim.hunter());
+        let expires =
+            <frame_system::Module<T>>::block_number() + T::ClaimPeriod::get();
+        <RequestExpiries<T>>::remove(bounty_id, &hunter);
+        <Claims<T>>::insert(
+            bounty_id,
+            &hunter,
+            claim.set_state(ClaimState::Active(expires)),
+        );
+        <ClaimExpiries<T>>::insert(expires, (bounty_id, hunter.clone()), ());
+        Self::deposit_event(RawEvent::BountyClaimed(
+            hunter,
+            bounty_id,
+            claim.bond(),
+            expires,
+        ));
+    }
+    /// Removes the claim and returns its bond
+    fn remove_claim(bounty_id: T::BountyId, hunter: &T::AccountId) {
+        if let Some(claim) = <Claims<T>>::take(bounty_id, hunter) {
+            T::Currency::unreserve(hunter, claim.bond());
+        }
+        <RequestExpiries<T>>::remove(bounty_id, hunter);
+    }
+    /// Slashes a portion of the bond to the bounty and returns the rest
+    fn lapse_claim(claim: Claim<T>) {
+        let (bounty_id, hunter) = (claim.bounty_id(), claim.hunter());
+        <Claims<T>>::remove(bounty_id, &hunter);
+        let slash = T::ClaimSlash::get().mul_floor(claim.bond());
+        let unmoved = T::Currency::repatriate_reserved(
+            &hunter,
+            &Self::bounty_account_id(bounty_id),
+            slash,
+            BalanceStatus::Free,
+        )
+        .unwrap_or(slash);
+        let slashed = slash.saturating_sub(unmoved);
+        T::Currency::unreserve(&hunter, claim.bond().saturating_sub(slashed));
+        if let Some(bounty) = <Bounties<T>>::get(bounty_id) {
+            <Bounties<T>>::insert(bounty_id, bounty.add_total(slashed));
+        }
+        Self::deposit_event(RawEvent::BountyClaimLapsed(
+            hunter, bounty_id, slashed,
+        ));
+    }
+}
+
+// Funding round helpers
+impl<T: Trait> Module<T> {
+    pub fn round_account_id(index: T::RoundId) -> T::AccountId {
+        T::Foundation::get().into_sub_account((b"round", index))
+    }
+    fn round_generate_uid() -> T::RoundId {
+        let mut id_counter = <RoundNonce<T>>::get() + 1u32.into();
+        while <FundingRounds<T>>::get(id_counter).is_some() {
+            id_counter += 1u32.into();
+        }
+        <RoundNonce<T>>::put(id_counter);
+        id_counter
+    }
+    /// Funds matched to the bounty by funding rounds
+    fn matched(bounty_id: T::BountyId) -> BalanceOf<T> {
+        <RoundMatches<T>>::iter_prefix(bounty_id)
+            .fold(BalanceOf::<T>::zero(), |acc, (_, m)| acc.saturating_add(m))
+    }
+    /// Matches each eligible bounty in proportion to the square of the sum of
+    /// the square roots of its round contributions, up to the round's cap, and
+    /// returns the remainder to the sponsor
+    /// -> returns the weight of the closure
+    fn close_funding_round(round: Round<T>) -> Weight {
+        let (round_id, pool) = (round.id(), round.pool());
+        let mut contributions = 0u64;
+        let scores = <RoundBounties<T>>::drain_prefix(round_id)
+            .map(|(bounty_id, _)| {
+                <BountyRounds<T>>::remove(bounty_id);
+                let root_sum = <RoundContributions<T>>::drain_prefix((
+                    round_id, bounty_id,
+                ))
+                .fold(
+                    BalanceOf::<T>::zero(),
+                    |acc, (_, c)| {
+                        contributions += 1;
+                        acc.saturating_add(c.integer_sqrt())
+                    },
+                );
+                (bounty_id, root_sum.saturating_mul(root_sum))
+            })
+            .collect::<Vec<_>>();
+        // each bounty is drained, read, matched by transfer and written with
+        // its match and each round contribution is drained
+        let db = T::DbWeight::get();
+        let weight = db
+            .reads_writes(3, 3)
+            .saturating_add(
+                db.reads_writes(5, 6).saturating_mul(scores.len() as Weight),
+            )
+            .saturating_add(
+                db.reads_writes(1, 1).saturating_mul(contributions),
+            );
+        let total_score = scores
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, (_, score)| {
+                acc.saturating_add(*score)
+            });
+        let from = Self::round_account_id(round_id);
+        let mut matched = BalanceOf::<T>::zero();
+        for (bounty_id, score) in scores {
+            let bounty = if let Some(b) = <Bounties<T>>::get(bounty_id) {
+                b
+            } else {
+                continue
+            };
+            let due = Perbill::from_rational_approximation(score, total_score)
+                .mul_floor(pool)
+                .min(round.cap());
+            if due.is_zero()
+                || T::Currency::transfer(
+                    &from,
+                    &Self::bounty_account_id(bounty_id),
+                    due,
+                    ExistenceRequirement::AllowDeath,
+                )
+                .is_err()
+            {
+                continue
+            }
+            let new_bounty = bounty.add_total(due);
+            let total = new_bounty.total();
+            <Bounties<T>>::insert(bounty_id, new_bounty);
+            <RoundMatches<T>>::mutate(bounty_id, round.sponsor(), |m| {
+                *m = Some(m.unwrap_or_else(Zero::zero).saturating_add(due))
+            });
+            matched = matched.saturating_add(due);
+            Self::deposit_event(RawEvent::BountyMatched(
+                round_id, bounty_id, due, total,
+            ));
+        }
+        let remainder = pool.saturating_sub(matched);
+        if !remainder.is_zero() {
+            let _ = T::Currency::transfer(
+                &from,
+                &round.sponsor(),
+                remainder,
+                ExistenceRequirement::AllowDeath,
+            );
+        }
+        Self::deposit_event(RawEvent::FundingRoundClosed(
+            round_id, matched, remainder,
+        ));
+        weight
+    }
+}
+
+// Submission deposit helpers
+impl<T: Trait> Module<T> {
+    fn return_submission_deposit(
+        submission_id: T::SubmissionId,
+        submitter: &T::AccountId,
+    ) {
+        if let Some(deposit) = <SubmissionDeposits<T>>::take(submission_id) {
+            T::Currency::unreserve(submitter, deposit);
+        }
+    }
+    /// Slashes the submission's deposit to the bounty, returning the amount moved
+    fn slash_submission_deposit(
+        submission_id: T::SubmissionId,
+        submitter: &T::AccountId,
+        bounty_id: T::BountyId,
+    ) -> BalanceOf<T> {
+        let deposit =
+            if let Some(d) = <SubmissionDeposits<T>>::take(submission_id) {
+                d
+            } else {
+                return Zero::zero()
+            };
+        let unmoved = T::Currency::repatriate_reserved(
+            submitter,
+            &Self::bounty_account_id(bounty_id),
+            deposit,
+            BalanceStatus::Free,
+        )
+        .unwrap_or(deposit);
+        T::Currency::unreserve(submitter, unmoved);
+        deposit.saturating_sub(unmoved)
+    }
+}
+
+// Deadline helpers
+impl<T: Trait> Module<T> {
+    /// Handles the deadlines and vote polls up to `now`, at most
+    /// MaxDeadlinesPerBlock of them, and leaves the rest to the next block
+    /// -> returns the weight of the deadlines and polls handled
+    fn handle_deadlines(now: T::BlockNumber) -> Weight {
+        let db = T::DbWeight::get();
+        let mut weight = db.reads_writes(1, 1);
+        let mut budget = T::MaxDeadlinesPerBlock::get() as usize;
+        let mut cursor = <DeadlineCursor<T>>::get();
+        while cursor <= now && budget > 0 {
+            let bounties = <BountyDeadlines<T>>::drain_prefix(cursor)
+                .take(budget)
+                .collect::<Vec<_>>();
+            budget -= bounties.len();
+            let submissions = <SubmissionDeadlines<T>>::drain_prefix(cursor)
+                .take(budget)
+                .collect::<Vec<_>>();
+            budget -= submissions.len();
+            let claims = <ClaimExpiries<T>>::drain_prefix(cursor)
+                .take(budget)
+                .collect::<Vec<_>>();
+            budget -= claims.len();
+            let rounds = <RoundDeadlines<T>>::drain_prefix(cursor)
+                .take(budget)
+                .collect::<Vec<_>>();
+            budget -= rounds.len();
+            let reviews = <ReviewPolls<T>>::drain_prefix(cursor)
+                .take(budget)
+                .collect::<Vec<_>>();
+            budget -= reviews.len();
+            let disputes = <DisputePolls<T>>::drain_prefix(cursor)
+                .take(budget)
+                .collect::<Vec<_>>();
+            budget -= disputes.len();
+            for (bounty_id, _) in bounties {
+                weight = weight
+                    .saturating_add(Self::bounty_deadline(now, bounty_id));
+            }
+            for (submission_id, _) in submissions {
+                weight = weight.saturating_add(Self::submission_deadline(
+                    cursor,
+                    submission_id,
+                ));
+            }
+            for ((bounty_id, hunter), _) in claims {
+                weight = weight.saturating_add(Self::claim_expiry(
+                    cursor, bounty_id, hunter,
+                ));
+            }
+            for (round_id, _) in rounds {
+                weight = weight.saturating_add(Self::round_deadline(round_id));
+            }
+            for (submission_id, vote_id) in reviews {
+                weight = weight.saturating_add(Self::poll_submission_review(
+                    submission_id,
+                    vote_id,
+                ));
+            }
+            for ((bounty_id, submission_id), vote_id) in disputes {
+                weight = weight.saturating_add(Self::poll_submission_dispute(
+                    bounty_id,
+                    submission_id,
+                    vote_id,
+                ));
+            }
+            // each step reads the six deadline and poll prefixes and the
+            // block is done unless the budget ran out first
+            weight = weight.saturating_add(db.reads(6));
+            if budget > 0 {
+                cursor += 1u32.into();
+                budget -= 1;
+            }
+        }
+        <DeadlineCursor<T>>::put(cursor);
+        weight
+    }
+    fn bounty_deadline(now: T::BlockNumber, bounty_id: T::BountyId) -> Weight {
+        let db = T::DbWeight::get();
+        let bounty = if let Some(b) = <Bounties<T>>::get(bounty_id) {
+            b
+        } else {
+            return db.reads(1)
+        };
+        if Self::has_open_disputes(bounty_id) {
+            // disputed funds are still locked so expiry waits for resolution
+            <BountyDeadlines<T>>::insert(
+                now + T::SubmissionReviewPeriod::get(),
+                bounty_id,
+                (),
+            );
+            db.reads_writes(2, 1)
+        } else {
+            db.reads(2).saturating_add(Self::expire_bounty(bounty))
+        }
+    }
+    fn submission_deadline(
+        at: T::BlockNumber,
+        submission_id: T::SubmissionId,
+    ) -> Weight {
+        let db = T::DbWeight::get();
+        // approved and withdrawn submissions are already removed
+        let submission = match <Submissions<T>>::get(submission_id) {
+            // submissions whose approval could not be paid wait for their
+            // retry deadline
+            Some(s) if s.state().awaiting_review() => {
+                match <RetryDeadlines<T>>::get(submission_id) {
+                    Some(retry) if retry != at => return db.reads(2),
+                    _ => s,
+                }
+            }
+            // rejected submissions are removed once their window ends
+            Some(s) if s.state().rejected() => {
+                if <RejectionWindows<T>>::get(submission_id) == Some(at) {
+                    Self::remove_submission(submission_id);
+                    return db.reads_writes(3, 6)
+                }
+                return db.reads(2)
+            }
+            _ => return db.reads(1),
+        };
+        // optimistic bounties pay instead unless they lack the funds
+        if !Self::optimistically_approve(submission_id, submission.clone()) {
+            Self::remove_submission(submission_id);
+            Self::deposit_event(RawEvent::BountySubmissionExpired(
+                submission.bounty_id(),
+                submission_id,
+                submission.submission(),
+            ));
+        }
+        // reads the submission, optimistic review, bounty and deposit and
+        // writes the submission, its indexes and the accounts paid
+        db.reads_writes(6, 9)
+    }
+    fn claim_expiry(
+        expires: T::BlockNumber,
+        bounty_id: T::BountyId,
+        hunter: T::AccountId,
+    ) -> Weight {
+        let db = T::DbWeight::get();
+        // delivered and released claims are already removed
+        match <Claims<T>>::get(bounty_id, &hunter) {
+            Some(claim) if claim.expires() == Some(expires) => {
+                Self::lapse_claim(claim);
+                db.reads_writes(4, 5)
+            }
+            // unapproved requests return the whole bond
+            Some(_)
+                if <RequestExpiries<T>>::get(bounty_id, &hunter)
+                    == Some(expires) =>
+            {
+                Self::remove_claim(bounty_id, &hunter);
+                Self::deposit_event(RawEvent::BountyClaimRequestExpired(
+                    hunter, bounty_id,
+                ));
+                db.reads_writes(2, 3)
+            }
+            _ => db.reads(2),
+        }
+    }
+    fn round_deadline(round_id: T::RoundId) -> Weight {
+        let db = T::DbWeight::get();
+        if let Some(round) = <FundingRounds<T>>::take(round_id) {
+            db.reads_writes(1, 1)
+                .saturating_add(Self::close_funding_round(round))
+        } else {
+            db.reads(1)
+        }
+    }
+}
+
+// Expiry and closure helpers
+impl<T: Trait> Module<T> {
+    /// Refunds and removes the bounty, returning the weight of the expiry
+    fn expire_bounty(bounty: Bounty<T>) -> Weight {
+        let (bounty_id, info) = (bounty.id(), bounty.info());
+        let (refunded, weight) = Self::refund_and_remove_bounty(bounty);
+        Self::deposit_event(RawEvent::BountyExpired(bounty_id, refunded, info));
+        weight
+    }
+    /// Refunds the remaining funds to contributors and round sponsors in
+    /// proportion to their contributions and matches, the remainder to the
+    /// depositer, and removes the bounty
+    /// -> returns the remaining funds and the weight of the refunds and removal
+    fn refund_and_remove_bounty(bounty: Bounty<T>) -> (BalanceOf<T>, Weight) {
+        let bounty_id = bounty.id();
+        let bounty_account = Self::bounty_account_id(bounty_id);
+        let remaining = T::Currency::free_balance(&bounty_account);
+        let contributions = <Contributions<T>>::drain_prefix(bounty_id)
+            .map(|(_, contrib)| (contrib.account(), contrib.total()))
+            .chain(<RoundMatches<T>>::drain_prefix(bounty_id))
+            .collect::<Vec<(T::AccountId, BalanceOf<T>)>>();
+        // each contribution and match is drained and refunded by transfer
+        let db = T::DbWeight::get();
+        let weight = db.reads_writes(5, 5).saturating_add(
+            db.reads_writes(3, 3)
+                .saturating_mul(contributions.len() as Weight),
+        );
+        let contributed = contributions
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, (_, c)| acc.saturating_add(*c));
+        let mut refunded = BalanceOf::<T>::zero();
+        for (account, contributed_by) in contributions {
+            // shares are floored, so the rounding remainder and any failed
+            // refund are sent to the depositer below
+            let due = multiply_by_rational(
+                remaining.unique_saturated_into(),
+                contributed_by.unique_saturated_into(),
+                contributed.unique_saturated_into(),
+            )
+            .map(BalanceOf::<T>::unique_saturated_from)
+            .unwrap_or_else(|_| BalanceOf::<T>::zero());
+            if T::Currency::transfer(
+                &bounty_account,
+                &account,
+                due,
+                ExistenceRequirement::AllowDeath,
+            )
+            .is_ok()
+            {
+                refunded = refunded.saturating_add(due);
+            }
+        }
+        let _ = T::Currency::transfer(
+            &bounty_account,
+            &bounty.depositer(),
+            remaining.saturating_sub(refunded),
+            ExistenceRequirement::AllowDeath,
+        );
+        if let Some(issue) = <BountyIssues<T>>::take(bounty_id) {
+            <IssueHashSet>::remove(issue);
+        }
+        let removal = Self::recursive_remove_bounty(bounty_id);
+        (remaining, weight.saturating_add(removal))
+    }
+}
+
+// Index query helpers, used by the runtime api
+impl<T: Trait> Module<T> {
+    /// Bounties for the repository, after the bounty `after` in index order
+    pub fn bounties_by_repo(
+        repo: EncodedRepo,
+        after: Option<T::BountyId>,
+        limit: u32,
+    ) -> Vec<Bounty<T>> {
+        Self::page(
+            <RepoBounties<T>>::storage_double_map_final_key1(&repo),
+            after.map(|id| {
+                <RepoBounties<T>>::storage_double_map_final_key(&repo, id)
+            }),
+            limit,
+        )
+    }
+    /// Bounties with the tag, after the bounty `after` in index order
+    pub fn bounties_by_tag(
+        tag: EncodedTag,
+        after: Option<T::BountyId>,
+        limit: u32,
+    ) -> Vec<Bounty<T>> {
+        Self::page(
+            <TagBounties<T>>::storage_double_map_final_key1(&tag),
+            after.map(|id| {
+                <TagBounties<T>>::storage_double_map_final_key(&tag, id)
+            }),
+            limit,
+        )
+    }
+    /// Bounties posted by the depositer, after the bounty `after` in index
+    /// order
+    pub fn bounties_by_depositer(
+        depositer: T::AccountId,
+        after: Option<T::BountyId>,
+        limit: u32,
+    ) -> Vec<Bounty<T>> {
+        Self::page(
+            <DepositerBounties<T>>::storage_double_map_final_key1(&depositer),
+            after.map(|id| {
+                <DepositerBounties<T>>::storage_double_map_final_key(
+                    &depositer, id,
+                )
+            }),
+            limit,
+        )
+    }
+    /// Up to `limit` bounties of the index under `prefix`, reading its keys
+    /// in order from `start` so a page only reads the entries it returns
+    fn page(
+        prefix: Vec<u8>,
+        start: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Vec<Bounty<T>> {
+        let mut key = start.unwrap_or_else(|| prefix.clone());
+        let mut bounties = Vec::new();
+        while (bounties.len() as u32) < limit {
+            key = match sp_io::storage::next_key(&key) {
+                Some(k) if k.starts_with(&prefix) => k,
+                _ => break,
+            };
+            // the identifier follows its 16 byte blake2_128_concat hash
+            if let Some(bounty) = key
+                .get(prefix.len() + 16..)
+                .and_then(|mut raw| T::BountyId::decode(&mut raw).ok())
+                .and_then(<Bounties<T>>::get)
+            {
+                bounties.push(bounty);
+            }
+        }
+        bounties
     }
 }
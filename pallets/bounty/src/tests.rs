@@ -5,6 +5,7 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::OnInitialize,
     weights::Weight,
 };
 use frame_system::{self as system,};
@@ -18,6 +19,13 @@ use sp_runtime::{
     traits::IdentityLookup,
     Perbill,
 };
+use util::{
+    meta::VoteCall,
+    vote::{
+        Threshold,
+        VoterView,
+    },
+};
 
 // type aliases
 pub type AccountId = u64;
@@ -35,6 +43,11 @@ impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
         pallet_balances<T>,
+        org<T>,
+        vote<T>,
+        donate<T>,
+        insurance<T>,
+        reputation<T>,
         bounty<T>,
     }
 }
@@ -87,23 +100,92 @@ impl pallet_balances::Trait for Test {
     type WeightInfo = ();
     type MaxLocks = MaxLocks;
 }
+impl org::Trait for Test {
+    type Event = TestEvent;
+    type Cid = u32;
+    type OrgId = u64;
+    type Shares = u64;
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type VoteId = u64;
+    type Signal = u64;
+    type ThresholdId = u64;
+}
+impl donate::Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+}
+parameter_types! {
+    pub const MinimumDisputeAmount: u64 = 10;
+}
+impl insurance::Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+    type DisputeId = u64;
+    type MinimumDisputeAmount = MinimumDisputeAmount;
+}
+parameter_types! {
+    pub const MinRecordedPayment: u64 = 1;
+}
+impl reputation::Trait for Test {
+    type Event = TestEvent;
+    type BountyId = u64;
+    type MilestoneId = u64;
+    type Balance = u64;
+    type MinRecordedPayment = MinRecordedPayment;
+}
 parameter_types! {
     pub const Foundation: ModuleId = ModuleId(*b"fundacon");
+    pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
     pub const MinDeposit: u64 = 10;
     pub const MinContribution: u64 = 5;
+    pub const SubmissionReviewPeriod: u64 = 10;
+    pub const ClaimBond: u64 = 4;
+    pub const ClaimPeriod: u64 = 5;
+    pub const ClaimSlash: Permill = Permill::from_percent(50);
+    pub const SubmissionDeposit: u64 = 1;
+    pub const MaxTags: u32 = 2;
+    pub const MaxLabelLength: u32 = 8;
+    pub const MaxDeadlinesPerBlock: u32 = 4;
+    pub const ReviewPollFrequency: u64 = 1;
+    pub const EscalationPeriod: u64 = 5;
+    pub DefaultDisputeResolution: VoteMetadataOf<Test> =
+        VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1u64),
+            Threshold::new(1u64, Some(1u64)),
+            None,
+        ));
 }
 impl Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32;
-    type Currency = Balances;
     type BountyId = u64;
     type SubmissionId = u64;
+    type RoundId = u64;
     type Foundation = Foundation;
+    type Treasury = TreasuryModuleId;
     type MinDeposit = MinDeposit;
     type MinContribution = MinContribution;
+    type SubmissionReviewPeriod = SubmissionReviewPeriod;
+    type ClaimBond = ClaimBond;
+    type ClaimPeriod = ClaimPeriod;
+    type ClaimSlash = ClaimSlash;
+    type SubmissionDeposit = SubmissionDeposit;
+    type MaxTags = MaxTags;
+    type MaxLabelLength = MaxLabelLength;
+    type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+    type ReviewPollFrequency = ReviewPollFrequency;
+    type EscalationPeriod = EscalationPeriod;
+    type DefaultDisputeResolution = DefaultDisputeResolution;
+    type DisputeId = u64;
+    type Court = insurance::Module<Test>;
+    type Reputation = Reputation;
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
+pub type Vote = vote::Module<Test>;
+pub type Reputation = reputation::Module<Test>;
 pub type Bounty = Module<Test>;
 
 fn random(output_len: usize) -> Vec<u8> {
@@ -112,7 +194,8 @@ fn random(output_len: usize) -> Vec<u8> {
     buf
 }
 
-fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64, u64, u64, u64, u64, u64>
+{
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -127,6 +210,14 @@ fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64> {
         .unwrap()
 }
 
+/// Auxiliary method for simulating block time passing
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Bounty::on_initialize(System::block_number());
+    }
+}
+
 fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::default()
         .build_storage::<Test>()
@@ -136,6 +227,13 @@ fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    org::GenesisConfig::<Test> {
+        sudo: 1,
+        doc: 1738,
+        mems: vec![1, 2, 3, 4, 5, 6],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     let mut ext: sp_io::TestExternalities = t.into();
     ext.execute_with(|| System::set_block_number(1));
     ext
@@ -157,6 +255,7 @@ fn post_bounty_works() {
                 random(10),
                 10u32, // cid
                 9,     // amount
+                None,
             ),
             Error::<Test>::BountyPostMustExceedMinDeposit,
         );
@@ -166,6 +265,7 @@ fn post_bounty_works() {
                 random(10),
                 10u32, // cid
                 101,   // amount
+                None,
             ),
             sp_runtime::DispatchError::Module {
                 index: 0,
@@ -179,6 +279,7 @@ fn post_bounty_works() {
             issue_hash.clone(),
             10u32, // constitution
             10,    // funding reserved
+            None,
         ));
         assert_eq!(RawEvent::BountyPosted(1, 10, 1, 10), get_last_event());
         assert_noop!(
@@ -187,6 +288,7 @@ fn post_bounty_works() {
                 issue_hash,
                 10u32, // constitution
                 10,    // funding reserved
+                None,
             ),
             Error::<Test>::IssueAlreadyClaimedForBountyOrSubmission
         );
@@ -201,6 +303,7 @@ fn contribution_works() {
             random(10),
             10u32, // constitution
             10,    // funding reserved
+            None,
         ));
         assert_noop!(
             Bounty::contribute_to_bounty(Origin::signed(2), 2, 5),
@@ -226,6 +329,53 @@ fn contribution_works() {
     });
 }
 
+#[test]
+fn contribution_withdrawal_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            10,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 20));
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(3), 1, 5),
+            Error::<Test>::ContributionDNE
+        );
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(1), 1, 5),
+            Error::<Test>::DepositerCannotWithdrawContribution
+        );
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            random(10),
+            11u32,
+            5u64,
+        ));
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(2), 1, 5),
+            Error::<Test>::CannotWithdrawContributionWhileSubmissionsPending
+        );
+        assert_ok!(Bounty::withdraw_bounty_submission(Origin::signed(3), 1));
+        assert_ok!(Bounty::withdraw_contribution(Origin::signed(2), 1, 10));
+        assert_eq!(
+            RawEvent::BountyContributionWithdrawn(2, 10, 1, 20, 10),
+            get_last_event()
+        );
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(2), 1, 11),
+            Error::<Test>::WithdrawalExceedsContribution
+        );
+        assert_ok!(Bounty::withdraw_contribution(Origin::signed(2), 1, 10));
+        assert!(Bounty::contributions(1, 2).is_none());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 10);
+        assert_eq!(Balances::total_balance(&2), 98);
+    });
+}
+
 #[test]
 fn submission_works() {
     new_test_ext().execute_with(|| {
@@ -244,6 +394,7 @@ fn submission_works() {
             random(10),
             10u32, // constitution
             21,    // funding reserved
+            None,
         ));
         assert_noop!(
             Bounty::submit_for_bounty(
@@ -302,6 +453,7 @@ fn submission_approval_works() {
             random(10),
             10u32, // constitution
             21,    // funding reserved
+            None,
         ));
         assert_noop!(
             Bounty::approve_bounty_submission(Origin::signed(1), 1),
@@ -325,3 +477,1225 @@ fn submission_approval_works() {
         assert_eq!(Balances::total_balance(&1), 79);
     });
 }
+
+#[test]
+fn deadlines_are_bounded_per_block() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32,
+            20,
+            None,
+        ));
+        for _ in 0..5 {
+            assert_ok!(Bounty::submit_for_bounty(
+                Origin::signed(2),
+                1,
+                random(10),
+                10u32,
+                1u64,
+            ));
+        }
+        assert_eq!(Bounty::bounty_submissions(1, 5), Some(()));
+        run_to_block(10);
+        assert_eq!(<BountySubmissions<Test>>::iter_prefix(1).count(), 5);
+        // only MaxDeadlinesPerBlock deadlines are handled, the rest wait a block
+        run_to_block(11);
+        assert_eq!(<BountySubmissions<Test>>::iter_prefix(1).count(), 1);
+        assert_eq!(Bounty::deadline_cursor(), 11);
+        run_to_block(12);
+        assert_eq!(<BountySubmissions<Test>>::iter_prefix(1).count(), 0);
+        assert_eq!(Bounty::deadline_cursor(), 13);
+        // expired submissions return their deposits
+        assert_eq!(Balances::free_balance(&2), 98);
+    });
+}
+
+#[test]
+fn bounty_expiry_refunds_contributors() {
+    new_test_ext().execute_with(|| {
+        let issue_hash = random(10);
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                issue_hash.clone(),
+                10u32,
+                20,
+                Some(1),
+            ),
+            Error::<Test>::BountyDeadlineMustBeInTheFuture
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            issue_hash.clone(),
+            10u32, // constitution
+            20,    // funding reserved
+            Some(10),
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 10));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            random(10),
+            10u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(Balances::total_balance(&4), 85);
+        assert_eq!(Bounty::deadlines(1), Some(10));
+        run_to_block(9);
+        assert!(Bounty::bounties(1).is_some());
+        run_to_block(10);
+        assert_eq!(RawEvent::BountyExpired(1, 30, 10), get_last_event());
+        assert!(Bounty::deadlines(1).is_none());
+        // remaining 30 is refunded in proportion to the 20/10/10 contributions
+        // and the rounding remainder goes to the depositer
+        assert_eq!(Balances::total_balance(&1), 96);
+        assert_eq!(Balances::total_balance(&2), 95);
+        assert_eq!(Balances::total_balance(&3), 197);
+        assert_eq!(Balances::total_balance(&Bounty::bounty_account_id(1)), 0);
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::contributions(1, 2).is_none());
+        // the issue may be posted again
+        assert!(Bounty::issue_hash_set(issue_hash.clone()).is_none());
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            issue_hash,
+            10u32,
+            10,
+            None,
+        ));
+    });
+}
+
+#[test]
+fn submission_rejection_withdrawal_and_expiry_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            21,    // funding reserved
+            None,
+        ));
+        let (issue1, issue2, issue3) = (random(10), random(10), random(10));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            issue1.clone(),
+            11u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            issue2.clone(),
+            12u32,
+            10u64,
+        ));
+        // rejected by the depositer
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(2), 1),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(
+            RawEvent::BountySubmissionRejected(1, 1, 11),
+            get_last_event()
+        );
+        // kept until its escalation window ends
+        assert!(Bounty::submissions(1).unwrap().state().rejected());
+        assert_eq!(Bounty::rejection_windows(1), Some(6));
+        assert!(Bounty::issue_hash_set(issue1.clone()).is_some());
+        // withdrawn by the submitter
+        assert_noop!(
+            Bounty::withdraw_bounty_submission(Origin::signed(2), 2),
+            Error::<Test>::NotAuthorizedToWithdrawBountySubmission
+        );
+        assert_ok!(Bounty::withdraw_bounty_submission(Origin::signed(3), 2));
+        assert_eq!(
+            RawEvent::BountySubmissionWithdrawn(3, 1, 2, 12),
+            get_last_event()
+        );
+        assert!(Bounty::issue_hash_set(issue2.clone()).is_none());
+        // resubmitted and rejected by root
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            issue2,
+            12u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::reject_bounty_submission(Origin::root(), 3));
+        // expires if not reviewed
+        System::set_block_number(5);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            issue3.clone(),
+            13u32,
+            10u64,
+        ));
+        run_to_block(6);
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::submissions(3).is_none());
+        assert!(Bounty::rejection_windows(1).is_none());
+        assert!(Bounty::issue_hash_set(issue1).is_none());
+        run_to_block(14);
+        assert!(Bounty::submissions(4).is_some());
+        run_to_block(15);
+        assert_eq!(
+            RawEvent::BountySubmissionExpired(1, 4, 13),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(4).is_none());
+        assert!(Bounty::issue_hash_set(issue3).is_none());
+    });
+}
+
+fn org_governance(sudo: Option<u64>) -> GovernanceOf<Test> {
+    ResolutionMetadata::new(
+        sudo,
+        Some(VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1u64),
+            Threshold::new(1u64, Some(1u64)),
+            None,
+        ))),
+    )
+    .unwrap()
+}
+
+#[test]
+fn governed_bounty_review_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_governed_bounty(
+                Origin::signed(7),
+                random(10),
+                10u32,
+                20,
+                None,
+                org_governance(None),
+            ),
+            Error::<Test>::NotAuthorizedToPostBountyForOrg
+        );
+        assert_ok!(Bounty::post_governed_bounty(
+            Origin::signed(2),
+            random(10),
+            10u32, // constitution
+            30,    // funding reserved
+            None,
+            org_governance(Some(3)),
+        ));
+        assert_eq!(RawEvent::BountyGovernedByOrg(2, 1, 1), get_last_event());
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            random(10),
+            11u32,
+            10u64,
+        ));
+        // the depositer no longer reviews submissions, the sudo does
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(2), 1),
+            Error::<Test>::NotAuthorizedToApproveBountySubmissions
+        );
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(3), 1));
+        assert_eq!(Balances::total_balance(&4), 85);
+        // approved by a vote of the org
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(5),
+            1,
+            random(10),
+            12u32,
+            10u64,
+        ));
+        assert_noop!(
+            Bounty::trigger_submission_review(Origin::signed(7), 2),
+            Error::<Test>::NotAuthorizedToTriggerSubmissionReview
+        );
+        assert_ok!(Bounty::trigger_submission_review(Origin::signed(5), 2));
+        assert_eq!(
+            RawEvent::BountySubmissionReviewTriggered(1, 2, 1),
+            get_last_event()
+        );
+        // the review vote is polled from the next block
+        assert_eq!(Bounty::review_polls(2, 2), Some(1));
+        assert_noop!(
+            Bounty::trigger_submission_review(Origin::signed(5), 2),
+            Error::<Test>::SubmissionNotInValidStateToTriggerReview
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(2);
+        assert_eq!(Balances::total_balance(&5), 20);
+        assert!(Bounty::submissions(2).is_none());
+        assert!(Bounty::submission_reviews(2).is_none());
+        // rejected by a vote of the org
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(6),
+            1,
+            random(10),
+            13u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::trigger_submission_review(Origin::signed(1), 3));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            2,
+            VoterView::Against,
+            None
+        ));
+        run_to_block(3);
+        assert_eq!(
+            RawEvent::BountySubmissionRejected(1, 3, 13),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(3).unwrap().state().rejected());
+        run_to_block(8);
+        assert!(Bounty::submissions(3).is_none());
+        assert_eq!(Balances::total_balance(&6), 69);
+    });
+}
+
+#[test]
+fn unpaid_review_approvals_are_requeued() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_governed_bounty(
+            Origin::signed(2),
+            random(10),
+            10u32, // constitution
+            30,    // funding reserved
+            None,
+            org_governance(Some(3)),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            random(10),
+            11u32,
+            20u64,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(5),
+            1,
+            random(10),
+            12u32,
+            20u64,
+        ));
+        assert_ok!(Bounty::trigger_submission_review(Origin::signed(5), 2));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(3), 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        // the vote passes but the bounty can no longer pay the submission
+        run_to_block(2);
+        assert!(Bounty::submissions(2).unwrap().state().awaiting_review());
+        assert!(Bounty::submission_reviews(2).is_none());
+        assert_eq!(Bounty::retry_deadlines(2), Some(12));
+        // the deadline set on submission no longer applies
+        run_to_block(11);
+        assert!(Bounty::submissions(2).is_some());
+        run_to_block(12);
+        assert_eq!(
+            RawEvent::BountySubmissionExpired(1, 2, 12),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(2).is_none());
+        assert!(Bounty::retry_deadlines(2).is_none());
+    });
+}
+
+#[test]
+fn multi_winner_payouts_and_closure_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            50,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            11u32,
+            30u64,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            random(10),
+            12u32,
+            20u64,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            random(10),
+            13u32,
+            10u64,
+        ));
+        assert_noop!(
+            Bounty::approve_bounty_submissions(Origin::signed(1), 1, vec![]),
+            Error::<Test>::NoPayoutsInApproval
+        );
+        assert_noop!(
+            Bounty::approve_bounty_submissions(
+                Origin::signed(2),
+                1,
+                vec![(1, 30)]
+            ),
+            Error::<Test>::NotAuthorizedToApproveBountySubmissions
+        );
+        assert_noop!(
+            Bounty::approve_bounty_submissions(
+                Origin::signed(1),
+                1,
+                vec![(1, 10), (1, 10)]
+            ),
+            Error::<Test>::DuplicateSubmissionInPayouts
+        );
+        assert_noop!(
+            Bounty::approve_bounty_submissions(
+                Origin::signed(1),
+                1,
+                vec![(1, 31)]
+            ),
+            Error::<Test>::PayoutMustBeNonZeroAndNotExceedSubmissionAmount
+        );
+        assert_noop!(
+            Bounty::approve_bounty_submissions(
+                Origin::signed(1),
+                1,
+                vec![(1, 30), (2, 20), (3, 10)]
+            ),
+            Error::<Test>::CannotApproveSubmissionIfAmountExceedsTotalAvailable
+        );
+        // first and second prize, the bounty stays open
+        assert_ok!(Bounty::approve_bounty_submissions(
+            Origin::signed(1),
+            1,
+            vec![(1, 25), (2, 15)]
+        ));
+        assert_eq!(Balances::total_balance(&2), 123);
+        assert_eq!(Balances::total_balance(&3), 215);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 10);
+        assert!(Bounty::submissions(1).is_none());
+        // draining the bounty closes it
+        assert_ok!(Bounty::approve_bounty_submissions(
+            Origin::signed(1),
+            1,
+            vec![(3, 10)]
+        ));
+        assert_eq!(RawEvent::BountyClosed(1, 0, 10), get_last_event());
+        assert_eq!(Balances::total_balance(&4), 85);
+        assert!(Bounty::bounties(1).is_none());
+        // closed by the depositer with funds left for contributors
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32,
+            20,
+            None,
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 2, 10));
+        assert_noop!(
+            Bounty::close_bounty(Origin::signed(3), 2),
+            Error::<Test>::NotAuthorizedToCloseBounty
+        );
+        assert_ok!(Bounty::close_bounty(Origin::signed(1), 2));
+        assert_eq!(RawEvent::BountyClosed(2, 30, 10), get_last_event());
+        assert_eq!(Balances::total_balance(&1), 51);
+        assert_eq!(Balances::total_balance(&3), 214);
+        assert!(Bounty::bounties(2).is_none());
+    });
+}
+
+#[test]
+fn bounty_claims_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            20,    // funding reserved
+            None,
+        ));
+        assert_noop!(
+            Bounty::claim_bounty(Origin::signed(1), 1),
+            Error::<Test>::DepositerCannotClaimBounty
+        );
+        assert_ok!(Bounty::claim_bounty(Origin::signed(2), 1));
+        assert_eq!(RawEvent::BountyClaimed(2, 1, 4, 6), get_last_event());
+        assert_eq!(Balances::reserved_balance(&2), 4);
+        assert_noop!(
+            Bounty::claim_bounty(Origin::signed(2), 1),
+            Error::<Test>::HunterAlreadyClaimedBounty
+        );
+        // concurrent claims wait for the depositer's approval
+        assert_ok!(Bounty::claim_bounty(Origin::signed(3), 1));
+        assert_eq!(RawEvent::BountyClaimRequested(3, 1, 4), get_last_event());
+        assert_noop!(
+            Bounty::submit_for_bounty(
+                Origin::signed(3),
+                1,
+                random(10),
+                11u32,
+                10u64,
+            ),
+            Error::<Test>::BountyClaimedByAnotherHunter
+        );
+        assert_noop!(
+            Bounty::approve_bounty_claim(Origin::signed(2), 1, 3),
+            Error::<Test>::NotAuthorizedToApproveBountyClaim
+        );
+        assert_ok!(Bounty::approve_bounty_claim(Origin::signed(1), 1, 3));
+        assert_eq!(RawEvent::BountyClaimed(3, 1, 4, 6), get_last_event());
+        // delivering the claim returns the bond
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            random(10),
+            11u32,
+            10u64,
+        ));
+        // only the submission deposit stays reserved
+        assert_eq!(Balances::reserved_balance(&3), 1);
+        assert!(Bounty::claims(1, 3).is_none());
+        assert_noop!(
+            Bounty::submit_for_bounty(
+                Origin::signed(4),
+                1,
+                random(10),
+                12u32,
+                10u64,
+            ),
+            Error::<Test>::BountyClaimedByAnotherHunter
+        );
+        // the undelivered claim lapses and half the bond is slashed to the bounty
+        run_to_block(6);
+        assert_eq!(RawEvent::BountyClaimLapsed(2, 1, 2), get_last_event());
+        assert_eq!(Balances::free_balance(&2), 96);
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 22);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            random(10),
+            12u32,
+            10u64,
+        ));
+        // requested claims are released in full
+        assert_ok!(Bounty::claim_bounty(Origin::signed(5), 1));
+        assert_ok!(Bounty::claim_bounty(Origin::signed(6), 1));
+        assert_ok!(Bounty::release_bounty_claim(Origin::signed(6), 1));
+        assert_eq!(RawEvent::BountyClaimReleased(6, 1), get_last_event());
+        assert_eq!(Balances::free_balance(&6), 69);
+        assert!(Bounty::request_expiries(1, 6).is_none());
+        assert_noop!(
+            Bounty::release_bounty_claim(Origin::signed(6), 1),
+            Error::<Test>::BountyClaimDNE
+        );
+        // unapproved requests lapse with the whole bond returned
+        assert_ok!(Bounty::claim_bounty(Origin::signed(6), 1));
+        assert_eq!(Bounty::request_expiries(1, 6), Some(11));
+        assert_eq!(Balances::reserved_balance(&6), 4);
+        run_to_block(11);
+        assert!(System::events().iter().any(|r| {
+            r.event
+                == TestEvent::bounty(RawEvent::BountyClaimRequestExpired(6, 1))
+        }));
+        assert!(Bounty::claims(1, 6).is_none());
+        assert!(Bounty::request_expiries(1, 6).is_none());
+        assert_eq!(Balances::free_balance(&6), 69);
+    });
+}
+
+#[test]
+fn optimistic_review_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            20,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 5));
+        assert_noop!(
+            Bounty::set_optimistic_review(
+                Origin::signed(2),
+                1,
+                Some(Permill::from_percent(25))
+            ),
+            Error::<Test>::NotAuthorizedToSetOptimisticReview
+        );
+        assert_ok!(Bounty::set_optimistic_review(
+            Origin::signed(1),
+            1,
+            Some(Permill::from_percent(25))
+        ));
+        assert_eq!(
+            RawEvent::BountyOptimisticReviewSet(
+                1,
+                Some(Permill::from_percent(25))
+            ),
+            get_last_event()
+        );
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            random(10),
+            11u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(5),
+            1,
+            random(10),
+            12u32,
+            5u64,
+        ));
+        // contributors below the threshold cannot reject
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(3), 2),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(2), 2));
+        // the unrejected submission is paid when its review period ends
+        run_to_block(10);
+        assert_eq!(Balances::total_balance(&4), 75);
+        run_to_block(11);
+        assert_eq!(
+            RawEvent::BountyPaymentExecuted(1, 25, 1, 10, 4, 10, 11),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&4), 85);
+        assert!(Bounty::submissions(1).is_none());
+    });
+}
+
+#[test]
+fn submission_disputes_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            50,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            11u32,
+            20u64,
+        ));
+        let resolution = org_governance(None).vote().unwrap();
+        assert_noop!(
+            Bounty::set_dispute_resolution(
+                Origin::signed(3),
+                1,
+                resolution.clone()
+            ),
+            Error::<Test>::NotAuthorizedToSetDisputeResolution
+        );
+        assert_ok!(Bounty::set_dispute_resolution(
+            Origin::signed(1),
+            1,
+            resolution
+        ));
+        assert_noop!(
+            Bounty::escalate_bounty_submission(Origin::signed(3), 1),
+            Error::<Test>::NotAuthorizedToEscalateBountySubmission
+        );
+        assert_ok!(Bounty::escalate_bounty_submission(Origin::signed(2), 1));
+        assert_eq!(
+            RawEvent::BountySubmissionEscalated(1, 1, 20, 1, 1),
+            get_last_event()
+        );
+        assert_eq!(Bounty::dispute_polls(2, (1, 1)), Some(1));
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 30);
+        assert_noop!(
+            Bounty::withdraw_bounty_submission(Origin::signed(2), 1),
+            Error::<Test>::DisputedSubmissionCannotBeWithdrawn
+        );
+        assert_noop!(
+            Bounty::close_bounty(Origin::signed(1), 1),
+            Error::<Test>::BountyHasOpenDisputes
+        );
+        // dispute accepted by a vote of the org
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(2);
+        assert_eq!(
+            RawEvent::BountySubmissionDisputeResolved(1, 1, 1, true),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&2), 118);
+        assert_eq!(Reputation::completed_bounties(2, 1), Some(20));
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 30);
+        // dispute rejected by a vote of the org
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            random(10),
+            12u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::escalate_bounty_submission(Origin::signed(3), 2));
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 20);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            2,
+            VoterView::Against,
+            None
+        ));
+        run_to_block(3);
+        assert_eq!(
+            RawEvent::BountySubmissionDisputeResolved(1, 2, 2, false),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(2).is_none());
+        assert_eq!(Reputation::reputations(3).disputes_lost(), 1);
+        assert_eq!(Balances::total_balance(&3), 200);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 30);
+        assert_ok!(Bounty::close_bounty(Origin::signed(1), 1));
+    });
+}
+
+#[test]
+fn rejected_submissions_may_be_escalated() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            50,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            11u32,
+            20u64,
+        ));
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(1), 1));
+        // the bounty sets no resolution so the default court resolves it
+        assert_ok!(Bounty::escalate_bounty_submission(Origin::signed(2), 1));
+        assert_eq!(
+            RawEvent::BountySubmissionEscalated(1, 1, 20, 1, 1),
+            get_last_event()
+        );
+        assert!(Bounty::rejection_windows(1).is_none());
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(2);
+        assert_eq!(
+            RawEvent::BountySubmissionDisputeResolved(1, 1, 1, true),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&2), 118);
+        // unescalated rejections are removed when the window ends
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            random(10),
+            12u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(1), 2));
+        run_to_block(6);
+        assert!(Bounty::submissions(2).is_some());
+        run_to_block(7);
+        assert!(Bounty::submissions(2).is_none());
+        assert_noop!(
+            Bounty::escalate_bounty_submission(Origin::signed(3), 2),
+            Error::<Test>::SubmissionDNE
+        );
+    });
+}
+
+#[test]
+fn disputes_may_pay_out_the_full_bounty() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            50,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            11u32,
+            50u64,
+        ));
+        assert_ok!(Bounty::set_dispute_resolution(
+            Origin::signed(1),
+            1,
+            org_governance(None).vote().unwrap()
+        ));
+        assert_ok!(Bounty::escalate_bounty_submission(Origin::signed(2), 1));
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 0);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        // the payment drains the bounty account, which closes the bounty
+        run_to_block(2);
+        assert_eq!(RawEvent::BountyClosed(1, 0, 10), get_last_event());
+        assert_eq!(Balances::total_balance(&2), 148);
+        assert_eq!(Balances::total_balance(&Bounty::bounty_account_id(1)), 0);
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::bounties(1).is_none());
+    });
+}
+
+#[test]
+fn disputes_the_court_cannot_poll_are_dropped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            50,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            11u32,
+            20u64,
+        ));
+        assert_ok!(Bounty::set_dispute_resolution(
+            Origin::signed(1),
+            1,
+            org_governance(None).vote().unwrap()
+        ));
+        assert_ok!(Bounty::escalate_bounty_submission(Origin::signed(2), 1));
+        let bounty_account = Bounty::bounty_account_id(1);
+        assert_eq!(Balances::reserved_balance(&bounty_account), 20);
+        // the dispute's vote is gone so the lock is released
+        <vote::VoteStates<Test>>::remove(1);
+        run_to_block(2);
+        assert_eq!(
+            RawEvent::BountySubmissionDisputeDropped(1, 1, 1),
+            get_last_event()
+        );
+        assert_eq!(Balances::reserved_balance(&bounty_account), 0);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 50);
+        assert!(Bounty::submission_disputes(1, 1).is_none());
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Balances::total_balance(&2), 98);
+        assert_ok!(Bounty::close_bounty(Origin::signed(1), 1));
+    });
+}
+
+#[test]
+fn team_submissions_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            50,    // funding reserved
+            None,
+        ));
+        assert_noop!(
+            Bounty::submit_for_bounty_as_team(
+                Origin::signed(7),
+                1,
+                random(10),
+                11u32,
+                33u64,
+                OrgRep::Equal(1),
+            ),
+            Error::<Test>::NotAuthorizedToSubmitForTeam
+        );
+        assert_ok!(Bounty::submit_for_bounty_as_team(
+            Origin::signed(2),
+            1,
+            random(10),
+            11u32,
+            33u64,
+            OrgRep::Equal(1),
+        ));
+        assert_eq!(RawEvent::BountySubmissionByTeam(2, 1, 1), get_last_event());
+        assert_noop!(
+            Bounty::escalate_bounty_submission(Origin::signed(2), 1),
+            Error::<Test>::TeamSubmissionsCannotBeEscalated
+        );
+        // each of the 6 members is paid 5 and the submitter gets the remainder
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(Balances::total_balance(&1), 55);
+        assert_eq!(Balances::total_balance(&2), 106);
+        assert_eq!(Balances::total_balance(&3), 205);
+        assert_eq!(Balances::total_balance(&6), 74);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 17);
+        assert!(Bounty::submission_teams(1).is_none());
+    });
+}
+
+#[test]
+fn min_reputation_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            50,    // funding reserved
+            None,
+        ));
+        assert_noop!(
+            Bounty::set_min_reputation(Origin::signed(2), 1, Some(1)),
+            Error::<Test>::NotAuthorizedToSetMinReputation
+        );
+        assert_ok!(Bounty::set_min_reputation(Origin::signed(1), 1, Some(1)));
+        assert_eq!(
+            RawEvent::BountyMinReputationSet(1, Some(1)),
+            get_last_event()
+        );
+        assert_noop!(
+            Bounty::submit_for_bounty(
+                Origin::signed(2),
+                1,
+                random(10),
+                11u32,
+                10u64,
+            ),
+            Error::<Test>::ReputationBelowBountyMinimum
+        );
+        // completed work is recorded when the submission is paid
+        assert_ok!(Bounty::set_min_reputation(Origin::signed(1), 1, None));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            11u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(Reputation::reputation_score(&2), 1);
+        assert_eq!(Reputation::completed_bounties(2, 1), Some(10));
+        assert_ok!(Bounty::set_min_reputation(Origin::signed(1), 1, Some(1)));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            12u32,
+            10u64,
+        ));
+    });
+}
+
+#[test]
+fn submission_deposits_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            50,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            11u32,
+            10u64,
+        ));
+        assert_eq!(Balances::reserved_balance(&2), 1);
+        assert_eq!(Bounty::submission_deposits(1), Some(1));
+        // good-faith rejection returns the deposit once it may no longer be
+        // escalated
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(Balances::reserved_balance(&2), 1);
+        run_to_block(6);
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert!(Bounty::submission_deposits(1).is_none());
+        // approval returns the deposit
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            12u32,
+            10u64,
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 2));
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::total_balance(&2), 108);
+        // spam is slashed to the bounty
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            random(10),
+            13u32,
+            10u64,
+        ));
+        assert_noop!(
+            Bounty::reject_bounty_submission_as_spam(Origin::signed(2), 3),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_bounty_submission_as_spam(
+            Origin::signed(1),
+            3
+        ));
+        assert_eq!(
+            RawEvent::BountySubmissionRejectedAsSpam(1, 3, 1),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(3).is_none());
+        assert_eq!(Balances::total_balance(&3), 199);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 41);
+        assert_eq!(Balances::total_balance(&Bounty::bounty_account_id(1)), 41);
+    });
+}
+
+#[test]
+fn funding_rounds_work() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            assert_ok!(Bounty::post_bounty(
+                Origin::signed(1),
+                random(10),
+                10u32, // constitution
+                20,    // funding reserved
+                None,
+            ));
+        }
+        assert_noop!(
+            Bounty::open_funding_round(Origin::signed(3), 5, 30, 5),
+            Error::<Test>::FundingRoundPoolBelowMinDeposit
+        );
+        assert_noop!(
+            Bounty::open_funding_round(Origin::signed(3), 50, 30, 1),
+            Error::<Test>::FundingRoundMustEndInTheFuture
+        );
+        assert_ok!(Bounty::open_funding_round(Origin::signed(3), 50, 30, 5));
+        assert_eq!(
+            RawEvent::FundingRoundOpened(3, 1, 50, 30, 5),
+            get_last_event()
+        );
+        assert_noop!(
+            Bounty::add_bounty_to_funding_round(Origin::signed(2), 1, 1),
+            Error::<Test>::NotAuthorizedToAddBountyToFundingRound
+        );
+        assert_ok!(Bounty::add_bounty_to_funding_round(
+            Origin::signed(3),
+            1,
+            1
+        ));
+        assert_ok!(Bounty::add_bounty_to_funding_round(
+            Origin::signed(3),
+            1,
+            2
+        ));
+        assert_noop!(
+            Bounty::add_bounty_to_funding_round(Origin::signed(3), 1, 2),
+            Error::<Test>::BountyAlreadyInFundingRound
+        );
+        // many small contributions are matched more than one large one
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 9));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(4), 1, 9));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(6), 1, 9));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 2, 36));
+        assert_eq!(Bounty::round_contributions((1, 2), 2), Some(36));
+        run_to_block(5);
+        // bounty 1 scores 81 of 117 and is capped, bounty 2 scores 36 of 117
+        assert_eq!(RawEvent::FundingRoundClosed(1, 45, 5), get_last_event());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 77);
+        assert_eq!(Bounty::bounties(2).unwrap().total(), 71);
+        assert_eq!(Balances::total_balance(&Bounty::bounty_account_id(1)), 77);
+        assert_eq!(Balances::total_balance(&3), 155);
+        assert!(Bounty::funding_rounds(1).is_none());
+        assert!(Bounty::bounty_rounds(1).is_none());
+        assert!(Bounty::round_contributions((1, 2), 2).is_none());
+        // root opens rounds on behalf of the treasury
+        let treasury = TreasuryModuleId::get().into_account();
+        Balances::make_free_balance_be(&treasury, 100);
+        assert_ok!(Bounty::open_funding_round(Origin::root(), 50, 30, 10));
+        assert_eq!(
+            RawEvent::FundingRoundOpened(treasury, 2, 50, 30, 10),
+            get_last_event()
+        );
+        assert_ok!(Bounty::add_bounty_to_funding_round(Origin::root(), 2, 1));
+    });
+}
+
+#[test]
+fn round_matches_are_earmarked() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            45,    // funding reserved
+            None,
+        ));
+        assert_ok!(Bounty::open_funding_round(Origin::signed(3), 50, 30, 5));
+        assert_ok!(Bounty::add_bounty_to_funding_round(
+            Origin::signed(3),
+            1,
+            1
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 25));
+        run_to_block(5);
+        assert_eq!(RawEvent::FundingRoundClosed(1, 30, 20), get_last_event());
+        assert_eq!(Bounty::round_matches(1, 3), Some(30));
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 100);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            random(10),
+            11u32,
+            50u64,
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(Balances::total_balance(&4), 125);
+        // contributors may not withdraw the matched funds
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(2), 1, 25),
+            Error::<Test>::WithdrawalExceedsContribution
+        );
+        // closing refunds the sponsor alongside contributors
+        assert_ok!(Bounty::close_bounty(Origin::signed(1), 1));
+        assert_eq!(RawEvent::BountyClosed(1, 50, 10), get_last_event());
+        assert!(Bounty::round_matches(1, 3).is_none());
+        assert_eq!(Balances::total_balance(&1), 78);
+        assert_eq!(Balances::total_balance(&2), 85);
+        assert_eq!(Balances::total_balance(&3), 185);
+    });
+}
+
+/// Bounty identifiers in an index, sorted
+fn index(ids: impl Iterator<Item = (u64, ())>) -> Vec<u64> {
+    let mut ids = ids.map(|(id, _)| id).collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn bounty_indexes_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32,
+            50,
+            None,
+        ));
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(11),
+            11u32,
+            20,
+            None,
+        ));
+        assert_eq!(
+            index(<DepositerBounties<Test>>::iter_prefix(1)),
+            vec![1, 2]
+        );
+        let (repo, rust, ui) =
+            (b"repo".to_vec(), b"rust".to_vec(), b"ui".to_vec());
+        assert_noop!(
+            Bounty::label_bounty(
+                Origin::signed(2),
+                1,
+                Some(repo.clone()),
+                vec![]
+            ),
+            Error::<Test>::NotAuthorizedToLabelBounty
+        );
+        assert_noop!(
+            Bounty::label_bounty(
+                Origin::signed(1),
+                1,
+                None,
+                vec![rust.clone(), ui.clone(), b"wasm".to_vec()]
+            ),
+            Error::<Test>::BountyTagsExceedModuleMax
+        );
+        assert_noop!(
+            Bounty::label_bounty(
+                Origin::signed(1),
+                1,
+                Some(b"long-repo".to_vec()),
+                vec![]
+            ),
+            Error::<Test>::BountyLabelExceedsModuleMax
+        );
+        // duplicate tags are dropped
+        assert_ok!(Bounty::label_bounty(
+            Origin::signed(1),
+            2,
+            Some(repo.clone()),
+            vec![rust.clone(), rust.clone()]
+        ));
+        assert_eq!(
+            RawEvent::BountyLabeled(2, Some(repo.clone()), vec![rust.clone()]),
+            get_last_event()
+        );
+        assert_ok!(Bounty::label_bounty(
+            Origin::signed(1),
+            1,
+            Some(repo.clone()),
+            vec![ui.clone(), rust.clone()]
+        ));
+        assert_eq!(index(<RepoBounties<Test>>::iter_prefix(&repo)), vec![1, 2]);
+        assert_eq!(index(<TagBounties<Test>>::iter_prefix(&rust)), vec![1, 2]);
+        assert_eq!(index(<TagBounties<Test>>::iter_prefix(&ui)), vec![1]);
+        // pages continue after the last bounty of the previous page
+        let first = Bounty::bounties_by_repo(repo.clone(), None, 1);
+        assert_eq!(first.len(), 1);
+        let rest =
+            Bounty::bounties_by_repo(repo.clone(), Some(first[0].id()), 10);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(first[0].id() + rest[0].id(), 3);
+        let first = Bounty::bounties_by_tag(rust.clone(), None, 1);
+        let rest =
+            Bounty::bounties_by_tag(rust.clone(), Some(first[0].id()), 1);
+        assert_ne!(first[0].id(), rest[0].id());
+        assert!(Bounty::bounties_by_tag(rust.clone(), Some(rest[0].id()), 1)
+            .is_empty());
+        // relabeling replaces the previous labels
+        assert_ok!(Bounty::label_bounty(Origin::signed(1), 1, None, vec![]));
+        assert_eq!(index(<RepoBounties<Test>>::iter_prefix(&repo)), vec![2]);
+        assert!(Bounty::tag_bounties(ui, 1).is_none());
+        // closed bounties leave every index
+        assert_ok!(Bounty::close_bounty(Origin::signed(1), 2));
+        assert!(Bounty::repo_bounties(repo, 2).is_none());
+        assert!(Bounty::tag_bounties(rust, 2).is_none());
+        assert_eq!(index(<DepositerBounties<Test>>::iter_prefix(1)), vec![1]);
+        assert_eq!(Bounty::bounties_by_depositer(1, None, 10).len(), 1);
+    });
+}
@@ -10,10 +10,25 @@
 //!
 //! This pallet adds contributor governance functionality to `Bounty`.
 //!
+//! The depositer leads the bounty. Submissions approved by the leader are
+//! scheduled for payment after the `ChallengePeriod`. Until then, any
+//! contributor may challenge the approval with a veto vote in which each
+//! contributor's vote is weighted by their contributions. If the veto meets
+//! the bounty's veto threshold, the submission is rejected. Otherwise, the
+//! submission is paid.
+//!
+//! Contributors may also trigger a refund vote. If the refund meets the
+//! bounty's refund threshold, the remaining funds are returned to
+//! contributors in proportion to their contributions and the bounty is
+//! removed.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod tests;
+
 use frame_support::{
     decl_error,
     decl_event,
@@ -42,6 +57,7 @@ use sp_runtime::{
         AtLeast32Bit,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
@@ -65,8 +81,12 @@ use util::{
     traits::{
         AccessGenesis,
         GetVoteOutcome,
+        OpenVote,
+    },
+    vote::{
+        Threshold,
+        VoteOutcome,
     },
-    vote::VoteOutcome,
 };
 
 // type aliases
@@ -95,8 +115,8 @@ pub trait Trait: frame_system::Trait + vote::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
-    /// The currency type
-    type Currency: Currency<Self::AccountId>
+    /// The currency type, contributions weigh contributor votes
+    type Currency: Currency<Self::AccountId, Balance = <Self as vote::Trait>::Signal>
         + ReservableCurrency<Self::AccountId>;
 
     /// The bounty post identifier
@@ -152,6 +172,7 @@ decl_event!(
         <T as vote::Trait>::VoteId,
         <T as Trait>::BountyId,
         <T as Trait>::SubmissionId,
+        <T as frame_system::Trait>::BlockNumber,
         Balance = BalanceOf<T>,
     {
         /// Poster, Initial Amount, Identifier, Bounty Metadata (i.e. github issue reference)
@@ -163,6 +184,14 @@ decl_event!(
         BountyRefunded(BountyId, Balance, Balance),
         /// Submitter, Bounty Identifier, Amount Requested, Submission Identifier, Bounty Metadata, Submission Metadata
         BountySubmissionPosted(AccountId, BountyId, Balance, SubmissionId, Cid, Cid),
+        /// Submission Identifier, Bounty Identifier, Requested Amount, Payment Scheduled For
+        BountySubmissionApproved(SubmissionId, BountyId, Balance, BlockNumber),
+        /// Submission Identifier, Bounty Identifier, Requested Amount
+        BountySubmissionRejected(SubmissionId, BountyId, Balance),
+        /// Challenger, Submission Identifier, Bounty Identifier, Veto Vote Identifier
+        SubmissionApprovalChallenged(AccountId, SubmissionId, BountyId, VoteId),
+        /// Challenger, Bounty Identifier, Refund Vote Identifier
+        BountyRefundChallengeTriggered(AccountId, BountyId, VoteId),
         /// Submission Identifier, Bounty Identifier, Requested Amount
         SubmissionApprovedButPaymentFailed(SubmissionId, BountyId, Balance),
         /// Submission Identifier, Bounty Identifier, Requested Amount
@@ -180,12 +209,19 @@ decl_error! {
         BountyDNE,
         SubmissionDNE,
         BountyPostMustExceedMinDeposit,
+        VetoThresholdBelowModuleMin,
+        RefundThresholdBelowModuleMin,
         ContributionMustExceedModuleMin,
         DepositerCannotSubmitForBounty,
         BountySubmissionExceedsTotalAvailableFunding,
         SubmissionNotInValidStateToApprove,
         CannotApproveSubmissionIfAmountExceedsTotalAvailable,
         NotAuthorizedToApproveBountySubmissions,
+        SubmissionNotInValidStateToReject,
+        NotAuthorizedToRejectBountySubmissions,
+        ChallengePeriodForSubmissionApprovalExpired,
+        OnlyContributorsCanChallengeBounty,
+        BountyAlreadyChallengedToClose,
     }
 }
 
@@ -208,6 +244,9 @@ decl_storage! {
         /// Posted Submissions
         pub Submissions get(fn submissions): map
             hasher(blake2_128_concat) T::SubmissionId => Option<BountySub<T>>;
+        /// Funds of each bounty committed to approved submissions awaiting payment
+        pub Committed get(fn committed): map
+            hasher(blake2_128_concat) T::BountyId => BalanceOf<T>;
 
         /// Every this period, poll the status of refund vote challenges and push results
         pub BountyPollFrequency get(fn bounty_poll_frequency) config(): T::BlockNumber;
@@ -231,6 +270,8 @@ decl_module! {
         ) -> DispatchResult {
             let depositer = ensure_signed(origin)?;
             ensure!(amount >= T::MinDeposit::get(), Error::<T>::BountyPostMustExceedMinDeposit);
+            ensure!(veto_threshold >= T::MinVetoThreshold::get(), Error::<T>::VetoThresholdBelowModuleMin);
+            ensure!(refund_threshold >= T::MinRefundThreshold::get(), Error::<T>::RefundThresholdBelowModuleMin);
             let imb = T::Currency::withdraw(
                 &depositer,
                 amount,
@@ -294,7 +335,21 @@ decl_module! {
             origin,
             submission_id: T::SubmissionId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let approver = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToApprove);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(approver == bounty.gov().leader(), Error::<T>::NotAuthorizedToApproveBountySubmissions);
+            // funds committed to earlier approvals are not available
+            let committed = <Committed<T>>::get(bounty_id);
+            let amount = submission.amount();
+            ensure!(bounty.total().saturating_sub(committed) >= amount, Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable);
+            // contributors may challenge the approval until payment
+            let scheduled = <frame_system::Module<T>>::block_number() + T::ChallengePeriod::get();
+            <Committed<T>>::insert(bounty_id, committed.saturating_add(amount));
+            <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState2::ApprovedAndScheduled(scheduled)));
+            Self::deposit_event(RawEvent::BountySubmissionApproved(submission_id, bounty_id, amount, scheduled));
             Ok(())
         }
         #[weight = 0]
@@ -302,7 +357,28 @@ decl_module! {
             origin,
             submission_id: T::SubmissionId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let rejector = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            match submission.state() {
+                // the leader rejects submissions before approval
+                SubmissionState2::SubmittedAwaitingResponse => {
+                    ensure!(rejector == bounty.gov().leader(), Error::<T>::NotAuthorizedToRejectBountySubmissions);
+                    <Submissions<T>>::remove(submission_id);
+                    Self::deposit_event(RawEvent::BountySubmissionRejected(submission_id, bounty_id, submission.amount()));
+                }
+                // contributors challenge approvals with a veto vote
+                SubmissionState2::ApprovedAndScheduled(n) => {
+                    ensure!(<frame_system::Module<T>>::block_number() < n, Error::<T>::ChallengePeriodForSubmissionApprovalExpired);
+                    ensure!(<BountyTips<T>>::get(bounty_id, &rejector).is_some(), Error::<T>::NotAuthorizedToRejectBountySubmissions);
+                    let veto_threshold = bounty.gov().veto_threshold();
+                    let vote_id = Self::open_contributor_vote(bounty_id, veto_threshold)?;
+                    <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState2::ChallengedAndUnderReview(vote_id)));
+                    Self::deposit_event(RawEvent::SubmissionApprovalChallenged(rejector, submission_id, bounty_id, vote_id));
+                }
+                _ => return Err(Error::<T>::SubmissionNotInValidStateToReject.into()),
+            }
             Ok(())
         }
         #[weight = 0]
@@ -310,7 +386,14 @@ decl_module! {
             origin,
             bounty_id: T::BountyId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let challenger = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(<BountyTips<T>>::get(bounty_id, &challenger).is_some(), Error::<T>::OnlyContributorsCanChallengeBounty);
+            ensure!(bounty.state() == BountyState::NoPendingChallenges, Error::<T>::BountyAlreadyChallengedToClose);
+            let refund_threshold = bounty.gov().refund_threshold();
+            let vote_id = Self::open_contributor_vote(bounty_id, refund_threshold)?;
+            <Bounties<T>>::insert(bounty_id, bounty.set_state(BountyState::ChallengedToClose(vote_id)));
+            Self::deposit_event(RawEvent::BountyRefundChallengeTriggered(challenger, bounty_id, vote_id));
             Ok(())
         }
         fn on_finalize(_n: T::BlockNumber) {
@@ -318,9 +401,13 @@ decl_module! {
             // poll submissions and execute approved and scheduled submissions
             if now % Self::submission_poll_frequency() == Zero::zero() {
                 for (sub_id, sub) in <Submissions<T>>::iter() {
+                    // payments wait for pending refund votes to conclude
+                    let refund_pending = <Bounties<T>>::get(sub.bounty_id())
+                        .map(|b| b.state() != BountyState::NoPendingChallenges)
+                        .unwrap_or(false);
                     match sub.state() {
                         SubmissionState2::ApprovedAndScheduled(n) => {
-                            if now >= n {
+                            if now >= n && !refund_pending {
                                 // TODO: make this path infallible
                                 // approve and execute submission payment
                                 if !Self::approve_and_execute_payment(sub_id).is_ok() {
@@ -334,16 +421,22 @@ decl_module! {
                                 VoteOutcome::Approved => {
                                     // => the submission is rejected because this vote was a challenge to an approval by the depositer
                                     <Submissions<T>>::remove(sub_id);
+                                    Self::release_commitment(sub.bounty_id(), sub.amount());
                                     Self::deposit_event(RawEvent::SpendChallengePassedAndSubmissionRejected(sub_id, sub.bounty_id(), sub.amount()));
                                 },
                                 VoteOutcome::Rejected => {
                                     // TODO: make this path infallible
                                     // => the submission is approved because this vote was a challenge to an approval by the depositer
-                                    if !Self::approve_and_execute_payment(sub_id).is_ok() {
+                                    if !refund_pending && !Self::approve_and_execute_payment(sub_id).is_ok() {
+                                        Self::deposit_event(RawEvent::SubmissionApprovedButPaymentFailed(sub_id, sub.bounty_id(), sub.amount()));
+                                    }
+                                },
+                                _ => {
+                                    // => the veto failed to meet the threshold before the vote ended
+                                    if Self::vote_ended(v, now) && !refund_pending && !Self::approve_and_execute_payment(sub_id).is_ok() {
                                         Self::deposit_event(RawEvent::SubmissionApprovedButPaymentFailed(sub_id, sub.bounty_id(), sub.amount()));
                                     }
                                 },
-                                _ => (),
                             }
                         }
                         _ => (),
@@ -358,9 +451,8 @@ decl_module! {
                         match status {
                             VoteOutcome::Approved => {
                                 // => the refund is executed
-                                if let Ok((amt_to_contributors, amt_to_depositer)) = Self::execute_refund(bid, &bty.gov().leader()) {
-                                    Self::deposit_event(RawEvent::BountyRefunded(bid, amt_to_contributors, amt_to_depositer));
-                                }
+                                let (amt_to_contributors, amt_to_depositer) = Self::execute_refund(bid, &bty.gov().leader());
+                                Self::deposit_event(RawEvent::BountyRefunded(bid, amt_to_contributors, amt_to_depositer));
                             },
                             VoteOutcome::Rejected => {
                                 // => the refund is not executed and the bty state is reset to NoPendingChallenges until next challenge
@@ -369,7 +461,15 @@ decl_module! {
                                 <Bounties<T>>::insert(bid, new_bty);
                                 Self::deposit_event(RawEvent::BountyRefundChallengeRejected(v, bid, total));
                             },
-                            _ => (),
+                            _ => {
+                                // => the refund failed to meet the threshold before the vote ended
+                                if Self::vote_ended(v, now) {
+                                    let new_bty = bty.set_state(BountyState::NoPendingChallenges);
+                                    let total = new_bty.total();
+                                    <Bounties<T>>::insert(bid, new_bty);
+                                    Self::deposit_event(RawEvent::BountyRefundChallengeRejected(v, bid, total));
+                                }
+                            },
                         }
                     }
                 }
@@ -409,11 +509,38 @@ impl<T: Trait> Module<T> {
 
 // Runtime helpers
 impl<T: Trait> Module<T> {
+    /// Opens a vote among the bounty's contributors, weighted by contribution,
+    /// which is approved at `threshold` and rejected once it can no longer pass
+    fn open_contributor_vote(
+        bounty_id: T::BountyId,
+        threshold: Permill,
+    ) -> Result<T::VoteId, DispatchError> {
+        let contributors: WeightedVector<T::AccountId, T::Signal> =
+            <BountyTips<T>>::iter_prefix(bounty_id)
+                .collect::<Vec<(T::AccountId, BalanceOf<T>)>>()
+                .into();
+        <vote::Module<T>>::open_percent_vote(
+            None,
+            contributors,
+            Threshold::new(
+                threshold,
+                Some(Permill::one().saturating_sub(threshold)),
+            ),
+            Some(T::ChallengePeriod::get()),
+        )
+    }
+    fn vote_ended(vote_id: T::VoteId, now: T::BlockNumber) -> bool {
+        <vote::Module<T>>::vote_states(vote_id)
+            .and_then(|v| v.ends())
+            .map(|end| end < now)
+            .unwrap_or(false)
+    }
     fn approve_and_execute_payment(id: T::SubmissionId) -> DispatchResult {
         let submission =
             <Submissions<T>>::get(id).ok_or(Error::<T>::SubmissionDNE)?;
+        // only submissions approved by the leader are paid
         ensure!(
-            submission.state().awaiting_review(),
+            !submission.state().awaiting_review(),
             Error::<T>::SubmissionNotInValidStateToApprove
         );
         let bounty_id = submission.bounty_id();
@@ -423,17 +550,24 @@ impl<T: Trait> Module<T> {
             bounty.total() >= submission.amount(),
             Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable
         );
+        // the bounty account may be drained by paying out its full total
+        let existence = if bounty.total() == submission.amount() {
+            ExistenceRequirement::AllowDeath
+        } else {
+            ExistenceRequirement::KeepAlive
+        };
         // execute payment
         T::Currency::transfer(
             &Self::bounty_account_id(bounty_id),
             &submission.submitter(),
             submission.amount(),
-            ExistenceRequirement::KeepAlive,
+            existence,
         )?;
         let new_bounty = bounty.subtract_funds(submission.amount());
         let (bounty_info, new_total) = (new_bounty.info(), new_bounty.total());
         // submission approved and executed => can be removed
         <Submissions<T>>::remove(id);
+        Self::release_commitment(bounty_id, submission.amount());
         <Bounties<T>>::insert(bounty_id, new_bounty);
         Self::deposit_event(RawEvent::BountyPaymentExecuted(
             bounty_id,
@@ -446,59 +580,55 @@ impl<T: Trait> Module<T> {
         ));
         Ok(())
     }
-    // remainder recipient should be the depositer, aka bounty.gov().leader()
+    /// Refunds the remaining funds to contributors in proportion to their
+    /// contributions, the remainder to `remainder_recipient`, and removes the bounty
     fn execute_refund(
         id: T::BountyId,
-        _remainder_recipient: &T::AccountId,
-    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        remainder_recipient: &T::AccountId,
+    ) -> (BalanceOf<T>, BalanceOf<T>) {
         let from = Self::bounty_account_id(id);
-        let total = T::Currency::total_balance(&from);
+        let remaining = T::Currency::free_balance(&from);
         let contributors: WeightedVector<T::AccountId, BalanceOf<T>> =
-            <BountyTips<T>>::iter()
-                .filter(|(i, _, _)| i == &id)
-                .map(|(_, ac, amt)| (ac, amt))
+            <BountyTips<T>>::iter_prefix(id)
                 .collect::<Vec<(T::AccountId, BalanceOf<T>)>>()
                 .into();
-        let num_of_accounts: u32 = contributors.vec().len() as u32;
-        if num_of_accounts == 1 {
-            T::Currency::transfer(
+        let mut to_contributors = BalanceOf::<T>::zero();
+        for (acc, nom) in contributors.vec().iter() {
+            let due = Permill::from_rational_approximation(
+                *nom,
+                contributors.total(),
+            )
+            .mul_floor(remaining);
+            // a failed refund is added to the remainder
+            if T::Currency::transfer(
                 &from,
-                &contributors.vec()[0].0,
-                total,
+                acc,
+                due,
                 ExistenceRequirement::AllowDeath,
-            )?;
-            // Self::recursive_remove_bounty(id);
-            Ok((total, BalanceOf::<T>::zero()))
-        } else {
-            todo!()
-            // let mut total_to_contributors = BalanceOf::<T>::zero();
-            // let den: T::Signal = contributors.total();
-            // for (acc, nom) in contributors.vec().iter() {
-            //     let due_proportion =
-            //         Permill::from_rational_approximation(nom, &den);
-            //     let due_amount: BalanceOf<T> = due_proportion * total;
-            //     T::Currency::transfer(
-            //         &from,
-            //         &acc,
-            //         due_amount,
-            //         ExistenceRequirement::AllowDeath,
-            //     )?;
-            //     total -= due_amount;
-            //     total_to_contributors += due_amount;
-            // }
-            // // send remainder
-            // T::Currency::transfer(
-            //     &from,
-            //     remainder_recipient,
-            //     total,
-            //     ExistenceRequirement::AllowDeath,
-            // )?;
-            // // Self::recursive_remove_bounty(id);
-            // Ok((total_to_contributors, total))
+            )
+            .is_ok()
+            {
+                to_contributors += due;
+            }
         }
+        let remainder = remaining - to_contributors;
+        let _ = T::Currency::transfer(
+            &from,
+            remainder_recipient,
+            remainder,
+            ExistenceRequirement::AllowDeath,
+        );
+        Self::recursive_remove_bounty(id);
+        (to_contributors, remainder)
+    }
+    /// Frees funds committed to an approved submission once it is paid or vetoed
+    fn release_commitment(bounty_id: T::BountyId, amount: BalanceOf<T>) {
+        <Committed<T>>::mutate(bounty_id, |c| *c = c.saturating_sub(amount));
     }
-    fn _recursive_remove_bounty(id: T::BountyId) {
+    fn recursive_remove_bounty(id: T::BountyId) {
         <Bounties<T>>::remove(id);
+        <BountyTips<T>>::remove_prefix(id);
+        <Committed<T>>::remove(id);
         <Submissions<T>>::iter()
             .filter(|(_, app)| app.bounty_id() == id)
             .for_each(|(app_id, _)| <Submissions<T>>::remove(app_id));
@@ -0,0 +1,334 @@
+use super::*;
+use frame_support::{
+    assert_noop,
+    assert_ok,
+    impl_outer_event,
+    impl_outer_origin,
+    parameter_types,
+    traits::OnFinalize,
+    weights::Weight,
+};
+use frame_system::{self as system,};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::IdentityLookup,
+    Perbill,
+};
+use util::vote::VoterView;
+
+// type aliases
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+mod bounty2 {
+    pub use super::super::*;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        system<T>,
+        pallet_balances<T>,
+        vote<T>,
+        bounty2<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+    type Origin = Origin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Call = ();
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type MaximumBlockLength = MaximumBlockLength;
+    type Version = ();
+    type PalletInfo = ();
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type BaseCallFilter = ();
+    type SystemWeightInfo = ();
+}
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+    pub const MaxLocks: u32 = 50;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = MaxLocks;
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type Cid = u32;
+    type VoteId = u64;
+    type Signal = u64;
+}
+parameter_types! {
+    pub const ChallengePeriod: u64 = 5;
+    pub const Foundation: ModuleId = ModuleId(*b"fundacon");
+    pub const MinDeposit: u64 = 10;
+    pub const MinContribution: u64 = 5;
+    pub const MinVetoThreshold: Permill = Permill::from_percent(10);
+    pub const MinRefundThreshold: Permill = Permill::from_percent(10);
+}
+impl Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+    type BountyId = u64;
+    type SubmissionId = u64;
+    type ChallengePeriod = ChallengePeriod;
+    type Foundation = Foundation;
+    type MinDeposit = MinDeposit;
+    type MinContribution = MinContribution;
+    type MinVetoThreshold = MinVetoThreshold;
+    type MinRefundThreshold = MinRefundThreshold;
+}
+pub type System = system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type Vote = vote::Module<Test>;
+pub type Bounty = Module<Test>;
+
+fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64, u64, u64> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let TestEvent::bounty2(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .last()
+        .unwrap()
+}
+
+/// Auxiliary method for simulating block time passing
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Bounty::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+    }
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 98), (3, 200), (4, 75)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    GenesisConfig::<Test> {
+        bounty_poll_frequency: 1,
+        submission_poll_frequency: 1,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn approval_and_veto_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                40,
+                Permill::from_percent(5),
+                Permill::from_percent(50),
+            ),
+            Error::<Test>::VetoThresholdBelowModuleMin
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            40,
+            Permill::from_percent(50),
+            Permill::from_percent(50),
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 50));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(3), 1, 11u32, 30));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(4), 1, 12u32, 10));
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(2), 1),
+            Error::<Test>::NotAuthorizedToApproveBountySubmissions
+        );
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(
+            RawEvent::BountySubmissionApproved(1, 1, 30, 6),
+            get_last_event()
+        );
+        // only contributors may challenge approvals
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(3), 1),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(2), 1));
+        assert_eq!(
+            RawEvent::SubmissionApprovalChallenged(2, 1, 1, 1),
+            get_last_event()
+        );
+        // veto passes with 50 of 90 contributed
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(2);
+        assert_eq!(
+            RawEvent::SpendChallengePassedAndSubmissionRejected(1, 1, 30),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(1).is_none());
+        // unchallenged approvals are paid after the challenge period
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 2));
+        run_to_block(7);
+        assert!(Bounty::submissions(2).is_some());
+        run_to_block(8);
+        assert_eq!(
+            RawEvent::BountyPaymentExecuted(1, 80, 2, 10, 4, 10, 12),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&4), 85);
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(2), 2),
+            Error::<Test>::SubmissionDNE
+        );
+        // the leader rejects submissions awaiting review
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(3), 1, 13u32, 20));
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(2), 3),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(1), 3));
+        assert_eq!(
+            RawEvent::BountySubmissionRejected(3, 1, 20),
+            get_last_event()
+        );
+    });
+}
+
+#[test]
+fn refund_vote_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            40,
+            Permill::from_percent(50),
+            Permill::from_percent(50),
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 40));
+        assert_noop!(
+            Bounty::trigger_refund_vote(Origin::signed(4), 1),
+            Error::<Test>::OnlyContributorsCanChallengeBounty
+        );
+        assert_ok!(Bounty::trigger_refund_vote(Origin::signed(2), 1));
+        assert_eq!(
+            RawEvent::BountyRefundChallengeTriggered(2, 1, 1),
+            get_last_event()
+        );
+        assert_noop!(
+            Bounty::trigger_refund_vote(Origin::signed(1), 1),
+            Error::<Test>::BountyAlreadyChallengedToClose
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(2);
+        assert_eq!(RawEvent::BountyRefunded(1, 80, 0), get_last_event());
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::bounty_tips(1, 2).is_none());
+        assert_eq!(Balances::total_balance(&1), 100);
+        assert_eq!(Balances::total_balance(&2), 98);
+    });
+}
+
+#[test]
+fn payment_may_drain_the_bounty() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            40,
+            Permill::from_percent(50),
+            Permill::from_percent(50),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(3), 1, 11u32, 40));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        run_to_block(8);
+        assert_eq!(
+            RawEvent::BountyPaymentExecuted(1, 0, 1, 40, 3, 10, 11),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&3), 240);
+        assert_eq!(Balances::total_balance(&Bounty::bounty_account_id(1)), 0);
+    });
+}
+
+#[test]
+fn approvals_cannot_exceed_uncommitted_funds() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            40,
+            Permill::from_percent(50),
+            Permill::from_percent(50),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(3), 1, 11u32, 30));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(4), 1, 12u32, 20));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(Bounty::committed(1), 30);
+        // only 10 of the 40 posted is not yet committed
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 2),
+            Error::<Test>::CannotApproveSubmissionIfAmountExceedsTotalAvailable
+        );
+        // payment releases the commitment
+        run_to_block(8);
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Bounty::committed(1), 0);
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 2));
+        assert_eq!(Bounty::committed(1), 20);
+    });
+}
@@ -38,8 +38,10 @@ use frame_system::{
     self as system,
     ensure_signed,
 };
+use orml_traits::MultiCurrency;
 use sp_runtime::{
     traits::{
+        AtLeast32BitUnsigned,
         CheckedSub,
         Zero,
     },
@@ -47,6 +49,7 @@ use sp_runtime::{
     DispatchResult,
     Permill,
 };
+use sp_std::prelude::*;
 use util::{
     organization::OrgRep,
     traits::GetGroup,
@@ -149,35 +152,78 @@ impl<T: Trait> Module<T> {
         let _ = free
             .checked_sub(&amt)
             .ok_or(Error::<T>::NotEnoughFundsInFreeToMakeTransfer)?;
+        let (payments, remainder) = Self::payments(recipient, amt)?;
+        payments
+            .into_iter()
+            .map(|(acc, amt_due)| -> DispatchResult {
+                T::Currency::transfer(
+                    sender,
+                    &acc,
+                    amt_due,
+                    ExistenceRequirement::KeepAlive,
+                )
+            })
+            .collect::<DispatchResult>()?;
+        // transfer remainder to remainder recipient
+        T::Currency::transfer(
+            sender,
+            remainder_recipient,
+            remainder,
+            ExistenceRequirement::KeepAlive,
+        )?;
+        let amt_transferred_to_org = amt - remainder;
+        Ok((amt_transferred_to_org, remainder))
+    }
+    /// Same as `donate` for an asset of the multi-currency `C`
+    pub fn donate_asset<C: MultiCurrency<T::AccountId>>(
+        currency_id: C::CurrencyId,
+        sender: &T::AccountId,
+        recipient: OrgRep<T::OrgId>,
+        remainder_recipient: &T::AccountId,
+        amt: C::Balance,
+    ) -> Result<(C::Balance, C::Balance), DispatchError> {
+        let _ = C::free_balance(currency_id, sender)
+            .checked_sub(&amt)
+            .ok_or(Error::<T>::NotEnoughFundsInFreeToMakeTransfer)?;
+        let (payments, remainder) = Self::payments(recipient, amt)?;
+        payments
+            .into_iter()
+            .map(|(acc, amt_due)| -> DispatchResult {
+                C::transfer(currency_id, sender, &acc, amt_due)
+            })
+            .collect::<DispatchResult>()?;
+        // transfer remainder to remainder recipient
+        C::transfer(currency_id, sender, remainder_recipient, remainder)?;
+        let amt_transferred_to_org = amt - remainder;
+        Ok((amt_transferred_to_org, remainder))
+    }
+    /// Returns the amount due to each member and the remainder
+    pub fn payments<Balance: AtLeast32BitUnsigned + Copy>(
+        recipient: OrgRep<T::OrgId>,
+        amt: Balance,
+    ) -> Result<(Vec<(T::AccountId, Balance)>, Balance), DispatchError> {
         // match on recipient type to distribute the donation either in proportion
         // to org ownership or equally among all members
-        let remainder = match recipient {
+        match recipient {
             OrgRep::Weighted(org_id) => {
                 // Get the membership set of the Org
                 let group = <org::Module<T>>::get_group(org_id)
                     .ok_or(Error::<T>::CannotDonateToOrgThatDNE)?;
-                // iterate through and pay the transfer
-                let mut transferred_amt = BalanceOf::<T>::zero();
-                group
+                let mut transferred_amt = Balance::zero();
+                let payments = group
                     .0
                     .into_iter()
-                    .map(|acc: T::AccountId| -> DispatchResult {
+                    .map(|acc: T::AccountId| {
                         let amt_due = Self::calculate_proportional_amount(
                             amt,
                             acc.clone(),
                             org_id,
                         )?;
-                        T::Currency::transfer(
-                            sender,
-                            &acc,
-                            amt_due,
-                            ExistenceRequirement::KeepAlive,
-                        )?;
                         transferred_amt += amt_due;
-                        Ok(())
+                        Ok((acc, amt_due))
                     })
-                    .collect::<DispatchResult>()?;
-                amt - transferred_amt
+                    .collect::<Result<Vec<_>, DispatchError>>()?;
+                Ok((payments, amt - transferred_amt))
             }
             OrgRep::Equal(org_id) => {
                 // Get the membership set of the Org
@@ -186,40 +232,24 @@ impl<T: Trait> Module<T> {
                 // amount for each member if equal payment per member
                 let equal_payment =
                     Self::calculate_uniform_amount(amt, group.0.len())?;
-                // iterate through and pay the transfer
-                let mut transferred_amt = BalanceOf::<T>::zero();
-                group
+                let mut transferred_amt = Balance::zero();
+                let payments = group
                     .0
                     .into_iter()
-                    .map(|acc: T::AccountId| -> DispatchResult {
-                        T::Currency::transfer(
-                            sender,
-                            &acc,
-                            equal_payment,
-                            ExistenceRequirement::KeepAlive,
-                        )?;
+                    .map(|acc: T::AccountId| {
                         transferred_amt += equal_payment;
-                        Ok(())
+                        (acc, equal_payment)
                     })
-                    .collect::<DispatchResult>()?;
-                amt - transferred_amt
+                    .collect::<Vec<_>>();
+                Ok((payments, amt - transferred_amt))
             }
-        };
-        // transfer remainder to remainder recipient
-        T::Currency::transfer(
-            sender,
-            remainder_recipient,
-            remainder,
-            ExistenceRequirement::KeepAlive,
-        )?;
-        let amt_transferred_to_org = amt - remainder;
-        Ok((amt_transferred_to_org, remainder))
+        }
     }
-    fn calculate_proportional_amount(
-        amount: BalanceOf<T>,
+    fn calculate_proportional_amount<Balance: AtLeast32BitUnsigned>(
+        amount: Balance,
         account: T::AccountId,
         group: T::OrgId,
-    ) -> Result<BalanceOf<T>, DispatchError> {
+    ) -> Result<Balance, DispatchError> {
         let issuance = <org::Module<T>>::orgs(group)
             .ok_or(Error::<T>::CannotDonateToOrgThatDNE)?
             .total_shares();
@@ -231,10 +261,10 @@ impl<T: Trait> Module<T> {
         );
         Ok(ownership.mul_floor(amount))
     }
-    fn calculate_uniform_amount(
-        amount: BalanceOf<T>,
+    fn calculate_uniform_amount<Balance: AtLeast32BitUnsigned>(
+        amount: Balance,
         group_count: usize,
-    ) -> Result<BalanceOf<T>, DispatchError> {
+    ) -> Result<Balance, DispatchError> {
         let group_size: u32 = group_count as u32;
         let equal_ownership =
             Permill::from_rational_approximation(1u32, group_size);
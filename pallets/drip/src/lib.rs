@@ -12,6 +12,12 @@
 //! This pallet enables scheduling recurring payments at a specified rate of
 //! dripping from the sender to recipient.
 //!
+//! Other modules may start streams through `StreamPayment`. A stream is a
+//! drip which completes once its total is paid. The total is reserved from
+//! the source when the stream starts so it cannot be spent elsewhere.
+//! Cancelling a stream unreserves and returns the amount which was not
+//! dripped. Failed payments emit `DripFailed` and are retried next period.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -25,10 +31,14 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
-    storage::IterableStorageMap,
+    storage::{
+        with_transaction,
+        IterableStorageMap,
+    },
     traits::{
         Currency,
         ExistenceRequirement,
+        ReservableCurrency,
     },
     Parameter,
 };
@@ -43,9 +53,12 @@ use sp_runtime::{
         CheckedDiv,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
+    DispatchError,
     DispatchResult,
+    TransactionOutcome,
 };
 use sp_std::{
     fmt::Debug,
@@ -59,6 +72,7 @@ use util::{
     traits::{
         GenerateUniqueID,
         IDIsAvailable,
+        StreamPayment,
     },
 };
 
@@ -86,7 +100,7 @@ pub trait Trait: System {
         + Zero;
 
     /// Currency type
-    type Currency: Currency<Self::AccountId>;
+    type Currency: ReservableCurrency<Self::AccountId>;
 }
 
 decl_event!(
@@ -103,6 +117,10 @@ decl_event!(
         Dripped(AccountId, AccountId, Balance),
         /// Drip identifier at this BlockNumber with this drip info
         DripCancelled(DripId, BlockNumber, AccountId, AccountId, Balance, BlockNumber),
+        /// Drip identifier, Source, Destination, Total Dripped
+        DripCompleted(DripId, AccountId, AccountId, Balance),
+        /// Drip identifier, Source, Destination, Amount Not Paid
+        DripFailed(DripId, AccountId, AccountId, Balance),
     }
 );
 
@@ -127,6 +145,9 @@ decl_storage! {
         /// The state of drips
         pub Drips get(fn drips): map
             hasher(blake2_128_concat) T::DripId => Option<DripOf<T>>;
+        /// Total and amount left to drip for drips which complete once paid
+        pub DripLimits get(fn drip_limits): map
+            hasher(blake2_128_concat) T::DripId => Option<(BalanceOf<T>, BalanceOf<T>)>;
     }
 }
 
@@ -142,24 +163,7 @@ decl_module! {
             rate: DripRate<T::BlockNumber, BalanceOf<T>>,
         ) -> DispatchResult {
             let source = ensure_signed(origin)?;
-            let first_payment_block = Self::first_next_block_mod_period_is_zero(rate.period_length())
-                .ok_or(Error::<T>::RatePeriodLengthMustBeGreaterThanZero)?;
-            ensure!(source != destination, Error::<T>::DoNotDripToSelf);
-            ensure!(rate.amount() > 0u32.into(), Error::<T>::RateAmountMustBeGreaterThanZero);
-            let drip = Drip::new(source.clone(), destination.clone(), rate);
-            let id = Self::generate_unique_id();
-            <Drips<T>>::insert(id, drip);
-            OpenDripCounter::mutate(|n| *n += 1u32);
-            Self::deposit_event(
-                RawEvent::DripStarted(
-                    id,
-                    first_payment_block,
-                    source,
-                    destination,
-                    rate.amount(),
-                    rate.period_length()
-                )
-            );
+            Self::start(source, destination, rate)?;
             Ok(())
         }
 
@@ -171,19 +175,7 @@ decl_module! {
             let caller = ensure_signed(origin)?;
             let drip = <Drips<T>>::get(id).ok_or(Error::<T>::DripDNE)?;
             ensure!(drip.source() == caller, Error::<T>::NotAuthorizedToCancelDrip);
-            <Drips<T>>::remove(id);
-            OpenDripCounter::mutate(|n| *n -= 1u32);
-            let now = <frame_system::Module<T>>::block_number();
-            Self::deposit_event(
-                RawEvent::DripCancelled(
-                    id,
-                    now,
-                    drip.source(),
-                    drip.destination(),
-                    drip.rate().amount(),
-                    drip.rate().period_length()
-                )
-            );
+            Self::cancel(id, drip);
             Ok(())
         }
 
@@ -192,12 +184,61 @@ decl_module! {
            // TODO: sweep periodically instead of scanning after every block (which is what it does now)
             <Drips<T>>::iter()
                 .filter(|(_, drip)| current_block % drip.rate().period_length() == 0u32.into())
-                .for_each(|(_, drip)| Self::pay(drip));
+                .collect::<Vec<_>>()
+                .into_iter()
+                .for_each(|(id, drip)| Self::pay(id, drip));
         }
     }
 }
 
 impl<T: Trait> Module<T> {
+    fn start(
+        source: T::AccountId,
+        destination: T::AccountId,
+        rate: DripRate<T::BlockNumber, BalanceOf<T>>,
+    ) -> Result<T::DripId, DispatchError> {
+        let first_payment_block =
+            Self::first_next_block_mod_period_is_zero(rate.period_length())
+                .ok_or(Error::<T>::RatePeriodLengthMustBeGreaterThanZero)?;
+        ensure!(source != destination, Error::<T>::DoNotDripToSelf);
+        ensure!(
+            rate.amount() > 0u32.into(),
+            Error::<T>::RateAmountMustBeGreaterThanZero
+        );
+        let drip = Drip::new(source.clone(), destination.clone(), rate);
+        let id = Self::generate_unique_id();
+        <Drips<T>>::insert(id, drip);
+        OpenDripCounter::mutate(|n| *n += 1u32);
+        Self::deposit_event(RawEvent::DripStarted(
+            id,
+            first_payment_block,
+            source,
+            destination,
+            rate.amount(),
+            rate.period_length(),
+        ));
+        Ok(id)
+    }
+    /// Removes the drip, returning the amount left if it was a stream
+    fn cancel(id: T::DripId, drip: DripOf<T>) -> BalanceOf<T> {
+        <Drips<T>>::remove(id);
+        OpenDripCounter::mutate(|n| *n -= 1u32);
+        let now = <frame_system::Module<T>>::block_number();
+        Self::deposit_event(RawEvent::DripCancelled(
+            id,
+            now,
+            drip.source(),
+            drip.destination(),
+            drip.rate().amount(),
+            drip.rate().period_length(),
+        ));
+        <DripLimits<T>>::take(id)
+            .map(|(_, left)| {
+                T::Currency::unreserve(&drip.source(), left);
+                left
+            })
+            .unwrap_or_else(Zero::zero)
+    }
     fn first_next_block_mod_period_is_zero(
         period_length: T::BlockNumber,
     ) -> Option<T::BlockNumber> {
@@ -214,23 +255,62 @@ impl<T: Trait> Module<T> {
             None
         }
     }
-    fn pay(drip: Drip<T::AccountId, DripRate<T::BlockNumber, BalanceOf<T>>>) {
-        let (src, dest, amt) =
-            (&drip.source(), &drip.destination(), drip.rate().amount());
-        if T::Currency::transfer(
-            src,
-            dest,
-            amt,
-            ExistenceRequirement::KeepAlive,
-        )
-        .is_ok()
-        {
+    fn pay(
+        id: T::DripId,
+        drip: Drip<T::AccountId, DripRate<T::BlockNumber, BalanceOf<T>>>,
+    ) {
+        let limit = <DripLimits<T>>::get(id);
+        let amt = if let Some((_, left)) = limit {
+            drip.rate().amount().min(left)
+        } else {
+            drip.rate().amount()
+        };
+        let (src, dest) = (&drip.source(), &drip.destination());
+        // streams pay out of their reserved total
+        let paid = with_transaction(|| {
+            if limit.is_some() {
+                T::Currency::unreserve(src, amt);
+            }
+            match T::Currency::transfer(
+                src,
+                dest,
+                amt,
+                ExistenceRequirement::KeepAlive,
+            ) {
+                Ok(()) => TransactionOutcome::Commit(true),
+                Err(_) => TransactionOutcome::Rollback(false),
+            }
+        });
+        if paid {
             Self::deposit_event(RawEvent::Dripped(
                 drip.source(),
                 drip.destination(),
-                drip.rate().amount(),
+                amt,
             ));
-        } // TODO: should notify source and dest accounts somehow for error branch
+            if let Some((total, left)) = limit {
+                let left = left.saturating_sub(amt);
+                if left.is_zero() {
+                    <Drips<T>>::remove(id);
+                    <DripLimits<T>>::remove(id);
+                    OpenDripCounter::mutate(|n| *n -= 1u32);
+                    Self::deposit_event(RawEvent::DripCompleted(
+                        id,
+                        drip.source(),
+                        drip.destination(),
+                        total,
+                    ));
+                } else {
+                    <DripLimits<T>>::insert(id, (total, left));
+                }
+            }
+        } else {
+            Self::deposit_event(RawEvent::DripFailed(
+                id,
+                drip.source(),
+                drip.destination(),
+                amt,
+            ));
+        }
     }
 }
 
@@ -250,3 +330,43 @@ impl<T: Trait> GenerateUniqueID<T::DripId> for Module<T> {
         id_counter
     }
 }
+
+impl<T: Trait> StreamPayment<T::AccountId, T::BlockNumber, BalanceOf<T>>
+    for Module<T>
+{
+    type DripId = T::DripId;
+    fn start_stream(
+        source: &T::AccountId,
+        destination: &T::AccountId,
+        amount: BalanceOf<T>,
+        period_length: T::BlockNumber,
+        total: BalanceOf<T>,
+    ) -> Result<T::DripId, DispatchError> {
+        ensure!(
+            !total.is_zero(),
+            Error::<T>::RateAmountMustBeGreaterThanZero
+        );
+        // the total is earmarked for the stream until paid or cancelled
+        T::Currency::reserve(source, total)?;
+        let id = match Self::start(
+            source.clone(),
+            destination.clone(),
+            DripRate::new(amount, period_length),
+        ) {
+            Ok(id) => id,
+            Err(e) => {
+                T::Currency::unreserve(source, total);
+                return Err(e)
+            }
+        };
+        <DripLimits<T>>::insert(id, (total, total));
+        Ok(id)
+    }
+    fn cancel_stream(id: T::DripId) -> Result<BalanceOf<T>, DispatchError> {
+        let drip = <Drips<T>>::get(id).ok_or(Error::<T>::DripDNE)?;
+        Ok(Self::cancel(id, drip))
+    }
+    fn is_streaming(id: T::DripId) -> bool {
+        <Drips<T>>::contains_key(id)
+    }
+}
@@ -189,3 +189,34 @@ fn drip_cancelled() {
         assert_eq!(Balances::total_balance(&2), 120);
     });
 }
+
+#[test]
+fn streams_complete_once_paid() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(8);
+        assert_noop!(
+            Drip::start_stream(&1, &2, 4, 10, 0),
+            Error::<Test>::RateAmountMustBeGreaterThanZero
+        );
+        assert_ok!(Drip::start_stream(&1, &2, 4, 10, 10));
+        assert_ok!(Drip::start_stream(&1, &3, 4, 10, 10));
+        // stream totals are reserved until paid or cancelled
+        assert_eq!(Balances::reserved_balance(&1), 20);
+        run_to_block(14);
+        assert_eq!(Balances::total_balance(&2), 104);
+        assert_eq!(Drip::cancel_stream(2), Ok(6));
+        assert_eq!(Balances::reserved_balance(&1), 6);
+        run_to_block(21);
+        assert_eq!(Balances::total_balance(&2), 108);
+        run_to_block(31);
+        // the last payment is the remainder
+        assert_eq!(Balances::total_balance(&2), 110);
+        assert_eq!(Balances::total_balance(&1), 986);
+        assert_eq!(Balances::reserved_balance(&1), 0);
+        assert!(Drip::drips(1).is_none());
+        assert!(Drip::drip_limits(1).is_none());
+        assert_eq!(Drip::open_drip_counter(), 0);
+        run_to_block(41);
+        assert_eq!(Balances::total_balance(&2), 110);
+    });
+}
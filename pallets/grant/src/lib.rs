@@ -9,7 +9,8 @@
 //!
 //! This pallet expresses the logic for posting employment opportunities,
 //! approving on-chain applications, and periodically transferring payment
-//! for services rendered.
+//! for services rendered. Submissions are reviewed by the foundation's
+//! governance or by its review committees.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
@@ -25,16 +26,20 @@ use frame_support::{
     decl_storage,
     ensure,
     storage::{
+        with_transaction,
         IterableStorageDoubleMap,
         IterableStorageMap,
     },
     traits::{
+        BalanceStatus,
         Currency,
         ExistenceRequirement,
         Get,
+        ReservableCurrency,
         WithdrawReason,
         WithdrawReasons,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -44,16 +49,22 @@ use frame_system::{
 use org::Trait as Org;
 use parity_scale_codec::Codec;
 use sp_runtime::{
+    helpers_128bit::multiply_by_rational,
     traits::{
         AccountIdConversion,
         AtLeast32Bit,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
+        UniqueSaturatedFrom,
+        UniqueSaturatedInto,
         Zero,
     },
+    DispatchError,
     DispatchResult,
     ModuleId,
     Permill,
+    TransactionOutcome,
 };
 use sp_std::{
     fmt::Debug,
@@ -66,7 +77,10 @@ use util::{
         GrantApplication,
         MilestoneStatus,
         MilestoneSubmission,
+        QueuedReview,
         Recipient,
+        ReviewCommittee,
+        ReviewScope,
     },
     meta::{
         ResolutionMetadata,
@@ -74,9 +88,12 @@ use util::{
     },
     organization::OrgRep,
     traits::{
+        GetGroup,
         GetVoteOutcome,
         GroupMembership,
         OpenVote,
+        RecordReputation,
+        StreamPayment,
     },
     vote::VoteOutcome,
 };
@@ -86,15 +103,15 @@ use vote::Trait as Vote;
 type BalanceOf<T> = <<T as donate::Trait>::Currency as Currency<
     <T as System>::AccountId,
 >>::Balance;
-type GovernanceOf<T> = ResolutionMetadata<
-    <T as System>::AccountId,
-    VoteMetadata<
-        OrgRep<<T as Org>::OrgId>,
-        <T as Vote>::Signal,
-        Permill,
-        <T as System>::BlockNumber,
-    >,
+type VoteMetadataOf<T> = VoteMetadata<
+    OrgRep<<T as Org>::OrgId>,
+    <T as Vote>::Signal,
+    Permill,
+    <T as System>::BlockNumber,
 >;
+type GovernanceOf<T> =
+    ResolutionMetadata<<T as System>::AccountId, VoteMetadataOf<T>>;
+type CommitteeOf<T> = ReviewCommittee<<T as Org>::Cid, VoteMetadataOf<T>>;
 type FoundationOf<T> =
     Foundation<<T as Org>::Cid, BalanceOf<T>, GovernanceOf<T>>;
 type RecipientOf<T> =
@@ -104,7 +121,13 @@ type GrantApp<T> = GrantApplication<
     <T as Org>::Cid,
     RecipientOf<T>,
     BalanceOf<T>,
-    ApplicationState<<T as Vote>::VoteId>,
+    ApplicationState<<T as Vote>::VoteId, <T as Org>::Cid>,
+>;
+type Review<T> = QueuedReview<
+    <T as Trait>::ApplicationId,
+    <T as Trait>::MilestoneId,
+    <T as Vote>::VoteId,
+    <T as Trait>::FoundationId,
 >;
 type Milestone<T> = MilestoneSubmission<
     <T as Trait>::FoundationId,
@@ -112,7 +135,7 @@ type Milestone<T> = MilestoneSubmission<
     <T as Org>::Cid,
     RecipientOf<T>,
     BalanceOf<T>,
-    MilestoneStatus<<T as Vote>::VoteId>,
+    MilestoneStatus<<T as Vote>::VoteId, <T as Org>::Cid>,
 >;
 
 pub trait Trait: System + Org + Vote + donate::Trait {
@@ -158,6 +181,19 @@ pub trait Trait: System + Org + Vote + donate::Trait {
         + PartialEq
         + Zero;
 
+    /// The review committee identifier
+    type CommitteeId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+
     /// The foundational foundation
     type BigFoundation: Get<ModuleId>;
 
@@ -166,30 +202,105 @@ pub trait Trait: System + Org + Vote + donate::Trait {
 
     /// Minimum contribution to open foundation
     type MinContribution: Get<BalanceOf<Self>>;
+
+    /// Deposit reserved by applicants, slashed if the application is spam
+    type ApplicationDeposit: Get<BalanceOf<Self>>;
+
+    /// Max number of queued reviews polled in one block
+    type MaxReviewPollsPerBlock: Get<u32>;
+
+    /// Max number of review committees registered by one foundation
+    type MaxReviewCommittees: Get<u32>;
+
+    /// Records approved milestones for their recipients
+    type Reputation: RecordReputation<
+        Self::AccountId,
+        MilestoneId = (Self::ApplicationId, Self::MilestoneId),
+        Balance = BalanceOf<Self>,
+    >;
+
+    /// The drip identifier used by `Drip`
+    type DripId: Parameter + Member + Copy;
+
+    /// Streams approved milestones for foundations which choose to
+    type Drip: StreamPayment<
+        Self::AccountId,
+        Self::BlockNumber,
+        BalanceOf<Self>,
+        DripId = Self::DripId,
+    >;
 }
 
 decl_event!(
     pub enum Event<T>
     where
         <T as System>::AccountId,
+        <T as System>::BlockNumber,
         <T as Org>::Cid,
+        <T as Org>::OrgId,
         <T as Vote>::VoteId,
         <T as Trait>::FoundationId,
         <T as Trait>::ApplicationId,
         <T as Trait>::MilestoneId,
+        <T as Trait>::CommitteeId,
+        <T as Trait>::DripId,
         Balance = BalanceOf<T>,
         Recipient = RecipientOf<T>,
     {
         FoundationCreated(FoundationId, Balance, Cid),
         FoundationDonation(AccountId, Balance, FoundationId, Balance),
+        /// Foundation Identifier, Donor, Amount Refunded
+        FoundationDonorRefunded(FoundationId, AccountId, Balance),
+        /// Foundation Identifier, Org, Amount Donated To Org Members
+        FoundationSwept(FoundationId, OrgId, Balance),
+        /// Foundation Identifier, Balance Remaining At Closure
+        FoundationClosed(FoundationId, Balance),
+        /// Foundation Identifier, Reputation Score Required To Apply (None if any recipient may apply)
+        FoundationMinReputationSet(FoundationId, Option<u32>),
+        /// Foundation Identifier, Period Length And Number Of Periods (None if milestones are paid in one transfer)
+        FoundationStreamedPaymentSet(FoundationId, Option<(BlockNumber, u32)>),
+        /// Foundation Identifier, Committee Identifier, Submissions Reviewed, Committee Remit
+        ReviewCommitteeAdded(FoundationId, CommitteeId, ReviewScope, Cid),
+        ReviewCommitteeRemoved(FoundationId, CommitteeId),
         ApplicationSubmitted(FoundationId, ApplicationId, Recipient, Balance, Cid),
         ApplicationReviewTriggered(FoundationId, ApplicationId, VoteId),
+        ApplicationCommitteeReviewTriggered(FoundationId, ApplicationId, CommitteeId, VoteId),
+        /// Foundation Identifier, Application Identifier, Vote Identifier (ended undecided)
+        ApplicationReviewLapsed(FoundationId, ApplicationId, VoteId),
         ApplicationApproved(FoundationId, ApplicationId, Cid),
         ApplicationRejected(FoundationId, ApplicationId),
+        /// Foundation Identifier, Application Identifier, Deposit Slashed To Foundation
+        ApplicationRejectedAsSpam(FoundationId, ApplicationId, Balance),
+        ApplicationWithdrawn(FoundationId, ApplicationId),
+        /// Foundation Identifier, Application Identifier, New Amount Requested, New Submission
+        ApplicationAmended(FoundationId, ApplicationId, Balance, Cid),
+        /// Foundation Identifier, Application Identifier, Reviewer Note
+        ApplicationChangesRequested(FoundationId, ApplicationId, Cid),
+        ApplicationClosed(FoundationId, ApplicationId),
         MilestoneSubmitted(FoundationId, ApplicationId, MilestoneId, Recipient, Balance, Cid),
         MilestoneReviewTriggered(FoundationId, ApplicationId, MilestoneId, VoteId),
+        MilestoneCommitteeReviewTriggered(FoundationId, ApplicationId, MilestoneId, CommitteeId, VoteId),
+        /// Foundation Identifier, Application Identifier, Milestone Identifier, Vote Identifier (ended undecided)
+        MilestoneReviewLapsed(FoundationId, ApplicationId, MilestoneId, VoteId),
         MilestoneApproved(FoundationId, ApplicationId, MilestoneId, Cid),
         MilestoneRejected(FoundationId, ApplicationId, MilestoneId),
+        /// Foundation Identifier, Application Identifier, Milestone Identifier, Amount Paid Or Streamed
+        MilestonePaid(FoundationId, ApplicationId, MilestoneId, Balance),
+        MilestoneWithdrawn(FoundationId, ApplicationId, MilestoneId),
+        /// Foundation Identifier, Application Identifier, Milestone Identifier, New Amount Requested, New Submission
+        MilestoneAmended(FoundationId, ApplicationId, MilestoneId, Balance, Cid),
+        /// Foundation Identifier, Application Identifier, Milestone Identifier, Reviewer Note
+        MilestoneChangesRequested(FoundationId, ApplicationId, MilestoneId, Cid),
+        /// Foundation Identifier, Application Identifier, Milestone Identifier, Drip Identifier
+        MilestoneStreamStarted(FoundationId, ApplicationId, MilestoneId, DripId),
+        /// Foundation Identifier, Application Identifier, Milestone Identifier, Amount Not Streamed
+        MilestoneStreamCancelled(FoundationId, ApplicationId, MilestoneId, Balance),
+        /// Foundation Identifier, Vote Identifier
+        FoundationClosureProposed(FoundationId, VoteId),
+        /// Foundation Identifier, Vote Identifier (rejected or ended undecided)
+        FoundationClosureRejected(FoundationId, VoteId),
+        /// Foundation Identifier, Vote Identifier (approved but the foundation could not be closed)
+        FoundationClosureFailed(FoundationId, VoteId),
     }
 );
 
@@ -214,6 +325,37 @@ decl_error! {
         MilestoneNotInValidStateToReject,
         NotAuthorizedToApproveMilestone,
         NotAuthorizedToRejectMilestone,
+        NotAuthorizedToSetMinReputation,
+        ReputationBelowFoundationMinimum,
+        NotAuthorizedToSetStreamedPayment,
+        StreamedPaymentRequiresNonZeroPeriods,
+        MilestoneNotStreaming,
+        NotAuthorizedToCancelMilestoneStream,
+        NotAuthorizedToCloseFoundation,
+        ApplicationNotInValidStateToWithdraw,
+        NotAuthorizedToWithdrawApplication,
+        ApplicationNotInValidStateToAmend,
+        NotAuthorizedToAmendApplication,
+        ApplicationNotInValidStateToRequestChanges,
+        NotAuthorizedToRequestApplicationChanges,
+        MilestoneNotInValidStateToWithdraw,
+        NotAuthorizedToWithdrawMilestone,
+        MilestoneNotInValidStateToAmend,
+        NotAuthorizedToAmendMilestone,
+        MilestoneNotInValidStateToRequestChanges,
+        NotAuthorizedToRequestMilestoneChanges,
+        NotAuthorizedToManageReviewCommittees,
+        ReviewCommitteeDNE,
+        ReviewCommitteesExceedModuleMax,
+        ApplicationNotInValidStateToClose,
+        NotAuthorizedToCloseApplication,
+        ApplicationHasOutstandingMilestones,
+        FoundationHasOutstandingGrants,
+        MilestoneNotInValidStateToRetryPayment,
+        MilestonePaymentFailed,
+        ApplicationMustBeApprovedByReviewCommittees,
+        MilestoneMustBeApprovedByReviewCommittees,
+        FoundationClosureAlreadyProposed,
     }
 }
 
@@ -236,18 +378,61 @@ decl_storage! {
         pub FoundationDonations get(fn bounty_tips): double_map
             hasher(blake2_128_concat) T::FoundationId,
             hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+        /// Minimum reputation score required of recipients to apply to each foundation
+        pub MinReputation get(fn min_reputation): map
+            hasher(blake2_128_concat) T::FoundationId => Option<u32>;
+        /// Period length and number of periods over which each foundation
+        /// streams approved milestones
+        pub StreamedPayments get(fn streamed_payments): map
+            hasher(blake2_128_concat) T::FoundationId => Option<(T::BlockNumber, u32)>;
+
+        /// Uid generation helper for CommitteeIds
+        CommitteeNonce get(fn committee_nonce): map
+            hasher(blake2_128_concat) T::FoundationId => T::CommitteeId;
+        /// Committees reviewing each foundation's submissions in their scope
+        pub ReviewCommittees get(fn review_committees): double_map
+            hasher(blake2_128_concat) T::FoundationId,
+            hasher(blake2_128_concat) T::CommitteeId => Option<CommitteeOf<T>>;
 
         /// Applications
         pub Applications get(fn applications): map
             hasher(blake2_128_concat) T::ApplicationId => Option<GrantApp<T>>;
+        /// Deposits reserved by applicants, returned when the application is
+        /// approved or rejected unless it is rejected as spam
+        pub ApplicationDeposits get(fn application_deposits): map
+            hasher(blake2_128_concat) T::ApplicationId => Option<(T::AccountId, BalanceOf<T>)>;
         /// Milestones
         pub Milestones get(fn milestones): double_map
             hasher(blake2_128_concat) T::ApplicationId,
             hasher(blake2_128_concat) T::MilestoneId => Option<Milestone<T>>;
+        /// Committee votes for applications under review by committees
+        pub ApplicationCommitteeVotes get(fn application_committee_votes): double_map
+            hasher(blake2_128_concat) T::ApplicationId,
+            hasher(blake2_128_concat) T::CommitteeId => Option<T::VoteId>;
+        /// Committee votes for milestones under review by committees
+        pub MilestoneCommitteeVotes get(fn milestone_committee_votes): double_map
+            hasher(blake2_128_concat) (T::ApplicationId, T::MilestoneId),
+            hasher(blake2_128_concat) T::CommitteeId => Option<T::VoteId>;
+        /// Drips streaming approved milestones to their recipients
+        pub MilestoneDrips get(fn milestone_drips): double_map
+            hasher(blake2_128_concat) T::ApplicationId,
+            hasher(blake2_128_concat) T::MilestoneId => Option<T::DripId>;
+
+        /// Votes to close each foundation, with the proposer and the org to
+        /// sweep the remaining balance to
+        pub FoundationClosures get(fn foundation_closures): map
+            hasher(blake2_128_concat) T::FoundationId => Option<(T::VoteId, T::AccountId, Option<OrgRep<T::OrgId>>)>;
 
-        /// Frequency with which applications are polled and updated
+        /// Reviews keyed by the block of their next poll
+        pub ReviewQueue get(fn review_queue): double_map
+            hasher(twox_64_concat) T::BlockNumber,
+            hasher(blake2_128_concat) Review<T> => Option<()>;
+        /// Earliest block whose queued reviews may not have all been polled
+        ReviewCursor get(fn review_cursor): T::BlockNumber;
+
+        /// Frequency with which applications under review are polled before their vote ends
         pub ApplicationPollFrequency get(fn application_poll_frequency) config(): T::BlockNumber;
-        /// Frequency with which milestones are polled and updated
+        /// Frequency with which milestones under review are polled before their vote ends
         pub MilestonePollFrequency get(fn milestone_poll_frequency) config(): T::BlockNumber;
     }
 }
@@ -306,6 +491,128 @@ decl_module! {
             Self::deposit_event(RawEvent::FoundationDonation(giver, new_amount, id, total));
             Ok(())
         }
+        /// Closes a foundation none of whose applications are live or have
+        /// outstanding milestones. Pending applications are rejected and the
+        /// remaining balance is either refunded to donors in proportion to their
+        /// donations or swept to `sweep_to` through `donate`. Whatever is left over
+        /// goes to the largest donor, the foundation stays open if that fails.
+        #[weight = 0]
+        fn close_foundation(
+            origin,
+            foundation_id: T::FoundationId,
+            sweep_to: Option<OrgRep<T::OrgId>>,
+        ) -> DispatchResult {
+            let closer = ensure_signed(origin)?;
+            let foundation = <Foundations<T>>::get(foundation_id).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&closer), Error::<T>::NotAuthorizedToCloseFoundation);
+            ensure!(!Self::has_outstanding_grants(foundation_id), Error::<T>::FoundationHasOutstandingGrants);
+            let remaining = Self::try_close(foundation_id, sweep_to, &closer)?;
+            Self::deposit_event(RawEvent::FoundationClosed(foundation_id, remaining));
+            Ok(())
+        }
+        /// Opens a governance vote to close the foundation as `close_foundation`
+        /// would, for foundations governed without a sudo. The proposer receives
+        /// what is left over if the foundation has no donors.
+        #[weight = 0]
+        fn propose_foundation_closure(
+            origin,
+            foundation_id: T::FoundationId,
+            sweep_to: Option<OrgRep<T::OrgId>>,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            let foundation = <Foundations<T>>::get(foundation_id).ok_or(Error::<T>::FoundationDNE)?;
+            let gov = foundation.gov().vote().ok_or(Error::<T>::NotAuthorizedToCloseFoundation)?;
+            ensure!(<org::Module<T>>::is_member_of_group(gov.org().org(), &proposer), Error::<T>::NotAuthorizedToCloseFoundation);
+            ensure!(<FoundationClosures<T>>::get(foundation_id).is_none(), Error::<T>::FoundationClosureAlreadyProposed);
+            ensure!(!Self::has_outstanding_grants(foundation_id), Error::<T>::FoundationHasOutstandingGrants);
+            let vote_id = Self::open_review_vote(gov, foundation.info())?;
+            <FoundationClosures<T>>::insert(foundation_id, (vote_id, proposer, sweep_to));
+            Self::queue_review(QueuedReview::Closure(foundation_id, vote_id), Self::application_poll_frequency());
+            Self::deposit_event(RawEvent::FoundationClosureProposed(foundation_id, vote_id));
+            Ok(())
+        }
+        /// Requires the recipients of new applications to have at least
+        /// `min_score` in `Reputation`, which records approved milestones
+        #[weight = 0]
+        fn set_min_reputation(
+            origin,
+            foundation_id: T::FoundationId,
+            min_score: Option<u32>,
+        ) -> DispatchResult {
+            let setter = ensure_signed(origin)?;
+            let foundation = <Foundations<T>>::get(foundation_id).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&setter), Error::<T>::NotAuthorizedToSetMinReputation);
+            if let Some(score) = min_score {
+                <MinReputation<T>>::insert(foundation_id, score);
+            } else {
+                <MinReputation<T>>::remove(foundation_id);
+            }
+            Self::deposit_event(RawEvent::FoundationMinReputationSet(foundation_id, min_score));
+            Ok(())
+        }
+        /// Streams approved milestones to their recipients through `drip` over
+        /// `periods` of `period_length` instead of paying them in one transfer. Org
+        /// recipients are still paid in one donation. The amount not yet streamed
+        /// is reserved from the foundation, so it cannot pay other milestones.
+        #[weight = 0]
+        fn set_streamed_payment(
+            origin,
+            foundation_id: T::FoundationId,
+            stream: Option<(T::BlockNumber, u32)>,
+        ) -> DispatchResult {
+            let setter = ensure_signed(origin)?;
+            let foundation = <Foundations<T>>::get(foundation_id).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&setter), Error::<T>::NotAuthorizedToSetStreamedPayment);
+            if let Some((period_length, periods)) = stream {
+                ensure!(!period_length.is_zero() && periods > 0, Error::<T>::StreamedPaymentRequiresNonZeroPeriods);
+                <StreamedPayments<T>>::insert(foundation_id, (period_length, periods));
+            } else {
+                <StreamedPayments<T>>::remove(foundation_id);
+            }
+            Self::deposit_event(RawEvent::FoundationStreamedPaymentSet(foundation_id, stream));
+            Ok(())
+        }
+        /// Registers a committee over a distinct org to review applications,
+        /// milestones or both. Foundations without committees in scope are
+        /// reviewed by their governance vote.
+        #[weight = 0]
+        fn add_review_committee(
+            origin,
+            foundation_id: T::FoundationId,
+            info: T::Cid,
+            vote: VoteMetadataOf<T>,
+            scope: ReviewScope,
+        ) -> DispatchResult {
+            let adder = ensure_signed(origin)?;
+            let foundation = <Foundations<T>>::get(foundation_id).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&adder), Error::<T>::NotAuthorizedToManageReviewCommittees);
+            ensure!(
+                (<ReviewCommittees<T>>::iter_prefix(foundation_id).count() as u32) < T::MaxReviewCommittees::get(),
+                Error::<T>::ReviewCommitteesExceedModuleMax
+            );
+            // TODO: screen validity of `vote` input in context of registered orgs
+            let id = Self::committee_generate_uid(foundation_id);
+            <ReviewCommittees<T>>::insert(foundation_id, id, CommitteeOf::<T>::new(info.clone(), vote, scope));
+            Self::deposit_event(RawEvent::ReviewCommitteeAdded(foundation_id, id, scope, info));
+            Ok(())
+        }
+        #[weight = 0]
+        fn remove_review_committee(
+            origin,
+            foundation_id: T::FoundationId,
+            committee_id: T::CommitteeId,
+        ) -> DispatchResult {
+            let remover = ensure_signed(origin)?;
+            let foundation = <Foundations<T>>::get(foundation_id).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&remover), Error::<T>::NotAuthorizedToManageReviewCommittees);
+            ensure!(<ReviewCommittees<T>>::get(foundation_id, committee_id).is_some(), Error::<T>::ReviewCommitteeDNE);
+            // reviews already triggered still wait for the committee's vote
+            <ReviewCommittees<T>>::remove(foundation_id, committee_id);
+            Self::deposit_event(RawEvent::ReviewCommitteeRemoved(foundation_id, committee_id));
+            Ok(())
+        }
+        /// Reserves `ApplicationDeposit` from the applicant, returned once the
+        /// application is approved, rejected or withdrawn
         #[weight = 0]
         fn submit_application(
             origin,
@@ -314,14 +621,27 @@ decl_module! {
             recipient: RecipientOf<T>,
             amount_requested: BalanceOf<T>,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let applicant = ensure_signed(origin)?;
             ensure!(!Self::foundation_id_is_available(foundation_id), Error::<T>::FoundationDNE);
+            if let Some(min_score) = <MinReputation<T>>::get(foundation_id) {
+                ensure!(
+                    T::Reputation::reputation_score(&recipient.account()) >= min_score,
+                    Error::<T>::ReputationBelowFoundationMinimum
+                );
+            }
+            let deposit = T::ApplicationDeposit::get();
+            <T as donate::Trait>::Currency::reserve(&applicant, deposit)?;
             let app = GrantApp::<T>::new(foundation_id, submission_ref.clone(), recipient.clone(), amount_requested);
             let id = Self::application_generate_uid();
             <Applications<T>>::insert(id, app);
+            if !deposit.is_zero() {
+                <ApplicationDeposits<T>>::insert(id, (applicant, deposit));
+            }
             Self::deposit_event(RawEvent::ApplicationSubmitted(foundation_id, id, recipient, amount_requested, submission_ref));
             Ok(())
         }
+        /// Opens a vote for every committee in scope, the application is approved
+        /// once every committee approves it and rejected if any rejects it
         #[weight = 0]
         fn trigger_application_review(
             origin,
@@ -331,19 +651,27 @@ decl_module! {
             let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
             ensure!(app.awaiting_review(), Error::<T>::ApplicationNotInValidStateToTriggerReview);
             let foundation = <Foundations<T>>::get(app.foundation_id()).ok_or(Error::<T>::FoundationDNE)?;
-            let auth = if let Some(gov) = foundation.gov().vote() {
-                <org::Module<T>>::is_member_of_group(gov.org().org(), &trigger_er) || foundation.gov().is_sudo(&trigger_er)
-            } else { false };
-            ensure!(auth, Error::<T>::NotAuthorizedToTriggerApplicationReview);
-            let new_vote_id = match foundation.gov().vote().ok_or(Error::<T>::NotAuthorizedToTriggerApplicationReview)? {
-                VoteMetadata::Signal(v) => <vote::Module<T>>::open_vote(Some(app.submission_ref()), v.org, v.threshold, v.duration)?,
-                VoteMetadata::Percentage(v) => <vote::Module<T>>::open_percent_vote(Some(app.submission_ref()), v.org, v.threshold, v.duration)?,
+            let committees = Self::committees_reviewing(app.foundation_id(), |s| s.reviews_applications());
+            ensure!(Self::can_trigger_review(&foundation, &committees, &trigger_er), Error::<T>::NotAuthorizedToTriggerApplicationReview);
+            let new_vote_id = if committees.is_empty() {
+                let gov = foundation.gov().vote().ok_or(Error::<T>::NotAuthorizedToTriggerApplicationReview)?;
+                Self::open_review_vote(gov, app.submission_ref())?
+            } else {
+                let votes = Self::open_committee_votes(committees, app.submission_ref())?;
+                <ApplicationCommitteeVotes<T>>::remove_prefix(application_id);
+                for (committee_id, vote_id) in votes.iter() {
+                    <ApplicationCommitteeVotes<T>>::insert(application_id, committee_id, vote_id);
+                    Self::deposit_event(RawEvent::ApplicationCommitteeReviewTriggered(app.foundation_id(), application_id, *committee_id, *vote_id));
+                }
+                votes[0].1
             };
             let new_app = app.set_state(ApplicationState::UnderReviewByAcceptanceCommittee(new_vote_id));
             <Applications<T>>::insert(application_id, new_app);
+            Self::queue_review(QueuedReview::Application(application_id, new_vote_id), Self::application_poll_frequency());
             Self::deposit_event(RawEvent::ApplicationReviewTriggered(app.foundation_id(), application_id, new_vote_id));
             Ok(())
         }
+        /// Approves by sudo, unless committees are in scope to review
         #[weight = 0]
         fn approve_application(
             origin,
@@ -354,7 +682,9 @@ decl_module! {
             ensure!(app.awaiting_review(), Error::<T>::ApplicationNotInValidStateToApprove);
             let foundation = <Foundations<T>>::get(app.foundation_id()).ok_or(Error::<T>::FoundationDNE)?;
             ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToApproveApplication);
+            ensure!(Self::committees_reviewing(app.foundation_id(), |s| s.reviews_applications()).is_empty(), Error::<T>::ApplicationMustBeApprovedByReviewCommittees);
             let new_app = app.set_state(ApplicationState::ApprovedAndLive);
+            Self::return_application_deposit(application_id);
             <Applications<T>>::insert(application_id, new_app);
             Self::deposit_event(RawEvent::ApplicationApproved(app.foundation_id(), application_id, app.submission_ref()));
             Ok(())
@@ -369,10 +699,99 @@ decl_module! {
             ensure!(app.awaiting_review(), Error::<T>::ApplicationNotInValidStateToReject);
             let foundation = <Foundations<T>>::get(app.foundation_id()).ok_or(Error::<T>::FoundationDNE)?;
             ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToRejectApplication);
+            Self::return_application_deposit(application_id);
             <Applications<T>>::remove(application_id);
             Self::deposit_event(RawEvent::ApplicationRejected(app.foundation_id(), application_id));
             Ok(())
         }
+        /// Rejects the application and slashes its deposit to the foundation
+        #[weight = 0]
+        fn reject_application_as_spam(
+            origin,
+            application_id: T::ApplicationId,
+        ) -> DispatchResult {
+            let purported_sudo = ensure_signed(origin)?;
+            let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
+            ensure!(app.awaiting_review(), Error::<T>::ApplicationNotInValidStateToReject);
+            let foundation_id = app.foundation_id();
+            let foundation = <Foundations<T>>::get(foundation_id).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToRejectApplication);
+            let slashed = Self::slash_application_deposit(application_id, foundation_id);
+            <Foundations<T>>::insert(foundation_id, foundation.add_funds(slashed));
+            <Applications<T>>::remove(application_id);
+            Self::deposit_event(RawEvent::ApplicationRejectedAsSpam(foundation_id, application_id, slashed));
+            Ok(())
+        }
+        /// Lets the applicant or recipient withdraw the application until it is
+        /// approved
+        #[weight = 0]
+        fn withdraw_application(
+            origin,
+            application_id: T::ApplicationId,
+        ) -> DispatchResult {
+            let withdrawer = ensure_signed(origin)?;
+            let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
+            ensure!(app.pending(), Error::<T>::ApplicationNotInValidStateToWithdraw);
+            ensure!(Self::is_applicant(application_id, &app, &withdrawer), Error::<T>::NotAuthorizedToWithdrawApplication);
+            Self::return_application_deposit(application_id);
+            <Applications<T>>::remove(application_id);
+            <ApplicationCommitteeVotes<T>>::remove_prefix(application_id);
+            Self::deposit_event(RawEvent::ApplicationWithdrawn(app.foundation_id(), application_id));
+            Ok(())
+        }
+        /// Lets the applicant or recipient amend the submission and requested
+        /// amount until the application is approved, which resets review
+        #[weight = 0]
+        fn amend_application(
+            origin,
+            application_id: T::ApplicationId,
+            submission_ref: T::Cid,
+            amount_requested: BalanceOf<T>,
+        ) -> DispatchResult {
+            let amender = ensure_signed(origin)?;
+            let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
+            ensure!(app.pending(), Error::<T>::ApplicationNotInValidStateToAmend);
+            ensure!(Self::is_applicant(application_id, &app, &amender), Error::<T>::NotAuthorizedToAmendApplication);
+            // open review votes are abandoned, the amended application is reviewed anew
+            <Applications<T>>::insert(application_id, app.amend(submission_ref.clone(), amount_requested));
+            <ApplicationCommitteeVotes<T>>::remove_prefix(application_id);
+            Self::deposit_event(RawEvent::ApplicationAmended(app.foundation_id(), application_id, amount_requested, submission_ref));
+            Ok(())
+        }
+        /// Sends the application back with a note instead of approving or
+        /// rejecting it
+        #[weight = 0]
+        fn request_application_changes(
+            origin,
+            application_id: T::ApplicationId,
+            note: T::Cid,
+        ) -> DispatchResult {
+            let purported_sudo = ensure_signed(origin)?;
+            let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
+            ensure!(app.awaiting_review(), Error::<T>::ApplicationNotInValidStateToRequestChanges);
+            let foundation = <Foundations<T>>::get(app.foundation_id()).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToRequestApplicationChanges);
+            <Applications<T>>::insert(application_id, app.set_state(ApplicationState::ChangesRequested(note.clone())));
+            Self::deposit_event(RawEvent::ApplicationChangesRequested(app.foundation_id(), application_id, note));
+            Ok(())
+        }
+        /// Closes an approved application none of whose milestones are pending,
+        /// approved but unpaid or still streaming
+        #[weight = 0]
+        fn close_application(
+            origin,
+            application_id: T::ApplicationId,
+        ) -> DispatchResult {
+            let closer = ensure_signed(origin)?;
+            let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
+            ensure!(app.approved_and_live(), Error::<T>::ApplicationNotInValidStateToClose);
+            let foundation = <Foundations<T>>::get(app.foundation_id()).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&closer), Error::<T>::NotAuthorizedToCloseApplication);
+            ensure!(!Self::has_outstanding_milestones(application_id), Error::<T>::ApplicationHasOutstandingMilestones);
+            <Applications<T>>::insert(application_id, app.set_state(ApplicationState::Closed));
+            Self::deposit_event(RawEvent::ApplicationClosed(app.foundation_id(), application_id));
+            Ok(())
+        }
         #[weight = 0]
         fn submit_milestone(
             origin,
@@ -392,6 +811,8 @@ decl_module! {
             Self::deposit_event(RawEvent::MilestoneSubmitted(foundation_id, application_id, id, recipient, amount_requested, submission_ref));
             Ok(())
         }
+        /// Opens a vote for every committee in scope, the milestone is approved
+        /// once every committee approves it and rejected if any rejects it
         #[weight = 0]
         fn trigger_milestone_review(
             origin,
@@ -402,19 +823,27 @@ decl_module! {
             let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
             ensure!(mile.awaiting_review(), Error::<T>::MilestoneNotInValidStateToTriggerReview);
             let foundation = <Foundations<T>>::get(mile.base_foundation()).ok_or(Error::<T>::FoundationDNE)?;
-            let auth = if let Some(gov) = foundation.gov().vote() {
-                <org::Module<T>>::is_member_of_group(gov.org().org(), &trigger_er) || foundation.gov().is_sudo(&trigger_er)
-            } else { false };
-            ensure!(auth, Error::<T>::NotAuthorizedToTriggerMilestoneReview);
-            let new_vote_id = match foundation.gov().vote().ok_or(Error::<T>::NotAuthorizedToTriggerMilestoneReview)? {
-                VoteMetadata::Signal(v) => <vote::Module<T>>::open_vote(Some(mile.submission()), v.org, v.threshold, v.duration)?,
-                VoteMetadata::Percentage(v) => <vote::Module<T>>::open_percent_vote(Some(mile.submission()), v.org, v.threshold, v.duration)?,
+            let committees = Self::committees_reviewing(mile.base_foundation(), |s| s.reviews_milestones());
+            ensure!(Self::can_trigger_review(&foundation, &committees, &trigger_er), Error::<T>::NotAuthorizedToTriggerMilestoneReview);
+            let new_vote_id = if committees.is_empty() {
+                let gov = foundation.gov().vote().ok_or(Error::<T>::NotAuthorizedToTriggerMilestoneReview)?;
+                Self::open_review_vote(gov, mile.submission())?
+            } else {
+                let votes = Self::open_committee_votes(committees, mile.submission())?;
+                <MilestoneCommitteeVotes<T>>::remove_prefix((application_id, milestone_id));
+                for (committee_id, vote_id) in votes.iter() {
+                    <MilestoneCommitteeVotes<T>>::insert((application_id, milestone_id), committee_id, vote_id);
+                    Self::deposit_event(RawEvent::MilestoneCommitteeReviewTriggered(mile.base_foundation(), application_id, milestone_id, *committee_id, *vote_id));
+                }
+                votes[0].1
             };
             let new_mile = mile.set_state(MilestoneStatus::SubmittedReviewStarted(new_vote_id));
             <Milestones<T>>::insert(application_id, milestone_id, new_mile);
+            Self::queue_review(QueuedReview::Milestone(application_id, milestone_id, new_vote_id), Self::milestone_poll_frequency());
             Self::deposit_event(RawEvent::MilestoneReviewTriggered(mile.base_foundation(), application_id, milestone_id, new_vote_id));
             Ok(())
         }
+        /// Approves by sudo, unless committees are in scope to review
         #[weight = 0]
         fn approve_milestone(
             origin,
@@ -426,11 +855,29 @@ decl_module! {
             ensure!(mile.awaiting_review(), Error::<T>::MilestoneNotInValidStateToApprove);
             let foundation = <Foundations<T>>::get(mile.base_foundation()).ok_or(Error::<T>::FoundationDNE)?;
             ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToApproveMilestone);
-            let new_mile = Self::approve_milestone_and_try_transfer(&mile);
+            ensure!(Self::committees_reviewing(mile.base_foundation(), |s| s.reviews_milestones()).is_empty(), Error::<T>::MilestoneMustBeApprovedByReviewCommittees);
+            let new_mile = Self::approve_milestone_and_try_transfer(milestone_id, &mile);
             <Milestones<T>>::insert(application_id, milestone_id, new_mile);
             Self::deposit_event(RawEvent::MilestoneApproved(mile.base_foundation(), application_id, milestone_id, mile.submission()));
             Ok(())
         }
+        /// Lets anyone pay an approved milestone the foundation could not pay,
+        /// once the foundation is funded
+        #[weight = 0]
+        fn retry_milestone_payment(
+            origin,
+            application_id: T::ApplicationId,
+            milestone_id: T::MilestoneId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
+            ensure!(mile.approved_not_transferred(), Error::<T>::MilestoneNotInValidStateToRetryPayment);
+            let new_mile = Self::approve_milestone_and_try_transfer(milestone_id, &mile);
+            ensure!(!new_mile.approved_not_transferred(), Error::<T>::MilestonePaymentFailed);
+            <Milestones<T>>::insert(application_id, milestone_id, new_mile);
+            Self::deposit_event(RawEvent::MilestonePaid(mile.base_foundation(), application_id, milestone_id, mile.payment()));
+            Ok(())
+        }
         #[weight = 0]
         fn reject_milestone(
             origin,
@@ -446,51 +893,92 @@ decl_module! {
             Self::deposit_event(RawEvent::MilestoneRejected(mile.base_foundation(), application_id, milestone_id));
             Ok(())
         }
-        fn on_finalize(_n: T::BlockNumber) {
-            // poll applications under review and approve passed applications
-            if Self::application_poll_frequency() % <frame_system::Module<T>>::block_number() == Zero::zero() {
-                <Applications<T>>::iter().filter(|(_, app)| app.under_review().is_some())
-                    .for_each(|(id, app)|  {
-                        if let Some(vid) = app.under_review() {
-                            if let Ok(status) = <vote::Module<T>>::get_vote_outcome(vid) {
-                                match status {
-                                    VoteOutcome::Approved => {
-                                        let new_app = app.set_state(ApplicationState::ApprovedAndLive);
-                                        <Applications<T>>::insert(id, new_app);
-                                        Self::deposit_event(RawEvent::ApplicationApproved(app.foundation_id(), id, app.submission_ref()));
-                                    },
-                                    VoteOutcome::Rejected => {
-                                        <Applications<T>>::remove(id);
-                                        Self::deposit_event(RawEvent::ApplicationRejected(app.foundation_id(), id));
-                                    },
-                                    _ => (),
-                                }
-                            }
-                        }
-                    });
-            }
-            // poll milestones under review and approve passed milestones
-            if Self::milestone_poll_frequency() % <frame_system::Module<T>>::block_number() == Zero::zero() {
-                <Milestones<T>>::iter().filter(|(_, _, mile)| mile.under_review().is_some())
-                    .for_each(|(aid, mid, mile)| {
-                        if let Some(vid) = mile.under_review() {
-                            if let Ok(status) = <vote::Module<T>>::get_vote_outcome(vid) {
-                                match status {
-                                    VoteOutcome::Approved => {
-                                        let new_mile = Self::approve_milestone_and_try_transfer(&mile);
-                                        <Milestones<T>>::insert(aid, mid, new_mile);
-                                        Self::deposit_event(RawEvent::MilestoneApproved(mile.base_foundation(), aid, mid, mile.submission()));
-                                    },
-                                    VoteOutcome::Rejected => {
-                                        <Milestones<T>>::remove(aid, mid);
-                                        Self::deposit_event(RawEvent::MilestoneRejected(mile.base_foundation(), aid, mid));
-                                    },
-                                    _ => (),
-                                }
-                            }
-                        }
-                    });
-            }
+        /// Lets the recipient withdraw the milestone until it is approved
+        #[weight = 0]
+        fn withdraw_milestone(
+            origin,
+            application_id: T::ApplicationId,
+            milestone_id: T::MilestoneId,
+        ) -> DispatchResult {
+            let withdrawer = ensure_signed(origin)?;
+            let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
+            ensure!(mile.pending(), Error::<T>::MilestoneNotInValidStateToWithdraw);
+            ensure!(mile.recipient().account() == withdrawer, Error::<T>::NotAuthorizedToWithdrawMilestone);
+            <Milestones<T>>::remove(application_id, milestone_id);
+            <MilestoneCommitteeVotes<T>>::remove_prefix((application_id, milestone_id));
+            Self::deposit_event(RawEvent::MilestoneWithdrawn(mile.base_foundation(), application_id, milestone_id));
+            Ok(())
+        }
+        /// Lets the recipient amend the submission and requested amount until the
+        /// milestone is approved, which resets review
+        #[weight = 0]
+        fn amend_milestone(
+            origin,
+            application_id: T::ApplicationId,
+            milestone_id: T::MilestoneId,
+            submission_ref: T::Cid,
+            amount_requested: BalanceOf<T>,
+        ) -> DispatchResult {
+            let amender = ensure_signed(origin)?;
+            let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
+            ensure!(mile.pending(), Error::<T>::MilestoneNotInValidStateToAmend);
+            ensure!(mile.recipient().account() == amender, Error::<T>::NotAuthorizedToAmendMilestone);
+            <Milestones<T>>::insert(application_id, milestone_id, mile.amend(submission_ref.clone(), amount_requested));
+            <MilestoneCommitteeVotes<T>>::remove_prefix((application_id, milestone_id));
+            Self::deposit_event(RawEvent::MilestoneAmended(mile.base_foundation(), application_id, milestone_id, amount_requested, submission_ref));
+            Ok(())
+        }
+        /// Sends the milestone back with a note instead of approving or
+        /// rejecting it
+        #[weight = 0]
+        fn request_milestone_changes(
+            origin,
+            application_id: T::ApplicationId,
+            milestone_id: T::MilestoneId,
+            note: T::Cid,
+        ) -> DispatchResult {
+            let purported_sudo = ensure_signed(origin)?;
+            let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
+            ensure!(mile.awaiting_review(), Error::<T>::MilestoneNotInValidStateToRequestChanges);
+            let foundation = <Foundations<T>>::get(mile.base_foundation()).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToRequestMilestoneChanges);
+            <Milestones<T>>::insert(application_id, milestone_id, mile.set_state(MilestoneStatus::ChangesRequested(note.clone())));
+            Self::deposit_event(RawEvent::MilestoneChangesRequested(mile.base_foundation(), application_id, milestone_id, note));
+            Ok(())
+        }
+        /// Cancels the stream of a grantee who walked away, the amount not yet
+        /// streamed is unreserved and stays with the foundation
+        #[weight = 0]
+        fn cancel_milestone_stream(
+            origin,
+            application_id: T::ApplicationId,
+            milestone_id: T::MilestoneId,
+        ) -> DispatchResult {
+            let purported_sudo = ensure_signed(origin)?;
+            let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
+            let drip_id = <MilestoneDrips<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneNotStreaming)?;
+            let foundation = <Foundations<T>>::get(mile.base_foundation()).ok_or(Error::<T>::FoundationDNE)?;
+            ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToCancelMilestoneStream);
+            // a completed stream can no longer be cancelled
+            let unpaid = T::Drip::cancel_stream(drip_id).map_err(|_| Error::<T>::MilestoneNotStreaming)?;
+            <MilestoneDrips<T>>::remove(application_id, milestone_id);
+            <Milestones<T>>::insert(application_id, milestone_id, mile.set_state(MilestoneStatus::StreamCancelled));
+            Self::deposit_event(RawEvent::MilestoneStreamCancelled(mile.base_foundation(), application_id, milestone_id, unpaid));
+            Ok(())
+        }
+        /// Polls a bounded number of the reviews queued by the earlier of
+        /// their vote's end and the poll frequency, the rest are polled in the
+        /// following blocks. Reviews whose vote ends undecided return to
+        /// awaiting review.
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let (polls, steps, payments) = Self::poll_queued_reviews(n);
+            // each poll reads the queue entry, submission, foundation and vote and
+            // writes the submission, queue and the foundation and recipient
+            // accounts, payments to org recipients are charged per member
+            T::DbWeight::get().reads_writes(1, 1)
+                .saturating_add(T::DbWeight::get().reads(steps.into()))
+                .saturating_add(T::DbWeight::get().reads_writes(5, 5).saturating_mul(polls.into()))
+                .saturating_add(payments)
         }
     }
 }
@@ -536,12 +1024,483 @@ impl<T: Trait> Module<T> {
         <MilestoneNonce<T>>::insert(app_id, id_counter);
         id_counter
     }
+    fn committee_generate_uid(
+        foundation_id: T::FoundationId,
+    ) -> T::CommitteeId {
+        let mut id_counter =
+            <CommitteeNonce<T>>::get(foundation_id) + 1u32.into();
+        while <ReviewCommittees<T>>::get(foundation_id, id_counter).is_some() {
+            id_counter += 1u32.into();
+        }
+        <CommitteeNonce<T>>::insert(foundation_id, id_counter);
+        id_counter
+    }
+}
+
+// Review helpers
+impl<T: Trait> Module<T> {
+    /// The foundation's committees whose scope includes the submission
+    fn committees_reviewing(
+        foundation_id: T::FoundationId,
+        in_scope: impl Fn(&ReviewScope) -> bool,
+    ) -> Vec<(T::CommitteeId, CommitteeOf<T>)> {
+        <ReviewCommittees<T>>::iter_prefix(foundation_id)
+            .filter(|(_, c)| in_scope(&c.scope()))
+            .collect()
+    }
+    /// Members of the governance org and the sudo may trigger review, as may
+    /// members of the committees reviewing the submission
+    fn can_trigger_review(
+        foundation: &FoundationOf<T>,
+        committees: &[(T::CommitteeId, CommitteeOf<T>)],
+        who: &T::AccountId,
+    ) -> bool {
+        let gov = foundation.gov();
+        if let Some(vote) = gov.vote() {
+            if <org::Module<T>>::is_member_of_group(vote.org().org(), who)
+                || gov.is_sudo(who)
+            {
+                return true
+            }
+        }
+        !committees.is_empty()
+            && (gov.is_sudo(who)
+                || committees.iter().any(|(_, c)| {
+                    <org::Module<T>>::is_member_of_group(
+                        c.vote().org().org(),
+                        who,
+                    )
+                }))
+    }
+    fn open_review_vote(
+        vote: VoteMetadataOf<T>,
+        topic: T::Cid,
+    ) -> Result<T::VoteId, DispatchError> {
+        match vote {
+            VoteMetadata::Signal(v) => {
+                <vote::Module<T>>::open_vote(
+                    Some(topic),
+                    v.org,
+                    v.threshold,
+                    v.duration,
+                )
+            }
+            VoteMetadata::Percentage(v) => {
+                <vote::Module<T>>::open_percent_vote(
+                    Some(topic),
+                    v.org,
+                    v.threshold,
+                    v.duration,
+                )
+            }
+        }
+    }
+    /// Opens a vote for every committee, none are opened if one fails
+    fn open_committee_votes(
+        committees: Vec<(T::CommitteeId, CommitteeOf<T>)>,
+        topic: T::Cid,
+    ) -> Result<Vec<(T::CommitteeId, T::VoteId)>, DispatchError> {
+        with_transaction(|| {
+            let mut votes = Vec::new();
+            for (committee_id, committee) in committees {
+                match Self::open_review_vote(committee.vote(), topic.clone()) {
+                    Ok(vote_id) => votes.push((committee_id, vote_id)),
+                    Err(e) => return TransactionOutcome::Rollback(Err(e)),
+                }
+            }
+            TransactionOutcome::Commit(Ok(votes))
+        })
+    }
+    /// The committee votes for the review, else its governance vote
+    fn review_votes(review: Review<T>) -> Vec<T::VoteId> {
+        let (votes, lead): (Vec<T::VoteId>, T::VoteId) = match review {
+            QueuedReview::Application(id, v) => {
+                (
+                    <ApplicationCommitteeVotes<T>>::iter_prefix(id)
+                        .map(|(_, vote_id)| vote_id)
+                        .collect(),
+                    v,
+                )
+            }
+            QueuedReview::Milestone(aid, mid, v) => {
+                (
+                    <MilestoneCommitteeVotes<T>>::iter_prefix((aid, mid))
+                        .map(|(_, vote_id)| vote_id)
+                        .collect(),
+                    v,
+                )
+            }
+            QueuedReview::Closure(_, v) => (Vec::new(), v),
+        };
+        if votes.is_empty() {
+            vec![lead]
+        } else {
+            votes
+        }
+    }
+    /// Approved once every vote is approved and rejected if any vote is
+    /// rejected, None if a vote ended, or was removed, without an outcome
+    fn review_outcome(
+        votes: &[T::VoteId],
+        now: T::BlockNumber,
+    ) -> Option<VoteOutcome> {
+        let outcomes = votes
+            .iter()
+            .map(|v| (*v, <vote::Module<T>>::get_vote_outcome(*v)))
+            .collect::<Vec<_>>();
+        if outcomes
+            .iter()
+            .any(|(_, o)| matches!(o, Ok(VoteOutcome::Rejected)))
+        {
+            Some(VoteOutcome::Rejected)
+        } else if outcomes
+            .iter()
+            .all(|(_, o)| matches!(o, Ok(VoteOutcome::Approved)))
+        {
+            Some(VoteOutcome::Approved)
+        } else if outcomes.iter().any(|(v, o)| {
+            !matches!(o, Ok(VoteOutcome::Approved))
+                && Self::vote_lapsed(*v, now)
+        }) {
+            None
+        } else {
+            Some(VoteOutcome::Voting)
+        }
+    }
+    /// Queues the review to be polled after `frequency` blocks or at the
+    /// first end of its votes, whichever comes first
+    fn queue_review(review: Review<T>, frequency: T::BlockNumber) {
+        let now = <frame_system::Module<T>>::block_number();
+        let mut at = now + frequency.max(1u32.into());
+        // votes are accepted through their end block
+        if let Some(ends) = Self::review_votes(review)
+            .into_iter()
+            .filter_map(|v| {
+                <vote::Module<T>>::vote_states(v).and_then(|s| s.ends())
+            })
+            .filter(|ends| *ends >= now)
+            .min()
+        {
+            at = at.min(ends + 1u32.into());
+        }
+        <ReviewQueue<T>>::insert(at, review, ());
+    }
+    /// Runs the polls queued up to `now`, at most MaxReviewPollsPerBlock of
+    /// them, and leaves the rest to the next block
+    /// -> returns the number of polls, of blocks stepped over and the weight
+    /// of payments to org recipients
+    fn poll_queued_reviews(now: T::BlockNumber) -> (u32, u32, Weight) {
+        let mut budget = T::MaxReviewPollsPerBlock::get();
+        let mut cursor = <ReviewCursor<T>>::get();
+        let (mut polls, mut steps, mut payments) = (0u32, 0u32, 0 as Weight);
+        while cursor <= now && budget > 0 {
+            let due = <ReviewQueue<T>>::drain_prefix(cursor)
+                .take(budget as usize)
+                .collect::<Vec<_>>();
+            let polled = due.len() as u32;
+            for (review, _) in due {
+                match review {
+                    QueuedReview::Application(id, vote_id) => {
+                        Self::poll_application_review(now, id, vote_id)
+                    }
+                    QueuedReview::Milestone(aid, mid, vote_id) => {
+                        payments = payments.saturating_add(
+                            Self::poll_milestone_review(now, aid, mid, vote_id),
+                        )
+                    }
+                    QueuedReview::Closure(id, vote_id) => {
+                        payments = payments.saturating_add(
+                            Self::poll_foundation_closure(now, id, vote_id),
+                        )
+                    }
+                }
+            }
+            polls += polled;
+            // the block is done unless the budget ran out first
+            if polled < budget {
+                cursor += 1u32.into();
+                steps += 1;
+            }
+            budget = budget.saturating_sub(polled.max(1));
+        }
+        <ReviewCursor<T>>::put(cursor);
+        (polls, steps, payments)
+    }
+    /// Weight of paying `m` beyond its foundation and recipient accounts,
+    /// org recipients are paid one transfer per member
+    fn org_payment_weight(m: &Milestone<T>) -> Weight {
+        match m.recipient().org() {
+            Some(org) => {
+                let members = <org::Module<T>>::get_group(org.org())
+                    .map(|g| g.0.len() as Weight)
+                    .unwrap_or(0);
+                // the group and the shares of each member are read and each
+                // member's account is written
+                T::DbWeight::get()
+                    .reads_writes(members.saturating_add(1), members)
+            }
+            None => 0,
+        }
+    }
+    /// Returns true if the vote ended, or was removed, without an outcome
+    fn vote_lapsed(vote_id: T::VoteId, now: T::BlockNumber) -> bool {
+        match <vote::Module<T>>::vote_states(vote_id) {
+            Some(v) => v.ends().map(|ends| ends < now).unwrap_or(false),
+            None => true,
+        }
+    }
+    fn poll_application_review(
+        now: T::BlockNumber,
+        id: T::ApplicationId,
+        vote_id: T::VoteId,
+    ) {
+        // drop entries for applications withdrawn, amended or decided since they were queued
+        let app = match <Applications<T>>::get(id) {
+            Some(a) if a.under_review() == Some(vote_id) => a,
+            _ => return,
+        };
+        let review = QueuedReview::Application(id, vote_id);
+        match Self::review_outcome(&Self::review_votes(review), now) {
+            Some(VoteOutcome::Approved) => {
+                let new_app = app.set_state(ApplicationState::ApprovedAndLive);
+                Self::return_application_deposit(id);
+                <Applications<T>>::insert(id, new_app);
+                <ApplicationCommitteeVotes<T>>::remove_prefix(id);
+                Self::deposit_event(RawEvent::ApplicationApproved(
+                    app.foundation_id(),
+                    id,
+                    app.submission_ref(),
+                ));
+            }
+            Some(VoteOutcome::Rejected) => {
+                Self::return_application_deposit(id);
+                <Applications<T>>::remove(id);
+                <ApplicationCommitteeVotes<T>>::remove_prefix(id);
+                Self::deposit_event(RawEvent::ApplicationRejected(
+                    app.foundation_id(),
+                    id,
+                ));
+            }
+            Some(_) => {
+                Self::queue_review(review, Self::application_poll_frequency())
+            }
+            None => {
+                <Applications<T>>::insert(
+                    id,
+                    app.set_state(ApplicationState::SubmittedAwaitingResponse),
+                );
+                <ApplicationCommitteeVotes<T>>::remove_prefix(id);
+                Self::deposit_event(RawEvent::ApplicationReviewLapsed(
+                    app.foundation_id(),
+                    id,
+                    vote_id,
+                ));
+            }
+        }
+    }
+    fn poll_milestone_review(
+        now: T::BlockNumber,
+        application_id: T::ApplicationId,
+        milestone_id: T::MilestoneId,
+        vote_id: T::VoteId,
+    ) -> Weight {
+        // drop entries for milestones withdrawn, amended or decided since they were queued
+        let mile = match <Milestones<T>>::get(application_id, milestone_id) {
+            Some(m) if m.under_review() == Some(vote_id) => m,
+            _ => return 0,
+        };
+        let review =
+            QueuedReview::Milestone(application_id, milestone_id, vote_id);
+        match Self::review_outcome(&Self::review_votes(review), now) {
+            Some(VoteOutcome::Approved) => {
+                let new_mile = Self::approve_milestone_and_try_transfer(
+                    milestone_id,
+                    &mile,
+                );
+                <Milestones<T>>::insert(application_id, milestone_id, new_mile);
+                <MilestoneCommitteeVotes<T>>::remove_prefix((
+                    application_id,
+                    milestone_id,
+                ));
+                Self::deposit_event(RawEvent::MilestoneApproved(
+                    mile.base_foundation(),
+                    application_id,
+                    milestone_id,
+                    mile.submission(),
+                ));
+                return Self::org_payment_weight(&mile)
+            }
+            Some(VoteOutcome::Rejected) => {
+                <Milestones<T>>::remove(application_id, milestone_id);
+                <MilestoneCommitteeVotes<T>>::remove_prefix((
+                    application_id,
+                    milestone_id,
+                ));
+                Self::deposit_event(RawEvent::MilestoneRejected(
+                    mile.base_foundation(),
+                    application_id,
+                    milestone_id,
+                ));
+            }
+            Some(_) => {
+                Self::queue_review(review, Self::milestone_poll_frequency())
+            }
+            None => {
+                <Milestones<T>>::insert(
+                    application_id,
+                    milestone_id,
+                    mile.set_state(MilestoneStatus::SubmittedAwaitingResponse),
+                );
+                <MilestoneCommitteeVotes<T>>::remove_prefix((
+                    application_id,
+                    milestone_id,
+                ));
+                Self::deposit_event(RawEvent::MilestoneReviewLapsed(
+                    mile.base_foundation(),
+                    application_id,
+                    milestone_id,
+                    vote_id,
+                ));
+            }
+        }
+        0
+    }
+    fn poll_foundation_closure(
+        now: T::BlockNumber,
+        foundation_id: T::FoundationId,
+        vote_id: T::VoteId,
+    ) -> Weight {
+        // drop entries for closures replaced or executed since they were queued
+        let (proposer, sweep_to) =
+            match <FoundationClosures<T>>::get(foundation_id) {
+                Some((v, proposer, sweep_to)) if v == vote_id => {
+                    (proposer, sweep_to)
+                }
+                _ => return 0,
+            };
+        let review = QueuedReview::Closure(foundation_id, vote_id);
+        match Self::review_outcome(&Self::review_votes(review), now) {
+            Some(VoteOutcome::Approved) => {
+                <FoundationClosures<T>>::remove(foundation_id);
+                if Self::has_outstanding_grants(foundation_id) {
+                    Self::deposit_event(RawEvent::FoundationClosureFailed(
+                        foundation_id,
+                        vote_id,
+                    ));
+                    return 0
+                }
+                // closing scans every application and pays every donor, or
+                // every member of the org swept to
+                let apps = <Applications<T>>::iter().count() as Weight;
+                let payees = match sweep_to {
+                    Some(org) => {
+                        <org::Module<T>>::get_group(org.org())
+                            .map(|g| g.0.len() as Weight)
+                            .unwrap_or(0)
+                    }
+                    None => {
+                        <FoundationDonations<T>>::iter_prefix(foundation_id)
+                            .count() as Weight
+                    }
+                };
+                match Self::try_close(foundation_id, sweep_to, &proposer) {
+                    Ok(remaining) => {
+                        Self::deposit_event(RawEvent::FoundationClosed(
+                            foundation_id,
+                            remaining,
+                        ))
+                    }
+                    Err(_) => {
+                        Self::deposit_event(RawEvent::FoundationClosureFailed(
+                            foundation_id,
+                            vote_id,
+                        ))
+                    }
+                }
+                return T::DbWeight::get().reads_writes(
+                    apps.saturating_add(payees),
+                    apps.saturating_add(payees),
+                )
+            }
+            Some(VoteOutcome::Voting) => {
+                Self::queue_review(review, Self::application_poll_frequency())
+            }
+            _ => {
+                <FoundationClosures<T>>::remove(foundation_id);
+                Self::deposit_event(RawEvent::FoundationClosureRejected(
+                    foundation_id,
+                    vote_id,
+                ));
+            }
+        }
+        0
+    }
 }
 
 // Storage helpers
 impl<T: Trait> Module<T> {
-    /// Approve milestone and TRY to execute transfer
-    fn approve_milestone_and_try_transfer(m: &Milestone<T>) -> Milestone<T> {
+    /// Approve milestone, TRY to execute transfer and record it for the
+    /// recipient once paid or streaming
+    fn approve_milestone_and_try_transfer(
+        id: T::MilestoneId,
+        m: &Milestone<T>,
+    ) -> Milestone<T> {
+        let new_mile = Self::try_transfer_milestone(id, m);
+        match new_mile.state() {
+            MilestoneStatus::ApprovedAndTransferExecuted
+            | MilestoneStatus::ApprovedAndStreaming => {
+                T::Reputation::record_milestone(
+                    &m.recipient().account(),
+                    (m.base_application(), id),
+                    m.payment(),
+                )
+            }
+            _ => (),
+        }
+        new_mile
+    }
+    /// Starts the milestone's stream or transfers its payment, returning the
+    /// milestone in its approved state
+    fn try_transfer_milestone(
+        id: T::MilestoneId,
+        m: &Milestone<T>,
+    ) -> Milestone<T> {
+        if let (None, Some((period_length, periods))) = (
+            m.recipient().org(),
+            <StreamedPayments<T>>::get(m.base_foundation()),
+        ) {
+            // round up so the stream completes within `periods`
+            let periods: BalanceOf<T> = periods.into();
+            let mut amount = m.payment() / periods;
+            if amount.saturating_mul(periods) < m.payment() {
+                amount = amount.saturating_add(1u32.into());
+            }
+            return match T::Drip::start_stream(
+                &Self::foundation_account_id(m.base_foundation()),
+                &m.recipient().account(),
+                amount,
+                period_length,
+                m.payment(),
+            ) {
+                Ok(drip_id) => {
+                    <MilestoneDrips<T>>::insert(
+                        m.base_application(),
+                        id,
+                        drip_id,
+                    );
+                    Self::deposit_event(RawEvent::MilestoneStreamStarted(
+                        m.base_foundation(),
+                        m.base_application(),
+                        id,
+                        drip_id,
+                    ));
+                    m.set_state(MilestoneStatus::ApprovedAndStreaming)
+                }
+                Err(_) => {
+                    m.set_state(MilestoneStatus::ApprovedButNotTransferred)
+                }
+            }
+        }
         if let Some(o) = m.recipient().org() {
             if <donate::Module<T>>::donate(
                 &Self::foundation_account_id(m.base_foundation()),
@@ -568,13 +1527,191 @@ impl<T: Trait> Module<T> {
             m.set_state(MilestoneStatus::ApprovedButNotTransferred)
         }
     }
-    fn _recursive_remove_foundation(id: T::FoundationId) {
+    /// The applicant who reserved the deposit or the recipient of the application
+    fn is_applicant(
+        id: T::ApplicationId,
+        app: &GrantApp<T>,
+        who: &T::AccountId,
+    ) -> bool {
+        app.recipient().account() == *who
+            || <ApplicationDeposits<T>>::get(id)
+                .map(|(applicant, _)| applicant == *who)
+                .unwrap_or(false)
+    }
+    fn return_application_deposit(id: T::ApplicationId) {
+        if let Some((applicant, deposit)) = <ApplicationDeposits<T>>::take(id) {
+            <T as donate::Trait>::Currency::unreserve(&applicant, deposit);
+        }
+    }
+    /// Slashes the application's deposit to the foundation, returning the amount moved
+    fn slash_application_deposit(
+        id: T::ApplicationId,
+        foundation_id: T::FoundationId,
+    ) -> BalanceOf<T> {
+        let (applicant, deposit) =
+            if let Some(d) = <ApplicationDeposits<T>>::take(id) {
+                d
+            } else {
+                return Zero::zero()
+            };
+        let unmoved = <T as donate::Trait>::Currency::repatriate_reserved(
+            &applicant,
+            &Self::foundation_account_id(foundation_id),
+            deposit,
+            BalanceStatus::Free,
+        )
+        .unwrap_or(deposit);
+        <T as donate::Trait>::Currency::unreserve(&applicant, unmoved);
+        deposit.saturating_sub(unmoved)
+    }
+    /// Closes the foundation, leaving it open if the refund or sweep fails
+    fn try_close(
+        id: T::FoundationId,
+        sweep_to: Option<OrgRep<T::OrgId>>,
+        closer: &T::AccountId,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        with_transaction(|| {
+            match Self::close(id, sweep_to, closer) {
+                Ok(r) => TransactionOutcome::Commit(Ok(r)),
+                Err(e) => TransactionOutcome::Rollback(Err(e)),
+            }
+        })
+    }
+    /// Rejects pending applications, removes the foundation and refunds or
+    /// sweeps its remaining balance, returning the remaining balance
+    fn close(
+        id: T::FoundationId,
+        sweep_to: Option<OrgRep<T::OrgId>>,
+        closer: &T::AccountId,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        <Applications<T>>::iter()
+            .filter(|(_, app)| app.foundation_id() == id && app.pending())
+            .for_each(|(app_id, _)| {
+                Self::deposit_event(RawEvent::ApplicationRejected(id, app_id))
+            });
+        let donations = <FoundationDonations<T>>::drain_prefix(id)
+            .collect::<Vec<(T::AccountId, BalanceOf<T>)>>();
+        Self::recursive_remove_foundation(id);
+        let account = Self::foundation_account_id(id);
+        let remaining = <T as donate::Trait>::Currency::free_balance(&account);
+        // the largest donor, else the closer, receives whatever is left over
+        let residue_to = donations
+            .iter()
+            .max_by_key(|(_, d)| *d)
+            .map(|(donor, _)| donor.clone())
+            .unwrap_or_else(|| closer.clone());
+        if let Some(org) = sweep_to {
+            // donations keep the foundation account alive
+            let swept = remaining.saturating_sub(
+                <T as donate::Trait>::Currency::minimum_balance(),
+            );
+            let (donated, _) =
+                <donate::Module<T>>::donate(&account, org, &residue_to, swept)?;
+            <T as donate::Trait>::Currency::transfer(
+                &account,
+                &residue_to,
+                <T as donate::Trait>::Currency::free_balance(&account),
+                ExistenceRequirement::AllowDeath,
+            )?;
+            Self::deposit_event(RawEvent::FoundationSwept(
+                id,
+                org.org(),
+                donated,
+            ));
+        } else {
+            Self::refund_donors(
+                id, &account, remaining, donations, residue_to,
+            )?;
+        }
+        Ok(remaining)
+    }
+    /// Refunds donors in proportion to their donations. The rounding
+    /// remainder and any failed refund are transferred to `residue_to`,
+    /// failing if that transfer fails so nothing is left in the account.
+    fn refund_donors(
+        id: T::FoundationId,
+        account: &T::AccountId,
+        remaining: BalanceOf<T>,
+        donations: Vec<(T::AccountId, BalanceOf<T>)>,
+        residue_to: T::AccountId,
+    ) -> DispatchResult {
+        let donated = donations
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, (_, d)| acc.saturating_add(*d));
+        for (donor, d) in donations {
+            if donor == residue_to {
+                continue
+            }
+            let due = multiply_by_rational(
+                remaining.unique_saturated_into(),
+                d.unique_saturated_into(),
+                donated.unique_saturated_into(),
+            )
+            .map(BalanceOf::<T>::unique_saturated_from)
+            .unwrap_or_else(|_| BalanceOf::<T>::zero());
+            if <T as donate::Trait>::Currency::transfer(
+                account,
+                &donor,
+                due,
+                ExistenceRequirement::AllowDeath,
+            )
+            .is_ok()
+            {
+                Self::deposit_event(RawEvent::FoundationDonorRefunded(
+                    id, donor, due,
+                ));
+            }
+        }
+        let residue = <T as donate::Trait>::Currency::free_balance(account);
+        <T as donate::Trait>::Currency::transfer(
+            account,
+            &residue_to,
+            residue,
+            ExistenceRequirement::AllowDeath,
+        )?;
+        Self::deposit_event(RawEvent::FoundationDonorRefunded(
+            id, residue_to, residue,
+        ));
+        Ok(())
+    }
+    /// Milestones which are pending, approved but unpaid or still streaming
+    fn has_outstanding_milestones(app_id: T::ApplicationId) -> bool {
+        <Milestones<T>>::iter_prefix(app_id)
+            .any(|(_, m)| m.pending() || m.approved_not_transferred())
+            || <MilestoneDrips<T>>::iter_prefix(app_id)
+                .any(|(_, drip_id)| T::Drip::is_streaming(drip_id))
+    }
+    /// Live applications or applications with outstanding milestones
+    fn has_outstanding_grants(id: T::FoundationId) -> bool {
+        <Applications<T>>::iter()
+            .filter(|(_, app)| app.foundation_id() == id)
+            .any(|(app_id, app)| {
+                app.approved_and_live()
+                    || Self::has_outstanding_milestones(app_id)
+            })
+    }
+    fn recursive_remove_foundation(id: T::FoundationId) {
         <Foundations<T>>::remove(id);
+        <FoundationClosures<T>>::remove(id);
+        <FoundationDonations<T>>::remove_prefix(id);
+        <MinReputation<T>>::remove(id);
+        <StreamedPayments<T>>::remove(id);
+        <ReviewCommittees<T>>::remove_prefix(id);
+        <CommitteeNonce<T>>::remove(id);
         <Applications<T>>::iter()
             .filter(|(_, app)| app.foundation_id() == id)
             .for_each(|(app_id, _)| {
+                Self::return_application_deposit(app_id);
                 <Applications<T>>::remove(app_id);
-                <Milestones<T>>::remove_prefix(app_id);
+                <ApplicationCommitteeVotes<T>>::remove_prefix(app_id);
+                <Milestones<T>>::drain_prefix(app_id).for_each(|(mid, _)| {
+                    <MilestoneCommitteeVotes<T>>::remove_prefix((app_id, mid));
+                });
+                <MilestoneDrips<T>>::drain_prefix(app_id).for_each(
+                    |(_, drip_id)| {
+                        let _ = T::Drip::cancel_stream(drip_id);
+                    },
+                );
             });
     }
 }
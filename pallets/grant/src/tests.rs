@@ -5,6 +5,10 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::{
+        OnFinalize,
+        OnInitialize,
+    },
     weights::Weight,
 };
 use sp_core::H256;
@@ -25,7 +29,10 @@ use util::{
         Organization,
     },
     traits::GroupMembership,
-    vote::Threshold,
+    vote::{
+        Threshold,
+        VoterView,
+    },
 };
 
 // type aliases
@@ -47,6 +54,8 @@ impl_outer_event! {
         org<T>,
         vote<T>,
         donate<T>,
+        reputation<T>,
+        drip<T>,
         grant<T>,
     }
 }
@@ -119,24 +128,63 @@ parameter_types! {
     pub const BigFoundation: ModuleId = ModuleId(*b"big/fund");
     pub const MinDeposit: u64 = 20;
     pub const MinContribution: u64 = 10;
+    pub const ApplicationDeposit: u64 = 1;
+    pub const MaxReviewPollsPerBlock: u32 = 2;
+    pub const MaxReviewCommittees: u32 = 2;
+}
+parameter_types! {
+    pub const MinRecordedPayment: u64 = 1;
+}
+impl reputation::Trait for Test {
+    type Event = TestEvent;
+    type BountyId = u64;
+    type MilestoneId = (u64, u64);
+    type Balance = u64;
+    type MinRecordedPayment = MinRecordedPayment;
+}
+impl drip::Trait for Test {
+    type Event = TestEvent;
+    type DripId = u64;
+    type Currency = Balances;
 }
 impl Trait for Test {
     type Event = TestEvent;
     type FoundationId = u64;
     type ApplicationId = u64;
     type MilestoneId = u64;
+    type CommitteeId = u64;
     type BigFoundation = BigFoundation;
     type MinDeposit = MinDeposit;
     type MinContribution = MinContribution;
+    type ApplicationDeposit = ApplicationDeposit;
+    type MaxReviewPollsPerBlock = MaxReviewPollsPerBlock;
+    type MaxReviewCommittees = MaxReviewCommittees;
+    type Reputation = Reputation;
+    type DripId = u64;
+    type Drip = Drip;
 }
 pub type System = frame_system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
 pub type Org = org::Module<Test>;
+pub type Reputation = reputation::Module<Test>;
+pub type Drip = drip::Module<Test>;
 pub type Grant = Module<Test>;
 
 #[allow(clippy::type_complexity)]
-fn get_last_event(
-) -> RawEvent<u64, u32, u64, u64, u64, u64, u64, Recipient<u64, OrgRep<u64>>> {
+fn get_last_event() -> RawEvent<
+    u64,
+    u64,
+    u32,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    Recipient<u64, OrgRep<u64>>,
+> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -723,3 +771,909 @@ fn approve_reject_milestone_works() {
         assert_eq!(get_last_event(), RawEvent::MilestoneRejected(1, 1, 2));
     });
 }
+
+#[test]
+fn min_reputation_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            20u64,
+            sudo_threshold_no_vote()
+        ));
+        assert_noop!(
+            Grant::set_min_reputation(Origin::signed(2), 1, Some(1)),
+            Error::<Test>::NotAuthorizedToSetMinReputation
+        );
+        assert_ok!(Grant::set_min_reputation(Origin::signed(1), 1, Some(1)));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::FoundationMinReputationSet(1, Some(1))
+        );
+        assert_noop!(
+            Grant::submit_application(
+                Origin::signed(2),
+                1u64,
+                11u32,
+                Recipient::new(2, None),
+                5u64,
+            ),
+            Error::<Test>::ReputationBelowFoundationMinimum
+        );
+        // approved milestones are recorded for their recipient
+        assert_ok!(Grant::set_min_reputation(Origin::signed(1), 1, None));
+        assert_ok!(Grant::submit_application(
+            Origin::signed(2),
+            1u64,
+            11u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+        assert_ok!(Grant::approve_application(Origin::signed(1), 1));
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            1,
+            12u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+        assert_ok!(Grant::approve_milestone(Origin::signed(1), 1, 1));
+        assert_eq!(Reputation::completed_milestones(2, (1, 1)), Some(5));
+        assert_eq!(Reputation::reputation_score(&2), 1);
+        // milestones the foundation cannot pay are not recorded
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            1,
+            14u32,
+            Recipient::new(2, None),
+            30u64,
+        ));
+        assert_ok!(Grant::approve_milestone(Origin::signed(1), 1, 2));
+        assert!(Reputation::completed_milestones(2, (1, 2)).is_none());
+        assert_eq!(Reputation::reputation_score(&2), 1);
+        assert_ok!(Grant::set_min_reputation(Origin::signed(1), 1, Some(1)));
+        assert_ok!(Grant::submit_application(
+            Origin::signed(2),
+            1u64,
+            13u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+    });
+}
+
+#[test]
+fn application_deposits_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            20u64,
+            sudo_threshold_no_vote()
+        ));
+        assert_ok!(Grant::submit_application(
+            Origin::signed(2),
+            1u64,
+            11u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+        assert_eq!(Balances::reserved_balance(&2), 1);
+        assert_eq!(Grant::application_deposits(1), Some((2, 1)));
+        // approval returns the deposit
+        assert_ok!(Grant::approve_application(Origin::signed(1), 1));
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert!(Grant::application_deposits(1).is_none());
+        // good-faith rejection returns the deposit
+        assert_ok!(Grant::submit_application(
+            Origin::signed(3),
+            1u64,
+            12u32,
+            Recipient::new(3, None),
+            5u64,
+        ));
+        assert_ok!(Grant::reject_application(Origin::signed(1), 2));
+        assert_eq!(Balances::reserved_balance(&3), 0);
+        assert_eq!(Balances::total_balance(&3), 200);
+        // spam is slashed to the foundation
+        assert_ok!(Grant::submit_application(
+            Origin::signed(3),
+            1u64,
+            13u32,
+            Recipient::new(3, None),
+            5u64,
+        ));
+        assert_noop!(
+            Grant::reject_application_as_spam(Origin::signed(2), 3),
+            Error::<Test>::NotAuthorizedToRejectApplication
+        );
+        assert_ok!(Grant::reject_application_as_spam(Origin::signed(1), 3));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ApplicationRejectedAsSpam(1, 3, 1)
+        );
+        assert!(Grant::applications(3).is_none());
+        assert_eq!(Balances::total_balance(&3), 199);
+        assert_eq!(Grant::foundations(1).unwrap().funds(), 21);
+        assert_eq!(
+            Balances::total_balance(&Grant::foundation_account_id(1)),
+            21
+        );
+    });
+}
+
+/// Auxiliary method for simulating block time passing
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Drip::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+        Grant::on_initialize(System::block_number());
+    }
+}
+
+#[test]
+fn streamed_milestones_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            50u64,
+            sudo_threshold_no_vote()
+        ));
+        assert_noop!(
+            Grant::set_streamed_payment(Origin::signed(2), 1, Some((5, 3))),
+            Error::<Test>::NotAuthorizedToSetStreamedPayment
+        );
+        assert_noop!(
+            Grant::set_streamed_payment(Origin::signed(1), 1, Some((5, 0))),
+            Error::<Test>::StreamedPaymentRequiresNonZeroPeriods
+        );
+        assert_ok!(Grant::set_streamed_payment(
+            Origin::signed(1),
+            1,
+            Some((5, 3))
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::FoundationStreamedPaymentSet(1, Some((5, 3)))
+        );
+        assert_ok!(Grant::submit_application(
+            Origin::signed(2),
+            1u64,
+            11u32,
+            Recipient::new(2, None),
+            20u64,
+        ));
+        assert_ok!(Grant::approve_application(Origin::signed(1), 1));
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            1,
+            12u32,
+            Recipient::new(2, None),
+            10u64,
+        ));
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            1,
+            13u32,
+            Recipient::new(2, None),
+            10u64,
+        ));
+        assert_ok!(Grant::approve_milestone(Origin::signed(1), 1, 1));
+        assert_eq!(Grant::milestone_drips(1, 1), Some(1));
+        assert_eq!(
+            Grant::milestones(1, 1).unwrap().state(),
+            MilestoneStatus::ApprovedAndStreaming
+        );
+        assert_ok!(Grant::approve_milestone(Origin::signed(1), 1, 2));
+        assert_eq!(get_last_event(), RawEvent::MilestoneApproved(1, 1, 2, 13));
+        // streamed amounts are earmarked against the foundation
+        assert_eq!(
+            Balances::reserved_balance(&Grant::foundation_account_id(1)),
+            20
+        );
+        // 10 over 3 periods is paid 4, 4, 2
+        run_to_block(6);
+        assert_eq!(Balances::free_balance(&2), 98 + 8);
+        assert_noop!(
+            Grant::cancel_milestone_stream(Origin::signed(2), 1, 2),
+            Error::<Test>::NotAuthorizedToCancelMilestoneStream
+        );
+        assert_ok!(Grant::cancel_milestone_stream(Origin::signed(1), 1, 2));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MilestoneStreamCancelled(1, 1, 2, 6)
+        );
+        assert_eq!(
+            Grant::milestones(1, 2).unwrap().state(),
+            MilestoneStatus::StreamCancelled
+        );
+        run_to_block(16);
+        assert_eq!(Balances::free_balance(&2), 98 + 8 + 6);
+        assert_eq!(
+            Balances::free_balance(&Grant::foundation_account_id(1)),
+            50 - 14
+        );
+        // completed streams can no longer be cancelled
+        assert_noop!(
+            Grant::cancel_milestone_stream(Origin::signed(1), 1, 1),
+            Error::<Test>::MilestoneNotStreaming
+        );
+    });
+}
+
+#[test]
+fn close_foundation_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            20u64,
+            sudo_threshold_no_vote()
+        ));
+        assert_ok!(Grant::donate_to_foundation(Origin::signed(2), 1, 20));
+        assert_ok!(Grant::donate_to_foundation(Origin::signed(3), 1, 40));
+        assert_ok!(Grant::submit_application(
+            Origin::signed(4),
+            1u64,
+            11u32,
+            Recipient::new(4, None),
+            40u64,
+        ));
+        assert_ok!(Grant::approve_application(Origin::signed(1), 1));
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(4),
+            1,
+            1,
+            12u32,
+            Recipient::new(4, None),
+            40u64,
+        ));
+        assert_ok!(Grant::approve_milestone(Origin::signed(1), 1, 1));
+        assert_ok!(Grant::submit_application(
+            Origin::signed(5),
+            1u64,
+            13u32,
+            Recipient::new(5, None),
+            10u64,
+        ));
+        assert_eq!(Balances::reserved_balance(&5), 1);
+        assert_noop!(
+            Grant::close_foundation(Origin::signed(2), 1, None),
+            Error::<Test>::NotAuthorizedToCloseFoundation
+        );
+        assert_noop!(
+            Grant::close_foundation(Origin::signed(1), 1, None),
+            Error::<Test>::FoundationHasOutstandingGrants
+        );
+        assert_ok!(Grant::close_application(Origin::signed(1), 1));
+        // the remaining 40 is refunded in proportion to the 80 donated
+        assert_ok!(Grant::close_foundation(Origin::signed(1), 1, None));
+        assert_eq!(get_last_event(), RawEvent::FoundationClosed(1, 40));
+        assert!(System::events().iter().any(|r| {
+            r.event == TestEvent::grant(RawEvent::ApplicationRejected(1, 2))
+        }));
+        assert!(System::events().iter().any(|r| {
+            r.event
+                == TestEvent::grant(RawEvent::FoundationDonorRefunded(1, 3, 20))
+        }));
+        assert_eq!(Balances::reserved_balance(&5), 0);
+        assert_eq!(Balances::free_balance(&1), 100 - 20 + 10);
+        assert_eq!(Balances::free_balance(&2), 98 - 20 + 10);
+        assert_eq!(Balances::free_balance(&3), 200 - 40 + 20);
+        assert!(Grant::foundations(1).is_none());
+        assert!(Grant::applications(1).is_none());
+        assert!(Grant::bounty_tips(1, 1).is_none());
+        // a swept foundation is donated to the org
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(3),
+            10u32,
+            25u64,
+            sudo_threshold_no_vote()
+        ));
+        assert_ok!(Grant::close_foundation(
+            Origin::signed(1),
+            2,
+            Some(OrgRep::Equal(1))
+        ));
+        assert!(System::events().iter().any(|r| {
+            r.event == TestEvent::grant(RawEvent::FoundationSwept(2, 1, 24))
+        }));
+        // the existential deposit left by the donation returns to the donor
+        assert_eq!(Balances::free_balance(&1), 90 + 4);
+        assert_eq!(Balances::free_balance(&3), 180 - 25 + 4 + 1);
+        assert_eq!(Balances::free_balance(&6), 69 + 4);
+        assert_eq!(Balances::free_balance(&Grant::foundation_account_id(2)), 0);
+    });
+}
+
+#[test]
+fn foundations_close_by_vote() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            30u64,
+            new_min_threshold_no_sudo()
+        ));
+        assert_ok!(Grant::donate_to_foundation(Origin::signed(2), 1, 10));
+        assert_noop!(
+            Grant::close_foundation(Origin::signed(1), 1, None),
+            Error::<Test>::NotAuthorizedToCloseFoundation
+        );
+        assert_noop!(
+            Grant::propose_foundation_closure(Origin::signed(7), 1, None),
+            Error::<Test>::NotAuthorizedToCloseFoundation
+        );
+        assert_ok!(Grant::propose_foundation_closure(
+            Origin::signed(2),
+            1,
+            None
+        ));
+        assert_eq!(get_last_event(), RawEvent::FoundationClosureProposed(1, 1));
+        assert_noop!(
+            Grant::propose_foundation_closure(Origin::signed(3), 1, None),
+            Error::<Test>::FoundationClosureAlreadyProposed
+        );
+        assert_ok!(vote::Module::<Test>::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(12);
+        assert_eq!(get_last_event(), RawEvent::FoundationClosed(1, 40));
+        assert!(System::events().iter().any(|r| {
+            r.event
+                == TestEvent::grant(RawEvent::FoundationDonorRefunded(1, 2, 10))
+        }));
+        assert_eq!(Balances::free_balance(&1), 100);
+        assert_eq!(Balances::free_balance(&2), 98);
+        assert!(Grant::foundations(1).is_none());
+        assert!(Grant::foundation_closures(1).is_none());
+    });
+}
+
+#[test]
+fn close_refuses_outstanding_grants() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            30u64,
+            new_min_threshold_and_sudo()
+        ));
+        assert_ok!(Grant::submit_application(
+            Origin::signed(2),
+            1u64,
+            11u32,
+            Recipient::new(2, None),
+            100u64,
+        ));
+        assert_ok!(Grant::approve_application(Origin::signed(1), 1));
+        // live applications keep the foundation open
+        assert_noop!(
+            Grant::close_foundation(Origin::signed(1), 1, None),
+            Error::<Test>::FoundationHasOutstandingGrants
+        );
+        assert_noop!(
+            Grant::close_application(Origin::signed(2), 1),
+            Error::<Test>::NotAuthorizedToCloseApplication
+        );
+        // unpaid milestones keep the application open until paid
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            1,
+            12u32,
+            Recipient::new(2, None),
+            40u64,
+        ));
+        assert_ok!(Grant::approve_milestone(Origin::signed(1), 1, 1));
+        assert_eq!(
+            Grant::milestones(1, 1).unwrap().state(),
+            MilestoneStatus::ApprovedButNotTransferred
+        );
+        assert_noop!(
+            Grant::close_application(Origin::signed(1), 1),
+            Error::<Test>::ApplicationHasOutstandingMilestones
+        );
+        assert_noop!(
+            Grant::retry_milestone_payment(Origin::signed(2), 1, 1),
+            Error::<Test>::MilestonePaymentFailed
+        );
+        assert_ok!(Grant::donate_to_foundation(Origin::signed(3), 1, 20));
+        assert_ok!(Grant::retry_milestone_payment(Origin::signed(2), 1, 1));
+        assert_eq!(get_last_event(), RawEvent::MilestonePaid(1, 1, 1, 40));
+        assert_noop!(
+            Grant::retry_milestone_payment(Origin::signed(2), 1, 1),
+            Error::<Test>::MilestoneNotInValidStateToRetryPayment
+        );
+        // milestones under review keep the application open
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            1,
+            13u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+        assert_ok!(Grant::trigger_milestone_review(Origin::signed(1), 1, 2));
+        assert_noop!(
+            Grant::close_application(Origin::signed(1), 1),
+            Error::<Test>::ApplicationHasOutstandingMilestones
+        );
+        assert_ok!(Grant::withdraw_milestone(Origin::signed(2), 1, 2));
+        // streams keep the application open until they complete
+        assert_ok!(Grant::set_streamed_payment(
+            Origin::signed(1),
+            1,
+            Some((5, 2))
+        ));
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            1,
+            14u32,
+            Recipient::new(2, None),
+            4u64,
+        ));
+        assert_ok!(Grant::approve_milestone(Origin::signed(1), 1, 3));
+        assert_noop!(
+            Grant::close_application(Origin::signed(1), 1),
+            Error::<Test>::ApplicationHasOutstandingMilestones
+        );
+        run_to_block(16);
+        assert_ok!(Grant::close_application(Origin::signed(1), 1));
+        assert_eq!(get_last_event(), RawEvent::ApplicationClosed(1, 1));
+        assert_noop!(
+            Grant::close_application(Origin::signed(1), 1),
+            Error::<Test>::ApplicationNotInValidStateToClose
+        );
+        assert_ok!(Grant::close_foundation(Origin::signed(1), 1, None));
+        assert!(Grant::foundations(1).is_none());
+    });
+}
+
+#[test]
+fn withdraw_amend_and_request_changes_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            20u64,
+            sudo_threshold_no_vote()
+        ));
+        assert_ok!(Grant::submit_application(
+            Origin::signed(2),
+            1u64,
+            11u32,
+            Recipient::new(3, None),
+            5u64,
+        ));
+        assert_noop!(
+            Grant::request_application_changes(Origin::signed(2), 1, 21u32),
+            Error::<Test>::NotAuthorizedToRequestApplicationChanges
+        );
+        assert_ok!(Grant::request_application_changes(
+            Origin::signed(1),
+            1,
+            21u32
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ApplicationChangesRequested(1, 1, 21u32)
+        );
+        assert_eq!(
+            Grant::applications(1).unwrap().changes_requested(),
+            Some(21u32)
+        );
+        // changes must be made before the application is approved
+        assert_noop!(
+            Grant::approve_application(Origin::signed(1), 1),
+            Error::<Test>::ApplicationNotInValidStateToApprove
+        );
+        assert_noop!(
+            Grant::amend_application(Origin::signed(4), 1, 12u32, 6u64),
+            Error::<Test>::NotAuthorizedToAmendApplication
+        );
+        // the recipient may amend as well as the applicant
+        assert_ok!(Grant::amend_application(Origin::signed(3), 1, 12u32, 6u64));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ApplicationAmended(1, 1, 6, 12u32)
+        );
+        let app = Grant::applications(1).unwrap();
+        assert!(app.awaiting_review());
+        assert_eq!(app.submission_ref(), 12u32);
+        assert_eq!(app.payment(), 6);
+        assert_noop!(
+            Grant::withdraw_application(Origin::signed(4), 1),
+            Error::<Test>::NotAuthorizedToWithdrawApplication
+        );
+        // withdrawal returns the deposit
+        assert_ok!(Grant::withdraw_application(Origin::signed(2), 1));
+        assert_eq!(get_last_event(), RawEvent::ApplicationWithdrawn(1, 1));
+        assert!(Grant::applications(1).is_none());
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        // approved applications can no longer be withdrawn or amended
+        assert_ok!(Grant::submit_application(
+            Origin::signed(2),
+            1u64,
+            13u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+        assert_ok!(Grant::approve_application(Origin::signed(1), 2));
+        assert_noop!(
+            Grant::withdraw_application(Origin::signed(2), 2),
+            Error::<Test>::ApplicationNotInValidStateToWithdraw
+        );
+        assert_noop!(
+            Grant::amend_application(Origin::signed(2), 2, 14u32, 6u64),
+            Error::<Test>::ApplicationNotInValidStateToAmend
+        );
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            2,
+            15u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+        assert_noop!(
+            Grant::request_milestone_changes(Origin::signed(2), 2, 1, 22u32),
+            Error::<Test>::NotAuthorizedToRequestMilestoneChanges
+        );
+        assert_ok!(Grant::request_milestone_changes(
+            Origin::signed(1),
+            2,
+            1,
+            22u32
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MilestoneChangesRequested(1, 2, 1, 22u32)
+        );
+        assert_noop!(
+            Grant::approve_milestone(Origin::signed(1), 2, 1),
+            Error::<Test>::MilestoneNotInValidStateToApprove
+        );
+        assert_noop!(
+            Grant::amend_milestone(Origin::signed(1), 2, 1, 16u32, 4u64),
+            Error::<Test>::NotAuthorizedToAmendMilestone
+        );
+        assert_ok!(Grant::amend_milestone(
+            Origin::signed(2),
+            2,
+            1,
+            16u32,
+            4u64
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MilestoneAmended(1, 2, 1, 4, 16u32)
+        );
+        assert_ok!(Grant::approve_milestone(Origin::signed(1), 2, 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MilestoneApproved(1, 2, 1, 16u32)
+        );
+        assert_noop!(
+            Grant::withdraw_milestone(Origin::signed(2), 2, 1),
+            Error::<Test>::MilestoneNotInValidStateToWithdraw
+        );
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            2,
+            17u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+        assert_noop!(
+            Grant::withdraw_milestone(Origin::signed(1), 2, 2),
+            Error::<Test>::NotAuthorizedToWithdrawMilestone
+        );
+        assert_ok!(Grant::withdraw_milestone(Origin::signed(2), 2, 2));
+        assert_eq!(get_last_event(), RawEvent::MilestoneWithdrawn(1, 2, 2));
+        assert!(Grant::milestones(2, 2).is_none());
+    });
+}
+
+#[test]
+fn review_queue_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            20u64,
+            new_min_threshold_and_sudo()
+        ));
+        for (applicant, submission) in [(2, 11u32), (3, 12), (4, 13)].iter() {
+            assert_ok!(Grant::submit_application(
+                Origin::signed(*applicant),
+                1u64,
+                *submission,
+                Recipient::new(*applicant, None),
+                5u64,
+            ));
+        }
+        for id in 1..4 {
+            assert_ok!(Grant::trigger_application_review(
+                Origin::signed(1),
+                id
+            ));
+            assert_ok!(vote::Module::<Test>::submit_vote(
+                Origin::signed(1),
+                id,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        // votes without an end are polled at the poll frequency
+        assert_eq!(
+            Grant::review_queue(11, QueuedReview::Application(1, 1)),
+            Some(())
+        );
+        assert_eq!(
+            Grant::review_queue(11, QueuedReview::Application(3, 3)),
+            Some(())
+        );
+        // only MaxReviewPollsPerBlock reviews are polled before the rest are left to the next block
+        run_to_block(11);
+        let approved = || {
+            (1..4)
+                .filter(|id| {
+                    Grant::applications(id).unwrap().approved_and_live()
+                })
+                .count()
+        };
+        assert_eq!(approved(), 2);
+        assert_eq!(Grant::review_cursor(), 11);
+        run_to_block(12);
+        assert_eq!(approved(), 3);
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Grant::review_cursor(), 13);
+        // undecided votes are polled again
+        assert_ok!(Grant::submit_application(
+            Origin::signed(5),
+            1u64,
+            14u32,
+            Recipient::new(5, None),
+            5u64,
+        ));
+        assert_ok!(Grant::trigger_application_review(Origin::signed(1), 4));
+        run_to_block(22);
+        assert_eq!(Grant::applications(4).unwrap().under_review(), Some(4));
+        assert_eq!(
+            Grant::review_queue(32, QueuedReview::Application(4, 4)),
+            Some(())
+        );
+        // withdrawn applications are dropped from the queue
+        assert_ok!(Grant::withdraw_application(Origin::signed(5), 4));
+        run_to_block(33);
+        assert!(Grant::applications(4).is_none());
+        assert!(
+            Grant::review_queue(32, QueuedReview::Application(4, 4)).is_none()
+        );
+        // reviews are polled at the end of their vote if it comes first
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            20u64,
+            ResolutionMetadata::new(
+                Some(1u64),
+                Some(VoteMetadata::Signal(VoteCall::new(
+                    OrgRep::Equal(1u64),
+                    Threshold::new(6u64, None),
+                    Some(3),
+                ))),
+            )
+            .unwrap()
+        ));
+        assert_ok!(Grant::submit_application(
+            Origin::signed(4),
+            2u64,
+            15u32,
+            Recipient::new(4, None),
+            5u64,
+        ));
+        assert_ok!(Grant::trigger_application_review(Origin::signed(1), 5));
+        assert_eq!(
+            Grant::review_queue(37, QueuedReview::Application(5, 5)),
+            Some(())
+        );
+        run_to_block(37);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ApplicationReviewLapsed(2, 5, 5)
+        );
+        assert!(Grant::applications(5).unwrap().awaiting_review());
+        // milestones are queued in the same way
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(2),
+            1,
+            1,
+            16u32,
+            Recipient::new(2, None),
+            5u64,
+        ));
+        assert_ok!(Grant::trigger_milestone_review(Origin::signed(1), 1, 1));
+        assert_ok!(vote::Module::<Test>::submit_vote(
+            Origin::signed(2),
+            6,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(47);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MilestoneApproved(1, 1, 1, 16u32)
+        );
+        assert!(Grant::milestones(1, 1).unwrap().approved_and_transferred());
+    });
+}
+
+fn committee_vote(org: u64, against: Option<u64>) -> VoteMetadataOf<Test> {
+    VoteMetadata::Signal(VoteCall::new(
+        OrgRep::Equal(org),
+        Threshold::new(1u64, against),
+        None,
+    ))
+}
+
+#[test]
+fn review_committees_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Grant::create_foundation(
+            Origin::signed(1),
+            10u32,
+            20u64,
+            sudo_threshold_no_vote()
+        ));
+        // technical and budget committees
+        assert_ok!(Org::new_flat_org(
+            Origin::signed(1),
+            None,
+            None,
+            31u32,
+            vec![2, 3]
+        ));
+        assert_ok!(Org::new_flat_org(
+            Origin::signed(1),
+            None,
+            None,
+            32u32,
+            vec![4, 5]
+        ));
+        assert_noop!(
+            Grant::add_review_committee(
+                Origin::signed(2),
+                1,
+                31u32,
+                committee_vote(2, None),
+                ReviewScope::Applications
+            ),
+            Error::<Test>::NotAuthorizedToManageReviewCommittees
+        );
+        assert_ok!(Grant::add_review_committee(
+            Origin::signed(1),
+            1,
+            31u32,
+            committee_vote(2, None),
+            ReviewScope::Applications
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ReviewCommitteeAdded(
+                1,
+                1,
+                ReviewScope::Applications,
+                31u32
+            )
+        );
+        assert_ok!(Grant::add_review_committee(
+            Origin::signed(1),
+            1,
+            32u32,
+            committee_vote(3, Some(1)),
+            ReviewScope::ApplicationsAndMilestones
+        ));
+        assert_noop!(
+            Grant::add_review_committee(
+                Origin::signed(1),
+                1,
+                33u32,
+                committee_vote(3, None),
+                ReviewScope::Milestones
+            ),
+            Error::<Test>::ReviewCommitteesExceedModuleMax
+        );
+        assert_ok!(Grant::submit_application(
+            Origin::signed(6),
+            1u64,
+            11u32,
+            Recipient::new(6, None),
+            5u64,
+        ));
+        assert_noop!(
+            Grant::trigger_application_review(Origin::signed(6), 1),
+            Error::<Test>::NotAuthorizedToTriggerApplicationReview
+        );
+        // the sudo may not bypass the committees in scope
+        assert_noop!(
+            Grant::approve_application(Origin::signed(1), 1),
+            Error::<Test>::ApplicationMustBeApprovedByReviewCommittees
+        );
+        // committee members may trigger review, which opens a vote for each committee
+        assert_ok!(Grant::trigger_application_review(Origin::signed(2), 1));
+        let technical = Grant::application_committee_votes(1, 1).unwrap();
+        let budget = Grant::application_committee_votes(1, 2).unwrap();
+        assert_ok!(vote::Module::<Test>::submit_vote(
+            Origin::signed(2),
+            technical,
+            VoterView::InFavor,
+            None
+        ));
+        // both committees must approve
+        run_to_block(11);
+        assert!(Grant::applications(1).unwrap().under_review().is_some());
+        assert_ok!(vote::Module::<Test>::submit_vote(
+            Origin::signed(4),
+            budget,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(21);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ApplicationApproved(1, 1, 11u32)
+        );
+        assert!(Grant::application_committee_votes(1, 1).is_none());
+        // only the budget committee reviews milestones
+        assert_ok!(Grant::submit_milestone(
+            Origin::signed(6),
+            1,
+            1,
+            12u32,
+            Recipient::new(6, None),
+            5u64,
+        ));
+        assert_noop!(
+            Grant::trigger_milestone_review(Origin::signed(2), 1, 1),
+            Error::<Test>::NotAuthorizedToTriggerMilestoneReview
+        );
+        assert_noop!(
+            Grant::approve_milestone(Origin::signed(1), 1, 1),
+            Error::<Test>::MilestoneMustBeApprovedByReviewCommittees
+        );
+        assert_ok!(Grant::trigger_milestone_review(Origin::signed(4), 1, 1));
+        assert!(Grant::milestone_committee_votes((1, 1), 1).is_none());
+        let budget = Grant::milestone_committee_votes((1, 1), 2).unwrap();
+        // any committee may reject
+        assert_ok!(vote::Module::<Test>::submit_vote(
+            Origin::signed(5),
+            budget,
+            VoterView::Against,
+            None
+        ));
+        run_to_block(31);
+        assert_eq!(get_last_event(), RawEvent::MilestoneRejected(1, 1, 1));
+        assert!(Grant::milestones(1, 1).is_none());
+        assert_noop!(
+            Grant::remove_review_committee(Origin::signed(2), 1, 1),
+            Error::<Test>::NotAuthorizedToManageReviewCommittees
+        );
+        assert_ok!(Grant::remove_review_committee(Origin::signed(1), 1, 1));
+        assert_eq!(get_last_event(), RawEvent::ReviewCommitteeRemoved(1, 1));
+        assert_noop!(
+            Grant::remove_review_committee(Origin::signed(1), 1, 1),
+            Error::<Test>::ReviewCommitteeDNE
+        );
+    });
+}
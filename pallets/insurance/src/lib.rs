@@ -24,6 +24,7 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
+    storage::with_transaction,
     traits::{
         Currency,
         ExistenceRequirement,
@@ -48,6 +49,7 @@ use sp_runtime::{
     DispatchError,
     DispatchResult,
     Permill,
+    TransactionOutcome,
 };
 use sp_std::{
     fmt::Debug,
@@ -66,6 +68,7 @@ use util::{
         IDIsAvailable,
         OpenVote,
         RegisterDisputeType,
+        ResolveDispute,
     },
     vote::VoteOutcome,
 };
@@ -189,34 +192,7 @@ decl_module! {
             dispute_id: T::DisputeId,
         ) -> DispatchResult {
             let trigger = ensure_signed(origin)?;
-            let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotRaiseDisputeIfDisputeStateDNE)?;
-            // ensure that the signer can trigger this dispute
-            ensure!(dispute.can_raise_dispute(&trigger), Error::<T>::SignerNotAuthorizedToRaiseThisDispute);
-            // check that it is in a valid state to trigger the dispute
-            let (new_dispute, dispatched_vote_id) = match dispute.state() {
-                DisputeState::DisputeNotRaised => {
-                    // use vote metadata to dispatch vote
-                    let new_vote_id = match dispute.resolution_metadata() {
-                        VoteMetadata::Signal(v) => <vote::Module<T>>::open_vote(None, v.org, v.threshold, v.duration)?,
-                        VoteMetadata::Percentage(v) => <vote::Module<T>>::open_percent_vote(None, v.org, v.threshold, v.duration)?,
-                    };
-                    // update the state of the dispute with the new vote identifier
-                    let updated_dispute = dispute.set_state(DisputeState::DisputeRaisedAndVoteDispatched(new_vote_id));
-                    // return tuple
-                    (updated_dispute, new_vote_id)
-                },
-                // throw error if not in a state to trigger vote
-                _ => return Err(Error::<T>::ActiveDisputeCannotBeRaisedFromCurrentState.into()),
-            };
-            let (locker, amt_locked, court_org) = (
-                new_dispute.locker(),
-                new_dispute.locked_funds(),
-                new_dispute.resolution_metadata().org(),
-            );
-            // insert new dispute state
-            <DisputeStates<T>>::insert(dispute_id, new_dispute);
-            // emit the event with the VoteId
-            Self::deposit_event(RawEvent::DisputeRaisedAndVoteTriggered(dispute_id, locker, amt_locked, trigger, court_org, dispatched_vote_id));
+            Self::raise_dispute(dispute_id, &trigger)?;
             Ok(())
         }
         #[weight = 0]
@@ -225,37 +201,11 @@ decl_module! {
             dispute_id: T::DisputeId,
         ) -> DispatchResult {
             let _ = ensure_signed(origin)?;
-            let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotPollDisputeIfDisputeStateDNE)?;
             // _could_ verify poller in context of dispute here
-
-            // match on the dispute's current state
-            let new_dispute_state = match dispute.state() {
-                DisputeState::DisputeRaisedAndVoteDispatched(live_vote_id) => {
-                    // check the vote outcome
-                    let outcome = <vote::Module<T>>::get_vote_outcome(live_vote_id)?;
-                    match outcome {
-                        VoteOutcome::Approved => {
-                            // unreserve capital from locker
-                            let _ = T::Currency::unreserve(&dispute.locker(), dispute.locked_funds());
-                            // transfer from locker to dispute_raiser
-                            T::Currency::transfer(&dispute.locker(), &dispute.dispute_raiser(), dispute.locked_funds(), ExistenceRequirement::KeepAlive)?;
-                            // update dispute state
-                            dispute.set_state(DisputeState::DisputeRaisedAndAccepted(live_vote_id))
-                        }
-                        VoteOutcome::Rejected => {
-                            // unreserve capital from locker
-                            let _ = T::Currency::unreserve(&dispute.locker(), dispute.locked_funds());
-                            // update dispute state
-                            dispute.set_state(DisputeState::DisputeRaisedAndRejected(live_vote_id))
-                        }
-                        _ => return Err(Error::<T>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome.into()),
-                    }
-                }
-                _ => return Err(Error::<T>::ActiveDisputeCannotBePolledFromCurrentState.into()),
-            };
-            // insert new dispute state
-            <DisputeStates<T>>::insert(dispute_id, new_dispute_state);
-            // emit the event with the outcome
+            ensure!(
+                Self::poll_dispute(dispute_id)?.is_some(),
+                Error::<T>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome
+            );
             Ok(())
         }
     }
@@ -316,3 +266,146 @@ impl<T: Trait>
         Ok(new_dispute_id)
     }
 }
+
+impl<T: Trait> ResolveDispute<T::DisputeId, T::AccountId> for Module<T> {
+    type VoteId = T::VoteId;
+    fn raise_dispute(
+        dispute_id: T::DisputeId,
+        trigger: &T::AccountId,
+    ) -> Result<T::VoteId, DispatchError> {
+        let dispute = <DisputeStates<T>>::get(dispute_id)
+            .ok_or(Error::<T>::CannotRaiseDisputeIfDisputeStateDNE)?;
+        // ensure that the signer can trigger this dispute
+        ensure!(
+            dispute.can_raise_dispute(trigger),
+            Error::<T>::SignerNotAuthorizedToRaiseThisDispute
+        );
+        // check that it is in a valid state to trigger the dispute
+        let (new_dispute, dispatched_vote_id) = match dispute.state() {
+            DisputeState::DisputeNotRaised => {
+                // use vote metadata to dispatch vote
+                let new_vote_id = match dispute.resolution_metadata() {
+                    VoteMetadata::Signal(v) => {
+                        <vote::Module<T>>::open_vote(
+                            None,
+                            v.org,
+                            v.threshold,
+                            v.duration,
+                        )?
+                    }
+                    VoteMetadata::Percentage(v) => {
+                        <vote::Module<T>>::open_percent_vote(
+                            None,
+                            v.org,
+                            v.threshold,
+                            v.duration,
+                        )?
+                    }
+                };
+                // update the state of the dispute with the new vote identifier
+                let updated_dispute = dispute.set_state(
+                    DisputeState::DisputeRaisedAndVoteDispatched(new_vote_id),
+                );
+                // return tuple
+                (updated_dispute, new_vote_id)
+            }
+            // throw error if not in a state to trigger vote
+            _ => {
+                return Err(
+                    Error::<T>::ActiveDisputeCannotBeRaisedFromCurrentState
+                        .into(),
+                )
+            }
+        };
+        let (locker, amt_locked, court_org) = (
+            new_dispute.locker(),
+            new_dispute.locked_funds(),
+            new_dispute.resolution_metadata().org(),
+        );
+        // insert new dispute state
+        <DisputeStates<T>>::insert(dispute_id, new_dispute);
+        // emit the event with the VoteId
+        Self::deposit_event(RawEvent::DisputeRaisedAndVoteTriggered(
+            dispute_id,
+            locker,
+            amt_locked,
+            trigger.clone(),
+            court_org,
+            dispatched_vote_id,
+        ));
+        Ok(dispatched_vote_id)
+    }
+    fn poll_dispute(
+        dispute_id: T::DisputeId,
+    ) -> Result<Option<bool>, DispatchError> {
+        let dispute = <DisputeStates<T>>::get(dispute_id)
+            .ok_or(Error::<T>::CannotPollDisputeIfDisputeStateDNE)?;
+        // match on the dispute's current state
+        let (new_dispute_state, accepted) = match dispute.state() {
+            DisputeState::DisputeRaisedAndVoteDispatched(live_vote_id) => {
+                // check the vote outcome
+                let outcome =
+                    <vote::Module<T>>::get_vote_outcome(live_vote_id)?;
+                match outcome {
+                    VoteOutcome::Approved => {
+                        // the lock stays reserved if the payment fails so the
+                        // dispute may be polled again
+                        with_transaction(|| {
+                            // unreserve capital from locker
+                            let _ = T::Currency::unreserve(
+                                &dispute.locker(),
+                                dispute.locked_funds(),
+                            );
+                            // transfer from locker to dispute_raiser, the
+                            // locker may be drained by the payment
+                            match T::Currency::transfer(
+                                &dispute.locker(),
+                                &dispute.dispute_raiser(),
+                                dispute.locked_funds(),
+                                ExistenceRequirement::AllowDeath,
+                            ) {
+                                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                                Err(e) => TransactionOutcome::Rollback(Err(e)),
+                            }
+                        })?;
+                        // update dispute state
+                        (
+                            dispute.set_state(
+                                DisputeState::DisputeRaisedAndAccepted(
+                                    live_vote_id,
+                                ),
+                            ),
+                            true,
+                        )
+                    }
+                    VoteOutcome::Rejected => {
+                        // unreserve capital from locker
+                        let _ = T::Currency::unreserve(
+                            &dispute.locker(),
+                            dispute.locked_funds(),
+                        );
+                        // update dispute state
+                        (
+                            dispute.set_state(
+                                DisputeState::DisputeRaisedAndRejected(
+                                    live_vote_id,
+                                ),
+                            ),
+                            false,
+                        )
+                    }
+                    _ => return Ok(None),
+                }
+            }
+            _ => {
+                return Err(
+                    Error::<T>::ActiveDisputeCannotBePolledFromCurrentState
+                        .into(),
+                )
+            }
+        };
+        // insert new dispute state
+        <DisputeStates<T>>::insert(dispute_id, new_dispute_state);
+        Ok(Some(accepted))
+    }
+}
@@ -536,6 +536,7 @@ impl<T: Trait>
     type SpendId = T::SpendId;
     type VoteId = T::VoteId;
     type SpendState = SpendState<T::VoteId>;
+    type SpendDest = T::AccountId;
     fn _propose_spend(
         caller: &T::AccountId,
         bank_id: T::BankId,
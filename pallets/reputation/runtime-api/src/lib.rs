@@ -0,0 +1,18 @@
+//! Runtime API definition for the reputation module
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use parity_scale_codec::Codec;
+use util::reputation::Reputation;
+
+sp_api::decl_runtime_apis! {
+    pub trait ReputationApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Completed work and disputes lost recorded for the account
+        fn reputation(who: AccountId) -> Reputation<Balance>;
+        /// Completed work less disputes lost
+        fn reputation_score(who: AccountId) -> u32;
+    }
+}
@@ -0,0 +1,172 @@
+#![recursion_limit = "256"]
+//! # Reputation Module
+//! This module records completed work per account.
+//!
+//! - [`reputation::Trait`](./trait.Trait.html)
+//!
+//! ## Overview
+//!
+//! This pallet has no calls. It is fed by other modules through
+//! `RecordReputation`: `bounty` records approved submissions and disputes
+//! lost by submitters, `grant` records approved milestones. Bounty depositers
+//! and grant foundations may require a minimum score to submit.
+//!
+//! An account's score is its completed work less the disputes it lost.
+//! Payments below `MinRecordedPayment` are not recorded, so splitting work
+//! into many trivial payments does not raise a score.
+//!
+//! [`Trait`]: ./trait.Trait.html
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+use frame_support::{
+    decl_event,
+    decl_module,
+    decl_storage,
+    traits::Get,
+    Parameter,
+};
+use frame_system::Trait as System;
+use sp_runtime::traits::{
+    AtLeast32BitUnsigned,
+    MaybeSerializeDeserialize,
+    Member,
+    Saturating,
+};
+use sp_std::{
+    fmt::Debug,
+    prelude::*,
+};
+use util::{
+    reputation::Reputation,
+    traits::RecordReputation,
+};
+
+// type aliases
+type ReputationOf<T> = Reputation<<T as Trait>::Balance>;
+
+pub trait Trait: System {
+    /// The overarching event type
+    type Event: From<Event<Self>> + Into<<Self as System>::Event>;
+
+    /// The bounty identifier
+    type BountyId: Parameter + Member + Copy;
+
+    /// The grant milestone identifier
+    type MilestoneId: Parameter + Member + Copy;
+
+    /// The balance type of recorded payments
+    type Balance: Parameter
+        + Member
+        + AtLeast32BitUnsigned
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// Payments below this amount are not recorded as completed work
+    type MinRecordedPayment: Get<Self::Balance>;
+}
+
+decl_event!(
+    pub enum Event<T>
+    where
+        <T as System>::AccountId,
+        <T as Trait>::BountyId,
+        <T as Trait>::MilestoneId,
+        <T as Trait>::Balance,
+    {
+        /// Account, Bounty Identifier, Amount Paid, New Score
+        BountyRecorded(AccountId, BountyId, Balance, u32),
+        /// Account, Milestone Identifier, Amount Requested, New Score
+        MilestoneRecorded(AccountId, MilestoneId, Balance, u32),
+        /// Account, New Score
+        DisputeLostRecorded(AccountId, u32),
+    }
+);
+
+decl_storage! {
+    trait Store for Module<T: Trait> as Reputation {
+        /// Completed work and disputes lost per account
+        pub Reputations get(fn reputations): map
+            hasher(blake2_128_concat) T::AccountId => ReputationOf<T>;
+        /// Sum paid to each account by each bounty
+        pub CompletedBounties get(fn completed_bounties): double_map
+            hasher(blake2_128_concat) T::AccountId,
+            hasher(blake2_128_concat) T::BountyId => Option<T::Balance>;
+        /// Sum requested by each approved milestone of each account
+        pub CompletedMilestones get(fn completed_milestones): double_map
+            hasher(blake2_128_concat) T::AccountId,
+            hasher(blake2_128_concat) T::MilestoneId => Option<T::Balance>;
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn deposit_event() = default;
+    }
+}
+
+impl<T: Trait> Module<T> {
+    pub fn reputation_score(who: &T::AccountId) -> u32 {
+        <Reputations<T>>::get(who).score()
+    }
+}
+
+impl<T: Trait> RecordReputation<T::AccountId> for Module<T> {
+    type BountyId = T::BountyId;
+    type MilestoneId = T::MilestoneId;
+    type Balance = T::Balance;
+    fn record_bounty(
+        who: &T::AccountId,
+        bounty: T::BountyId,
+        amount: T::Balance,
+    ) {
+        if amount < T::MinRecordedPayment::get() {
+            return
+        }
+        let new_rep = <Reputations<T>>::get(who).add_bounty(amount);
+        let paid = <CompletedBounties<T>>::get(who, bounty)
+            .unwrap_or_default()
+            .saturating_add(amount);
+        <CompletedBounties<T>>::insert(who, bounty, paid);
+        <Reputations<T>>::insert(who, new_rep);
+        Self::deposit_event(RawEvent::BountyRecorded(
+            who.clone(),
+            bounty,
+            amount,
+            new_rep.score(),
+        ));
+    }
+    fn record_milestone(
+        who: &T::AccountId,
+        milestone: T::MilestoneId,
+        amount: T::Balance,
+    ) {
+        if amount < T::MinRecordedPayment::get() {
+            return
+        }
+        let new_rep = <Reputations<T>>::get(who).add_milestone(amount);
+        <CompletedMilestones<T>>::insert(who, milestone, amount);
+        <Reputations<T>>::insert(who, new_rep);
+        Self::deposit_event(RawEvent::MilestoneRecorded(
+            who.clone(),
+            milestone,
+            amount,
+            new_rep.score(),
+        ));
+    }
+    fn record_dispute_lost(who: &T::AccountId) {
+        let new_rep = <Reputations<T>>::get(who).add_dispute_lost();
+        <Reputations<T>>::insert(who, new_rep);
+        Self::deposit_event(RawEvent::DisputeLostRecorded(
+            who.clone(),
+            new_rep.score(),
+        ));
+    }
+    fn reputation_score(who: &T::AccountId) -> u32 {
+        <Reputations<T>>::get(who).score()
+    }
+}
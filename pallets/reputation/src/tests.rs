@@ -0,0 +1,137 @@
+use super::*;
+use frame_support::{
+    impl_outer_event,
+    impl_outer_origin,
+    parameter_types,
+    weights::Weight,
+};
+use frame_system::{self as system,};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::IdentityLookup,
+    Perbill,
+};
+
+// type aliases
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+mod reputation {
+    pub use super::super::*;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        system<T>,
+        reputation<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+    type Origin = Origin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Call = ();
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type MaximumBlockLength = MaximumBlockLength;
+    type Version = ();
+    type PalletInfo = ();
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type BaseCallFilter = ();
+    type SystemWeightInfo = ();
+}
+parameter_types! {
+    pub const MinRecordedPayment: u64 = 5;
+}
+impl Trait for Test {
+    type Event = TestEvent;
+    type BountyId = u64;
+    type MilestoneId = (u64, u64);
+    type Balance = u64;
+    type MinRecordedPayment = MinRecordedPayment;
+}
+pub type System = system::Module<Test>;
+pub type Reputation = Module<Test>;
+
+fn get_last_event() -> RawEvent<u64, u64, (u64, u64), u64> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let TestEvent::reputation(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .last()
+        .unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn recording_work_updates_score() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Reputation::reputation_score(&1), 0);
+        <Reputation as RecordReputation<u64>>::record_bounty(&1, 1, 10);
+        assert_eq!(RawEvent::BountyRecorded(1, 1, 10, 1), get_last_event());
+        <Reputation as RecordReputation<u64>>::record_bounty(&1, 1, 5);
+        assert_eq!(Reputation::completed_bounties(1, 1), Some(15));
+        // payments below the minimum are not recorded
+        <Reputation as RecordReputation<u64>>::record_bounty(&1, 2, 4);
+        assert!(Reputation::completed_bounties(1, 2).is_none());
+        assert_eq!(Reputation::reputation_score(&1), 2);
+        <Reputation as RecordReputation<u64>>::record_milestone(&1, (1, 1), 20);
+        assert_eq!(
+            RawEvent::MilestoneRecorded(1, (1, 1), 20, 3),
+            get_last_event()
+        );
+        assert_eq!(Reputation::completed_milestones(1, (1, 1)), Some(20));
+        <Reputation as RecordReputation<u64>>::record_dispute_lost(&1);
+        assert_eq!(RawEvent::DisputeLostRecorded(1, 2), get_last_event());
+        let rep = Reputation::reputations(1);
+        assert_eq!(rep.bounties_completed(), 2);
+        assert_eq!(rep.bounty_earnings(), 15);
+        assert_eq!(rep.milestones_completed(), 1);
+        assert_eq!(rep.milestone_earnings(), 20);
+        assert_eq!(rep.disputes_lost(), 1);
+        // scores do not underflow
+        <Reputation as RecordReputation<u64>>::record_dispute_lost(&2);
+        assert_eq!(Reputation::reputation_score(&2), 0);
+    });
+}
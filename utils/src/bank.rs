@@ -1,3 +1,4 @@
+use crate::organization::OrgRep;
 use parity_scale_codec::{
     Codec,
     Decode,
@@ -53,6 +54,18 @@ impl<
             false
         }
     }
+    pub fn set_controller(&self, controller: Option<AccountId>) -> Self {
+        BankState {
+            controller,
+            ..self.clone()
+        }
+    }
+    pub fn set_threshold_id(&self, threshold_id: ThresholdId) -> Self {
+        BankState {
+            threshold_id,
+            ..self.clone()
+        }
+    }
 }
 
 #[derive(
@@ -63,15 +76,123 @@ pub enum SpendState<VoteId> {
     Voting(VoteId),
     ApprovedButNotExecuted,
     ApprovedAndExecuted,
+    Rejected,
+}
+
+#[derive(
+    new, Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Amount of one asset held by a bank
+pub struct Asset<CurrencyId, Balance> {
+    currency_id: CurrencyId,
+    balance: Balance,
+}
+
+impl<CurrencyId: Copy, Balance: Copy> Asset<CurrencyId, Balance> {
+    pub fn currency_id(&self) -> CurrencyId {
+        self.currency_id
+    }
+    pub fn balance(&self) -> Balance {
+        self.balance
+    }
+}
+
+#[derive(
+    new,
+    Clone,
+    Copy,
+    Default,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    sp_runtime::RuntimeDebug,
+)]
+/// Balance of a bank account split by whether or not it is reserved for spends
+pub struct BankBalance<Currency> {
+    // available for new spend proposals
+    free: Currency,
+    // reserved for spends that are approved or under vote
+    committed: Currency,
+    // sum of free and committed
+    total: Currency,
+}
+
+impl<Currency: Copy> BankBalance<Currency> {
+    pub fn free(&self) -> Currency {
+        self.free
+    }
+    pub fn committed(&self) -> Currency {
+        self.committed
+    }
+    pub fn total(&self) -> Currency {
+        self.total
+    }
+}
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Change to a bank which is enacted if the bank's threshold approves it
+pub enum BankChange<AccountId, ThresholdId> {
+    Close,
+    ReplaceController(Option<AccountId>),
+    ChangeThreshold(ThresholdId),
+}
+
+#[derive(
+    new, Clone, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub struct ChangeProposal<AccountId, Change, VoteId> {
+    // receives the remainder if the change closes the bank
+    proposer: AccountId,
+    change: Change,
+    vote_id: VoteId,
+}
+
+impl<AccountId: Clone, Change: Clone, VoteId: Copy>
+    ChangeProposal<AccountId, Change, VoteId>
+{
+    pub fn proposer(&self) -> AccountId {
+        self.proposer.clone()
+    }
+    pub fn change(&self) -> Change {
+        self.change.clone()
+    }
+    pub fn vote_id(&self) -> VoteId {
+        self.vote_id
+    }
+}
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Reference to a bank's proposal in the poll schedule
+pub enum QueuedProposal<SpendId, ChangeId> {
+    Spend(SpendId),
+    Change(ChangeId),
 }
 
 #[derive(
     Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
 )]
-pub struct SpendProposal<BankId, SpendId, Currency, AccountId, State> {
+/// Recipient of a spend from a bank account
+pub enum SpendDest<AccountId, BankId, OrgId> {
+    // transfer to the account
+    Account(AccountId),
+    // transfer to another bank account
+    Bank(BankId),
+    // distribute among members of the org via `donate`
+    Org(OrgRep<OrgId>),
+}
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub struct SpendProposal<BankId, SpendId, Currency, Dest, State> {
     id: (BankId, SpendId),
     amount: Currency,
-    dest: AccountId,
+    dest: Dest,
     state: State,
 }
 
@@ -79,15 +200,15 @@ impl<
         BankId: Copy,
         SpendId: Copy,
         Currency: Copy,
-        AccountId: Clone,
+        Dest: Clone,
         VoteId: Copy,
-    > SpendProposal<BankId, SpendId, Currency, AccountId, SpendState<VoteId>>
+    > SpendProposal<BankId, SpendId, Currency, Dest, SpendState<VoteId>>
 {
     pub fn new(
         bank_id: BankId,
         spend_id: SpendId,
         amount: Currency,
-        dest: AccountId,
+        dest: Dest,
     ) -> Self {
         Self {
             id: (bank_id, spend_id),
@@ -105,7 +226,7 @@ impl<
     pub fn amount(&self) -> Currency {
         self.amount
     }
-    pub fn dest(&self) -> AccountId {
+    pub fn dest(&self) -> Dest {
         self.dest.clone()
     }
     pub fn state(&self) -> SpendState<VoteId> {
@@ -118,3 +239,50 @@ impl<
         }
     }
 }
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub enum LedgerEntryKind {
+    // funds received by the bank
+    Deposit,
+    // funds paid out for an executed spend
+    Spend,
+    // funds distributed when the bank was closed
+    CloseDistribution,
+}
+
+#[derive(
+    new, Clone, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Record of one inflow or outflow of a bank account
+pub struct LedgerEntry<Counterparty, SpendId, Currency, BlockNumber> {
+    kind: LedgerEntryKind,
+    // sender for deposits, recipient otherwise
+    counterparty: Counterparty,
+    amount: Currency,
+    // spend which moved the funds, if any
+    spend_id: Option<SpendId>,
+    // block in which the funds moved
+    block: BlockNumber,
+}
+
+impl<Counterparty: Clone, SpendId: Copy, Currency: Copy, BlockNumber: Copy>
+    LedgerEntry<Counterparty, SpendId, Currency, BlockNumber>
+{
+    pub fn kind(&self) -> LedgerEntryKind {
+        self.kind
+    }
+    pub fn counterparty(&self) -> Counterparty {
+        self.counterparty.clone()
+    }
+    pub fn amount(&self) -> Currency {
+        self.amount
+    }
+    pub fn spend_id(&self) -> Option<SpendId> {
+        self.spend_id
+    }
+    pub fn block(&self) -> BlockNumber {
+        self.block
+    }
+}
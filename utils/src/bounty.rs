@@ -166,26 +166,126 @@ impl<
     }
 }
 
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+pub enum ClaimState<BlockNumber> {
+    /// Waiting for the depositer to approve a concurrent claim
+    Requested,
+    /// Only active claimants may submit until the claim expires
+    Active(BlockNumber),
+}
+
+impl<BlockNumber> Default for ClaimState<BlockNumber> {
+    fn default() -> ClaimState<BlockNumber> {
+        ClaimState::Requested
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct BountyClaim<BountyId, AccountId, Currency, BlockNumber> {
+    bounty_id: BountyId,
+    hunter: AccountId,
+    bond: Currency,
+    state: ClaimState<BlockNumber>,
+}
+
+impl<BountyId: Copy, AccountId: Clone, Currency: Copy, BlockNumber: Copy>
+    BountyClaim<BountyId, AccountId, Currency, BlockNumber>
+{
+    pub fn bounty_id(&self) -> BountyId {
+        self.bounty_id
+    }
+    pub fn hunter(&self) -> AccountId {
+        self.hunter.clone()
+    }
+    pub fn bond(&self) -> Currency {
+        self.bond
+    }
+    pub fn state(&self) -> ClaimState<BlockNumber> {
+        self.state
+    }
+    pub fn expires(&self) -> Option<BlockNumber> {
+        match self.state {
+            ClaimState::Active(n) => Some(n),
+            _ => None,
+        }
+    }
+    pub fn set_state(&self, state: ClaimState<BlockNumber>) -> Self {
+        Self {
+            state,
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// Matching pool for contributions to eligible bounties until the round ends
+pub struct FundingRound<RoundId, AccountId, Currency, BlockNumber> {
+    id: RoundId,
+    // receives the unmatched remainder of the pool
+    sponsor: AccountId,
+    pool: Currency,
+    // maximum matched to any one bounty
+    cap: Currency,
+    end: BlockNumber,
+}
+
+impl<RoundId: Copy, AccountId: Clone, Currency: Copy, BlockNumber: Copy>
+    FundingRound<RoundId, AccountId, Currency, BlockNumber>
+{
+    pub fn id(&self) -> RoundId {
+        self.id
+    }
+    pub fn sponsor(&self) -> AccountId {
+        self.sponsor.clone()
+    }
+    pub fn pool(&self) -> Currency {
+        self.pool
+    }
+    pub fn cap(&self) -> Currency {
+        self.cap
+    }
+    pub fn end(&self) -> BlockNumber {
+        self.end
+    }
+}
+
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
 /// All variants hold identifiers which point to larger objects in runtime storage maps
-pub enum SubmissionState {
+pub enum SubmissionState<VoteId> {
     SubmittedAwaitingResponse,
     ApprovedAndExecuted,
+    UnderReview(VoteId),
+    /// Escalated to a dispute, tracked by the runtime
+    Disputed,
+    /// Rejected but may still be escalated until its window ends
+    Rejected,
 }
 
-impl Default for SubmissionState {
-    fn default() -> SubmissionState {
+impl<VoteId> Default for SubmissionState<VoteId> {
+    fn default() -> SubmissionState<VoteId> {
         SubmissionState::SubmittedAwaitingResponse
     }
 }
 
-impl SubmissionState {
+impl<VoteId: Copy> SubmissionState<VoteId> {
     pub fn awaiting_review(&self) -> bool {
         matches!(self, SubmissionState::SubmittedAwaitingResponse)
     }
+    pub fn under_review(&self) -> Option<VoteId> {
+        match self {
+            SubmissionState::UnderReview(vote_id) => Some(*vote_id),
+            _ => None,
+        }
+    }
+    pub fn disputed(&self) -> bool {
+        matches!(self, SubmissionState::Disputed)
+    }
     pub fn approved(&self) -> bool {
         matches!(self, SubmissionState::ApprovedAndExecuted)
     }
+    pub fn rejected(&self) -> bool {
+        matches!(self, SubmissionState::Rejected)
+    }
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
@@ -310,4 +410,10 @@ impl<
     pub fn state(&self) -> State {
         self.state
     }
+    pub fn set_state(&self, state: State) -> Self {
+        BountySubmission {
+            state,
+            ..self.clone()
+        }
+    }
 }
@@ -78,35 +78,96 @@ impl<
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
-pub enum ApplicationState<VoteId> {
+pub enum ApplicationState<VoteId, IpfsReference> {
     SubmittedAwaitingResponse,
     // wraps a vote_id for the acceptance committee
     UnderReviewByAcceptanceCommittee(VoteId),
     ApprovedAndLive,
     Closed,
+    // wraps the reviewer's note, must be amended before review
+    ChangesRequested(IpfsReference),
 }
 
-impl<VoteId: Codec + PartialEq + Zero + From<u32> + Copy>
-    ApplicationState<VoteId>
+impl<
+        VoteId: Codec + PartialEq + Zero + From<u32> + Copy,
+        IpfsReference: Clone,
+    > ApplicationState<VoteId, IpfsReference>
 {
     pub fn awaiting_review(&self) -> bool {
         matches!(self, ApplicationState::SubmittedAwaitingResponse)
     }
     // basically, can be approved (notably not when already approved)
-    pub fn under_review_by_acceptance_committee(self) -> Option<VoteId> {
+    pub fn under_review_by_acceptance_committee(&self) -> Option<VoteId> {
         match self {
             ApplicationState::SubmittedAwaitingResponse => None,
             ApplicationState::UnderReviewByAcceptanceCommittee(vote_id) => {
-                Some(vote_id)
+                Some(*vote_id)
             }
             _ => None,
         }
     }
-    pub fn approved_and_live(self) -> bool {
+    pub fn changes_requested(&self) -> Option<IpfsReference> {
+        match self {
+            ApplicationState::ChangesRequested(note) => Some(note.clone()),
+            _ => None,
+        }
+    }
+    pub fn approved_and_live(&self) -> bool {
         matches!(self, ApplicationState::ApprovedAndLive)
     }
 }
 
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+/// The submissions routed to a review committee
+pub enum ReviewScope {
+    Applications,
+    Milestones,
+    ApplicationsAndMilestones,
+}
+
+impl ReviewScope {
+    pub fn reviews_applications(&self) -> bool {
+        !matches!(self, ReviewScope::Milestones)
+    }
+    pub fn reviews_milestones(&self) -> bool {
+        !matches!(self, ReviewScope::Applications)
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// A committee whose approval is required for the submissions in its scope
+pub struct ReviewCommittee<IpfsReference, VoteMetadata> {
+    /// The IPFS reference to the committee's remit
+    info: IpfsReference,
+    /// The vote opened for each review
+    vote: VoteMetadata,
+    /// The submissions reviewed by the committee
+    scope: ReviewScope,
+}
+
+impl<IpfsReference: Clone, VoteMetadata: Clone>
+    ReviewCommittee<IpfsReference, VoteMetadata>
+{
+    pub fn info(&self) -> IpfsReference {
+        self.info.clone()
+    }
+    pub fn vote(&self) -> VoteMetadata {
+        self.vote.clone()
+    }
+    pub fn scope(&self) -> ReviewScope {
+        self.scope
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+/// Reference to a review vote in the poll queue
+pub enum QueuedReview<ApplicationId, MilestoneId, VoteId, FoundationId> {
+    Application(ApplicationId, VoteId),
+    Milestone(ApplicationId, MilestoneId, VoteId),
+    /// Vote of the foundation's governance to close it
+    Closure(FoundationId, VoteId),
+}
+
 #[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 pub struct Recipient<AccountId, OrgId> {
     account: AccountId,
@@ -153,7 +214,7 @@ impl<
         IpfsReference,
         Recipient,
         Payment,
-        ApplicationState<VoteId>,
+        ApplicationState<VoteId, IpfsReference>,
     >
 {
     pub fn new(
@@ -166,7 +227,7 @@ impl<
         IpfsReference,
         Recipient,
         Payment,
-        ApplicationState<VoteId>,
+        ApplicationState<VoteId, IpfsReference>,
     > {
         GrantApplication {
             foundation_id,
@@ -197,27 +258,56 @@ impl<
     pub fn under_review(&self) -> Option<VoteId> {
         self.state.under_review_by_acceptance_committee()
     }
-    pub fn state(&self) -> ApplicationState<VoteId> {
-        self.state
+    pub fn changes_requested(&self) -> Option<IpfsReference> {
+        self.state.changes_requested()
     }
-    pub fn set_state(&self, s: ApplicationState<VoteId>) -> Self {
+    /// Not yet approved, may still be withdrawn or amended
+    pub fn pending(&self) -> bool {
+        self.awaiting_review()
+            || self.under_review().is_some()
+            || self.changes_requested().is_some()
+    }
+    pub fn state(&self) -> ApplicationState<VoteId, IpfsReference> {
+        self.state.clone()
+    }
+    pub fn set_state(
+        &self,
+        s: ApplicationState<VoteId, IpfsReference>,
+    ) -> Self {
         GrantApplication {
             state: s,
             ..self.clone()
         }
     }
+    /// Replaces the submission and payment, resetting review
+    pub fn amend(
+        &self,
+        submission_ref: IpfsReference,
+        payment: Payment,
+    ) -> Self {
+        GrantApplication {
+            submission_ref,
+            payment,
+            state: ApplicationState::SubmittedAwaitingResponse,
+            ..self.clone()
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
-pub enum MilestoneStatus<VoteId> {
+pub enum MilestoneStatus<VoteId, IpfsReference> {
     SubmittedAwaitingResponse,
     SubmittedReviewStarted(VoteId),
     ApprovedButNotTransferred,
     ApprovedAndTransferExecuted,
+    ApprovedAndStreaming,
+    StreamCancelled,
+    // wraps the reviewer's note, must be amended before review
+    ChangesRequested(IpfsReference),
 }
 
-impl<VoteId> Default for MilestoneStatus<VoteId> {
-    fn default() -> MilestoneStatus<VoteId> {
+impl<VoteId, IpfsReference> Default for MilestoneStatus<VoteId, IpfsReference> {
+    fn default() -> MilestoneStatus<VoteId, IpfsReference> {
         MilestoneStatus::SubmittedAwaitingResponse
     }
 }
@@ -253,7 +343,7 @@ impl<
         IpfsReference,
         Recipient,
         Payment,
-        MilestoneStatus<VoteId>,
+        MilestoneStatus<VoteId, IpfsReference>,
     >
 {
     pub fn new(
@@ -267,7 +357,7 @@ impl<
         IpfsReference,
         Recipient,
         Payment,
-        MilestoneStatus<VoteId>,
+        MilestoneStatus<VoteId, IpfsReference>,
     > {
         MilestoneSubmission {
             base,
@@ -292,8 +382,8 @@ impl<
     pub fn payment(&self) -> Payment {
         self.payment
     }
-    pub fn state(&self) -> MilestoneStatus<VoteId> {
-        self.state
+    pub fn state(&self) -> MilestoneStatus<VoteId, IpfsReference> {
+        self.state.clone()
     }
     pub fn awaiting_review(&self) -> bool {
         matches!(self.state, MilestoneStatus::SubmittedAwaitingResponse)
@@ -304,16 +394,40 @@ impl<
             _ => None,
         }
     }
+    pub fn changes_requested(&self) -> Option<IpfsReference> {
+        match &self.state {
+            MilestoneStatus::ChangesRequested(note) => Some(note.clone()),
+            _ => None,
+        }
+    }
+    /// Not yet approved, may still be withdrawn or amended
+    pub fn pending(&self) -> bool {
+        self.awaiting_review()
+            || self.under_review().is_some()
+            || self.changes_requested().is_some()
+    }
     pub fn approved_not_transferred(&self) -> bool {
         matches!(self.state, MilestoneStatus::ApprovedButNotTransferred)
     }
     pub fn approved_and_transferred(&self) -> bool {
         matches!(self.state, MilestoneStatus::ApprovedAndTransferExecuted)
     }
-    pub fn set_state(&self, state: MilestoneStatus<VoteId>) -> Self {
+    pub fn set_state(
+        &self,
+        state: MilestoneStatus<VoteId, IpfsReference>,
+    ) -> Self {
         MilestoneSubmission {
             state,
             ..self.clone()
         }
     }
+    /// Replaces the submission and payment, resetting review
+    pub fn amend(&self, submission: IpfsReference, payment: Payment) -> Self {
+        MilestoneSubmission {
+            submission,
+            payment,
+            state: MilestoneStatus::SubmittedAwaitingResponse,
+            ..self.clone()
+        }
+    }
 }
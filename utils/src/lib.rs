@@ -19,6 +19,7 @@ pub mod meta;
 pub mod moloch;
 pub mod organization;
 pub mod rank;
+pub mod reputation;
 pub mod rfp;
 pub mod share;
 pub mod sss;
@@ -0,0 +1,74 @@
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
+use sp_runtime::traits::Saturating;
+
+#[derive(
+    new,
+    Clone,
+    Copy,
+    Default,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    sp_runtime::RuntimeDebug,
+)]
+/// Completed work recorded for an account
+pub struct Reputation<Balance> {
+    // approved bounty submissions
+    bounties_completed: u32,
+    // sum paid for approved bounty submissions
+    bounty_earnings: Balance,
+    // approved grant milestones
+    milestones_completed: u32,
+    // sum requested by approved grant milestones
+    milestone_earnings: Balance,
+    // disputes resolved against the account
+    disputes_lost: u32,
+}
+
+impl<Balance: Copy + Saturating> Reputation<Balance> {
+    pub fn bounties_completed(&self) -> u32 {
+        self.bounties_completed
+    }
+    pub fn bounty_earnings(&self) -> Balance {
+        self.bounty_earnings
+    }
+    pub fn milestones_completed(&self) -> u32 {
+        self.milestones_completed
+    }
+    pub fn milestone_earnings(&self) -> Balance {
+        self.milestone_earnings
+    }
+    pub fn disputes_lost(&self) -> u32 {
+        self.disputes_lost
+    }
+    /// Completed work less disputes lost
+    pub fn score(&self) -> u32 {
+        self.bounties_completed
+            .saturating_add(self.milestones_completed)
+            .saturating_sub(self.disputes_lost)
+    }
+    pub fn add_bounty(&self, amount: Balance) -> Self {
+        Reputation {
+            bounties_completed: self.bounties_completed.saturating_add(1),
+            bounty_earnings: self.bounty_earnings.saturating_add(amount),
+            ..*self
+        }
+    }
+    pub fn add_milestone(&self, amount: Balance) -> Self {
+        Reputation {
+            milestones_completed: self.milestones_completed.saturating_add(1),
+            milestone_earnings: self.milestone_earnings.saturating_add(amount),
+            ..*self
+        }
+    }
+    pub fn add_dispute_lost(&self) -> Self {
+        Reputation {
+            disputes_lost: self.disputes_lost.saturating_add(1),
+            ..*self
+        }
+    }
+}
@@ -253,6 +253,61 @@ pub trait RegisterDisputeType<AccountId, Currency, VoteMetadata, BlockNumber> {
     ) -> Result<Self::DisputeIdentifier>;
 }
 
+pub trait ResolveDispute<DisputeId, AccountId> {
+    type VoteId;
+    /// Dispatches the vote which resolves the dispute
+    fn raise_dispute(
+        dispute_id: DisputeId,
+        trigger: &AccountId,
+    ) -> Result<Self::VoteId>;
+    /// Executes the outcome once the vote concludes, returning whether the
+    /// dispute was accepted or `None` if the vote has not concluded
+    fn poll_dispute(dispute_id: DisputeId) -> Result<Option<bool>>;
+}
+
+// ====== Reputation Logic ======
+
+pub trait RecordReputation<AccountId> {
+    type BountyId;
+    type MilestoneId;
+    type Balance;
+    /// Records an approved bounty submission paid to `who`
+    fn record_bounty(
+        who: &AccountId,
+        bounty: Self::BountyId,
+        amount: Self::Balance,
+    );
+    /// Records an approved grant milestone requested by `who`
+    fn record_milestone(
+        who: &AccountId,
+        milestone: Self::MilestoneId,
+        amount: Self::Balance,
+    );
+    /// Records a dispute resolved against `who`
+    fn record_dispute_lost(who: &AccountId);
+    /// Completed work less disputes lost
+    fn reputation_score(who: &AccountId) -> u32;
+}
+
+// ====== Drip Logic ======
+
+pub trait StreamPayment<AccountId, BlockNumber, Balance> {
+    type DripId;
+    /// Drips `amount` from `source` to `destination` every `period_length`
+    /// blocks until `total` is paid or the stream is cancelled
+    fn start_stream(
+        source: &AccountId,
+        destination: &AccountId,
+        amount: Balance,
+        period_length: BlockNumber,
+        total: Balance,
+    ) -> Result<Self::DripId>;
+    /// Cancels the stream, returning the amount which was not dripped
+    fn cancel_stream(id: Self::DripId) -> Result<Balance>;
+    /// Whether the stream is still dripping
+    fn is_streaming(id: Self::DripId) -> bool;
+}
+
 // ~~~~~~~~ Bank Module ~~~~~~~~
 
 pub trait OpenBankAccount<OrgId, Currency, AccountId, Threshold> {
@@ -270,11 +325,12 @@ pub trait SpendGovernance<BankId, Currency, AccountId, SProp> {
     type SpendId;
     type VoteId;
     type SpendState;
+    type SpendDest;
     fn _propose_spend(
         caller: &AccountId,
         bank_id: BankId,
         amount: Currency,
-        dest: AccountId,
+        dest: Self::SpendDest,
     ) -> Result<Self::SpendId>;
     fn _trigger_vote_on_spend_proposal(
         caller: &AccountId,